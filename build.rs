@@ -1,33 +1,335 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Bindings checked into the repository, generated from `dcmi_interface_api.h` without
+/// `dynamic-loading`
+const PREBUILT_BINDINGS: &str = "bindings/hw_dcmi_sys.rs";
+/// Directory of the vendored headers, one subdirectory per DCMI release, e.g. `24.1.rc2/`
+const VENDORED_HEADERS: &str = "headers";
+const HEADER_NAME: &str = "dcmi_interface_api.h";
+const LIBRARY_NAME: &str = "libdcmi.so";
+/// Where NPU driver installs put libdcmi, searched after `HW_DCMI_PATH`, pkg-config and
+/// `LD_LIBRARY_PATH`; keep in sync with `DEFAULT_DIRS` in `src/loader.rs`
+const DEFAULT_DIRS: &[&str] = &[
+    "/usr/local/dcmi",
+    "/usr/local/Ascend/driver/lib64/driver",
+    "/usr/local/Ascend/driver/lib64",
+    "/usr/lib64",
+];
 
 fn main() {
-    // 读取环境变量HW_DCMI_PATH作为库搜索路径
+    // 读取环境变量HW_DCMI_PATH作为头文件与库的搜索路径
     let hw_dcmi_path = env::var("HW_DCMI_PATH").unwrap_or_else(|_| "/usr/local/dcmi".to_string());
-    let interface_path = format!("{}/dcmi_interface_api.h", hw_dcmi_path);
-    println!("cargo:rustc-link-search=native={}", hw_dcmi_path);
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    // With dynamic-loading libdcmi is opened by DCMI::init instead of being linked
+    let dynamic_loading = env::var_os("CARGO_FEATURE_DYNAMIC_LOADING").is_some();
+    // Documentation builds on docs.rs have neither the header nor the library
+    println!("cargo:rerun-if-env-changed=DOCS_RS");
+    let docs_only =
+        env::var_os("CARGO_FEATURE_DOCS_ONLY").is_some() || env::var_os("DOCS_RS").is_some();
+    // With prebuilt-bindings the header is not needed
+    let prebuilt = env::var_os("CARGO_FEATURE_PREBUILT_BINDINGS").is_some() || docs_only;
+    if !dynamic_loading && !docs_only {
+        println!("cargo:rustc-link-search=native={}", library_dir().display());
+
+        // Tell cargo to tell rustc to link the dcmi shared library.
+        println!("cargo:rustc-link-lib=dylib=dcmi");
+    }
+
+    let mut bindings = if prebuilt {
+        println!("cargo:rerun-if-changed={PREBUILT_BINDINGS}");
+        let linked = std::fs::read_to_string(manifest_dir.join(PREBUILT_BINDINGS))
+            .expect("Couldn't read prebuilt bindings!");
+        if dynamic_loading {
+            dynamic_bindings(&linked)
+        } else {
+            linked
+        }
+    } else {
+        // Only resolved here, prebuilt and docs-only builds must not need a header
+        match header_path(&manifest_dir, &hw_dcmi_path) {
+            Ok(interface_path) => generate_bindings(&interface_path, dynamic_loading),
+            // reported by the compiler, which shows the message unlike a panic of this script
+            Err(message) => format!("compile_error!({message:?});\n"),
+        }
+    };
 
-    // Tell cargo to tell rustc to link the dcmi shared library.
-    println!("cargo:rustc-link-lib=dylib=dcmi");
+    // 指定输出文件的路径为 src/hw_dcmi_sys.rs
+    let out_path = manifest_dir.join("src/hw_dcmi_sys.rs");
 
+    // Write the bindings to the specified file.
+    if dynamic_loading {
+        // Names of all functions, to check a loaded library for missing symbols
+        let symbols: Vec<String> = bindings
+            .split("pub unsafe fn ")
+            .skip(1)
+            .filter_map(|rest| rest.split('(').next())
+            .map(str::trim)
+            .filter(|name| name.starts_with("dcmi_"))
+            .map(|name| format!("    \"{name}\",\n"))
+            .collect();
+        bindings.push_str(&format!(
+            "pub const DCMI_SYMBOLS: &[&str] = &[\n{}];\n",
+            symbols.concat()
+        ));
+    }
+    // unchanged bindings are not rewritten, so cargo does not rebuild the crate
+    if std::fs::read_to_string(&out_path).ok().as_deref() != Some(bindings.as_str()) {
+        std::fs::write(out_path, bindings).expect("Couldn't write bindings!");
+    }
+}
+
+/// Generate the bindings of `interface_path` with bindgen
+fn generate_bindings(interface_path: &Path, dynamic_loading: bool) -> String {
     // The bindgen::Builder is the main entry point to bindgen,
     // and lets you build up options for the resulting bindings.
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default()
         // The input header we would like to generate bindings for.
-        .header(interface_path)
+        .header(interface_path.to_string_lossy())
+        // Only the DCMI API: its functions and the types and constants of its header, not
+        // the definitions of the system headers it includes such as stdbool's `true_`
+        .allowlist_function("dcmi_.*")
+        .allowlist_file(header_regex(interface_path))
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        // Finish the builder and generate the bindings.
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+    if dynamic_loading {
+        // Functions become fields of DcmiLibrary, missing symbols are kept as errors
+        builder = builder
+            .dynamic_library_name("DcmiLibrary")
+            .dynamic_link_require_all(false);
+    }
+    // Finish the builder and generate the bindings.
+    // Unwrap the Result and panic on failure.
+    builder
         .generate()
-        // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings");
+        .expect("Unable to generate bindings")
+        .to_string()
+}
 
-    // 指定输出文件的路径为 src/hw_dcmi_sys.rs
-    let out_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/hw_dcmi_sys.rs");
+/// Regex matching the path of `header` as bindgen reports it
+fn header_regex(header: &Path) -> String {
+    let name = header.file_name().unwrap_or_default().to_string_lossy();
+    let mut regex = String::from(".*");
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() && c != '_' {
+            regex.push('\\');
+        }
+        regex.push(c);
+    }
+    regex
+}
 
-    // Write the bindings to the specified file.
-    bindings
-        .write_to_file(out_path)
-        .expect("Couldn't write bindings!");
+/// Directory to link libdcmi from
+///
+/// The first of `HW_DCMI_PATH`, the directories of the `dcmi` pkg-config package,
+/// `LD_LIBRARY_PATH` and [`DEFAULT_DIRS`] which contains the library. If none does, linking fails
+/// with `HW_DCMI_PATH` or the first default directory as search path.
+fn library_dir() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=HW_DCMI_PATH");
+    println!("cargo:rerun-if-env-changed=LD_LIBRARY_PATH");
+    let hw_dcmi_path = env::var_os("HW_DCMI_PATH").map(PathBuf::from);
+    let pkg_config = pkg_config::Config::new()
+        .cargo_metadata(false)
+        .probe("dcmi")
+        .map(|library| library.link_paths)
+        .unwrap_or_default();
+    let ld_library_path = env::var_os("LD_LIBRARY_PATH")
+        .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let found = hw_dcmi_path
+        .iter()
+        .cloned()
+        .chain(pkg_config)
+        .chain(ld_library_path)
+        .chain(DEFAULT_DIRS.iter().map(PathBuf::from))
+        .find(|dir| dir.join(LIBRARY_NAME).is_file());
+    found
+        .or(hw_dcmi_path)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DIRS[0]))
+}
+
+/// Header the bindings are generated from
+///
+/// In order of precedence: the file named by `HW_DCMI_HEADER`, with the `vendored-header`
+/// feature the header of the release selected by `HW_DCMI_HEADER_VERSION` (by default the newest)
+/// under `headers/`, otherwise the header installed in `HW_DCMI_PATH`.
+///
+/// # Errors
+/// A message explaining the expected layout if no vendored header is found. The crate does not
+/// ship one, the header is part of the proprietary DCMI package.
+fn header_path(manifest_dir: &Path, hw_dcmi_path: &str) -> Result<PathBuf, String> {
+    println!("cargo:rerun-if-env-changed=HW_DCMI_HEADER");
+    println!("cargo:rerun-if-env-changed=HW_DCMI_HEADER_VERSION");
+    if let Some(header) = env::var_os("HW_DCMI_HEADER") {
+        return Ok(PathBuf::from(header));
+    }
+    if env::var_os("CARGO_FEATURE_VENDORED_HEADER").is_none() {
+        return Ok(Path::new(hw_dcmi_path).join(HEADER_NAME));
+    }
+    let vendored = manifest_dir.join(VENDORED_HEADERS);
+    println!("cargo:rerun-if-changed={}", vendored.display());
+    let layout = format!(
+        "the `vendored-header` feature expects {dir}/<release>/{HEADER_NAME}, e.g. \
+         {dir}/24.1.rc2/{HEADER_NAME}; copy it from /usr/local/dcmi/{HEADER_NAME} of a machine \
+         running that driver release, or name a header with HW_DCMI_HEADER",
+        dir = vendored.display()
+    );
+    let version = match env::var("HW_DCMI_HEADER_VERSION") {
+        Ok(version) => version,
+        Err(_) => std::fs::read_dir(&vendored)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().join(HEADER_NAME).is_file())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .max_by(|a, b| version_key(a).cmp(&version_key(b)))
+            .ok_or_else(|| format!("No vendored {HEADER_NAME} found, {layout}"))?,
+    };
+    let header = vendored.join(&version).join(HEADER_NAME);
+    if !header.is_file() {
+        return Err(format!(
+            "No vendored {HEADER_NAME} for DCMI {version}, {layout}"
+        ));
+    }
+    Ok(header)
+}
+
+/// Sort key of a release name such as `24.1.rc2`, numbers compare numerically
+fn version_key(version: &str) -> Vec<(bool, u64)> {
+    version
+        .split('.')
+        .map(|part| match part.parse() {
+            Ok(number) => (true, number),
+            // release candidates come before the final release, `24.1.rc2` < `24.1.0`
+            Err(_) => {
+                let digits = part.trim_start_matches(|c: char| !c.is_ascii_digit());
+                (false, digits.parse().unwrap_or(0))
+            }
+        })
+        .collect()
+}
+
+/// Function declared in an `extern "C"` block of the bindings
+struct Function {
+    name: String,
+    /// `(name, type)` of every parameter
+    params: Vec<(String, String)>,
+    /// Return type including the arrow, e.g. `-> ::std::os::raw::c_int`, empty for none
+    ret: String,
+}
+
+/// Turn linked bindings into the ones bindgen generates with `dynamic_library_name`
+///
+/// The `extern "C"` blocks are replaced by the `DcmiLibrary` struct, with a field per function
+/// holding the symbol looked up in the library, or the lookup error if it is missing.
+fn dynamic_bindings(linked: &str) -> String {
+    let mut types = String::new();
+    let mut functions = Vec::new();
+    let mut rest = linked;
+    while let Some(start) = rest.find("extern \"C\" {") {
+        types.push_str(&rest[..start]);
+        let block = &rest[start..];
+        let end = block.find("\n}").expect("unterminated extern block") + 2;
+        for declaration in block[..end].split("pub fn ").skip(1) {
+            // the `;` of array types is followed by a space
+            let declaration = declaration.split(";\n").next().unwrap();
+            functions.push(parse_function(declaration));
+        }
+        rest = block[end..].trim_start_matches('\n');
+    }
+    types.push_str(rest);
+
+    let signature = |f: &Function| {
+        let params: Vec<String> = f.params.iter().map(|(n, t)| format!("{n}: {t}")).collect();
+        format!("unsafe extern \"C\" fn({}) {}", params.join(", "), f.ret)
+    };
+    let mut library =
+        String::from("pub struct DcmiLibrary {\n    __library: ::libloading::Library,\n");
+    for f in &functions {
+        library.push_str(&format!(
+            "    pub {}: Result<{}, ::libloading::Error>,\n",
+            f.name,
+            signature(f)
+        ));
+    }
+    library.push_str(
+        "}\nimpl DcmiLibrary {\n    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>\n    where\n        P: AsRef<::std::ffi::OsStr>,\n    {\n        let library = ::libloading::Library::new(path)?;\n        Self::from_library(library)\n    }\n    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>\n    where\n        L: Into<::libloading::Library>,\n    {\n        let __library = library.into();\n",
+    );
+    for f in &functions {
+        library.push_str(&format!(
+            "        let {0} = __library.get(b\"{0}\\0\").map(|sym| *sym);\n",
+            f.name
+        ));
+    }
+    let names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+    library.push_str(&format!(
+        "        Ok(DcmiLibrary {{\n            __library,\n            {},\n        }})\n    }}\n",
+        names.join(",\n            ")
+    ));
+    for f in &functions {
+        let params: Vec<String> = f
+            .params
+            .iter()
+            .map(|(n, t)| format!(", {n}: {t}"))
+            .collect();
+        let args: Vec<&str> = f.params.iter().map(|(n, _)| n.as_str()).collect();
+        library.push_str(&format!(
+            "    pub unsafe fn {0}(&self{1}) {2} {{\n        (self.{0}.as_ref().expect(\"Expected function, got error.\"))({3})\n    }}\n",
+            f.name,
+            params.concat(),
+            f.ret,
+            args.join(", ")
+        ));
+    }
+    library.push_str("}\n");
+    types + &library
+}
+
+/// Parse `name(param: type, ...) -> ret` of a declaration
+fn parse_function(declaration: &str) -> Function {
+    let declaration = declaration.split_whitespace().collect::<Vec<_>>().join(" ");
+    let open = declaration.find('(').expect("function without parameters");
+    // nesting of brackets, the `>` of a `->` in a function pointer type closes nothing
+    let nesting = |previous: char, c: char| match c {
+        '(' | '[' | '<' => 1,
+        ')' | ']' => -1,
+        '>' if previous != '-' => -1,
+        _ => 0,
+    };
+    let mut depth = 0;
+    let mut previous = ' ';
+    // the parameter list ends at the parenthesis matching the opening one
+    let mut close = open;
+    for (index, c) in declaration
+        .char_indices()
+        .filter(|&(index, _)| index >= open)
+    {
+        depth += nesting(previous, c);
+        previous = c;
+        if depth == 0 {
+            close = index;
+            break;
+        }
+    }
+    let mut params = Vec::new();
+    let mut param = String::new();
+    for c in declaration[open + 1..close].chars().chain([',']) {
+        depth += nesting(previous, c);
+        previous = c;
+        match c {
+            ',' if depth == 0 => {
+                if let Some((name, ty)) = param.split_once(':') {
+                    params.push((name.trim().to_string(), ty.trim().to_string()));
+                }
+                param.clear();
+            }
+            _ => param.push(c),
+        }
+    }
+    Function {
+        name: declaration[..open].trim().to_string(),
+        params,
+        ret: declaration[close + 1..].trim().to_string(),
+    }
 }
@@ -0,0 +1,153 @@
+//! Prometheus exporter for NPU nodes, meant to run as a Kubernetes DaemonSet
+//!
+//! Samples every NPU with a [`Sampler`], checks the node health periodically and serves the
+//! results on `/metrics`. Node and pod names are read from the environment, set them with the
+//! downward API:
+//!
+//! ```yaml
+//! env:
+//!   - name: NODE_NAME
+//!     valueFrom: { fieldRef: { fieldPath: spec.nodeName } }
+//!   - name: POD_NAME
+//!     valueFrom: { fieldRef: { fieldPath: metadata.name } }
+//!   - name: POD_NAMESPACE
+//!     valueFrom: { fieldRef: { fieldPath: metadata.namespace } }
+//! ```
+//!
+//! Further settings:
+//! - `LISTEN_ADDR`: address of the metrics endpoint, `0.0.0.0:9101` by default
+//! - `SAMPLE_INTERVAL_SECS`: time between two samples, 15 by default
+//! - `HEALTH_INTERVAL_SECS`: time between two health reports, 60 by default
+//!
+//! ```sh
+//! cargo run --example k8s_exporter --features exporter-example
+//! ```
+
+use hw_dcmi::enums::UnitType;
+use hw_dcmi::health::HealthStatus;
+use hw_dcmi::monitor::{Sample, Sampler, SamplerConfig};
+use hw_dcmi::watchdog::Heartbeat;
+use hw_dcmi::DCMI;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use std::error::Error;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Downward API variables exported as labels of every metric
+const K8S_LABELS: [(&str, &str); 3] = [
+    ("NODE_NAME", "node"),
+    ("POD_NAME", "pod"),
+    ("POD_NAMESPACE", "namespace"),
+];
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn record_sample(sample: &Sample) {
+    match &sample.value {
+        Ok(value) => metrics::gauge!(
+            format!("hw_dcmi_chip_{}", sample.metric),
+            "card" => sample.card_id.to_string(),
+            "chip" => sample.chip_id.to_string()
+        )
+        .set(*value),
+        Err(e) => metrics::counter!(
+            "hw_dcmi_read_errors_total",
+            "metric" => sample.metric.as_str(),
+            "error" => e.metric_label()
+        )
+        .increment(1),
+    }
+}
+
+/// Export the health status of every chip (0 healthy, 1 degraded, 2 unhealthy) and an info
+/// metric carrying its identity
+fn record_health(dcmi: &DCMI) {
+    let report = match dcmi.health_report() {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("health report failed: {e}");
+            return;
+        }
+    };
+    for health in &report.chips {
+        let card = health.card_id.to_string();
+        let chip = health.chip_id.to_string();
+        let status = health.status();
+        metrics::gauge!(
+            "hw_dcmi_chip_health_status",
+            "card" => card.clone(),
+            "chip" => chip.clone()
+        )
+        .set(status as u8 as f64);
+        if status != HealthStatus::Healthy {
+            let reasons: Vec<String> = health.reasons.iter().map(ToString::to_string).collect();
+            eprintln!("card {card} chip {chip} {status}: {}", reasons.join(", "));
+        }
+    }
+    metrics::gauge!("hw_dcmi_node_health_status").set(report.status() as u8 as f64);
+
+    for chip in dcmi.all_chips().unwrap_or_default() {
+        if let Ok(identity) = chip.identity() {
+            let serial = identity.elabel.map(|elabel| elabel.serial_number);
+            metrics::gauge!(
+                "hw_dcmi_chip_info",
+                "card" => identity.card_id.to_string(),
+                "chip" => identity.chip_id.to_string(),
+                "model" => identity.info.model(),
+                "serial" => serial.unwrap_or_default(),
+                "driver_version" => identity.driver_version.unwrap_or_default()
+            )
+            .set(1.0);
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let listen: SocketAddr = env_or("LISTEN_ADDR", SocketAddr::from(([0, 0, 0, 0], 9101)));
+    let sample_interval = Duration::from_secs(env_or("SAMPLE_INTERVAL_SECS", 15));
+    let health_interval = Duration::from_secs(env_or("HEALTH_INTERVAL_SECS", 60));
+
+    let mut exporter = PrometheusBuilder::new().with_http_listener(listen);
+    for (variable, label) in K8S_LABELS {
+        if let Ok(value) = std::env::var(variable) {
+            exporter = exporter.add_global_label(label, value);
+        }
+    }
+    exporter.install()?;
+
+    let dcmi = Arc::new(DCMI::init()?);
+    let mut chips = Vec::new();
+    for card in dcmi.owned_cards()? {
+        let npus = card.get_chips()?.into_iter();
+        chips.extend(npus.filter(|chip| chip.unit_type() == UnitType::NPU));
+    }
+    let heartbeat = Heartbeat::new();
+    let config = SamplerConfig::new(sample_interval).heartbeat(heartbeat.clone());
+    let sampler = Sampler::spawn(chips, config)?;
+    eprintln!("serving NPU metrics on http://{listen}/metrics");
+
+    let mut last_health: Option<Instant> = None;
+    loop {
+        for sample in sampler.drain() {
+            record_sample(&sample);
+        }
+        if last_health.is_none_or(|at| at.elapsed() >= health_interval) {
+            record_health(&dcmi);
+            last_health = Some(Instant::now());
+        }
+        // a stuck driver call blocks the sampler, make it visible instead of exporting stale data
+        if !heartbeat.is_fresh(sample_interval * 4) {
+            eprintln!(
+                "sampler has not finished a round for {:?}",
+                heartbeat.elapsed()
+            );
+        }
+        std::thread::sleep(sample_interval);
+    }
+}
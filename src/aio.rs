@@ -0,0 +1,308 @@
+//! Async wrappers for tokio based services
+//!
+//! Every DCMI call blocks the calling thread, some (events, ECC queries on a busy chip) for a
+//! long time. The wrappers run the calls on tokio's blocking thread pool with
+//! [`tokio::task::spawn_blocking`], so they can be awaited from async code without stalling the
+//! runtime. Queries without a dedicated wrapper are available through [`AsyncDCMI::run`] and
+//! [`AsyncChip::run`].
+//!
+//! ```no_run
+//! # use hw_dcmi::aio::AsyncDCMI;
+//! # async fn example() -> hw_dcmi::error::DCMIResult<()> {
+//! let dcmi = AsyncDCMI::init().await?;
+//! for chip in dcmi.chips().await? {
+//!     println!("{}: {:?}", chip.id(), chip.get_temperatures().await?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::device::{Chip, OwnedChip};
+use crate::enums::{FrequencyType, HealthState, UnitType, UtilizationType};
+use crate::error::{DCMIError, DCMIResult};
+use crate::event::{ErrorRecord, EventFilter, FaultEvent};
+use crate::health::{ChipHealth, NodeHealthReport};
+use crate::snapshot::{ChipSnapshot, ChipStatus, SystemSnapshot};
+use crate::structs::{
+    CtrlCpuInfo, ECCSummary, Frequencies, HBMInfo, MemoryInfo, PCIEInfo, ProcessMemoryInfo,
+    TemperatureThresholds, Temperatures, ThrottleStatus,
+};
+use crate::units::{Celsius, MegaHertz, Watts};
+use crate::DCMI;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Number of fault events buffered for an [`AsyncEventStream`] which is not read
+const EVENT_BUFFER: usize = 64;
+
+/// Run `f` on the blocking thread pool
+///
+/// A panic of `f` is resumed in the awaiting task, a runtime shutting down before `f` ran is
+/// reported as [`DCMIError::AbortOperate`].
+async fn blocking<T, F>(f: F) -> DCMIResult<T>
+where
+    F: FnOnce() -> DCMIResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(_) => Err(DCMIError::AbortOperate),
+    }
+}
+
+/// Async handle to a shared [`DCMI`] instance
+#[derive(Debug, Clone)]
+pub struct AsyncDCMI {
+    dcmi: Arc<DCMI>,
+}
+
+impl From<Arc<DCMI>> for AsyncDCMI {
+    fn from(dcmi: Arc<DCMI>) -> Self {
+        AsyncDCMI { dcmi }
+    }
+}
+
+impl AsyncDCMI {
+    /// Initialize the DCMI library, see [`DCMI::init`]
+    pub async fn init() -> DCMIResult<Self> {
+        let dcmi = blocking(DCMI::init).await?;
+        Ok(AsyncDCMI::from(Arc::new(dcmi)))
+    }
+
+    /// Shared synchronous instance
+    pub fn dcmi(&self) -> &Arc<DCMI> {
+        &self.dcmi
+    }
+
+    /// Run any query on the blocking thread pool
+    pub async fn run<T, F>(&self, f: F) -> DCMIResult<T>
+    where
+        F: FnOnce(&DCMI) -> DCMIResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let dcmi = self.dcmi.clone();
+        blocking(move || f(&dcmi)).await
+    }
+
+    /// All NPU chips of all cards, see [`DCMI::all_chips`]
+    pub async fn chips(&self) -> DCMIResult<Vec<AsyncChip>> {
+        let dcmi = self.dcmi.clone();
+        blocking(move || {
+            let mut chips = Vec::new();
+            for card in dcmi.owned_cards()? {
+                chips.extend(
+                    card.get_chips()?
+                        .into_iter()
+                        .filter(|chip| chip.unit_type() == UnitType::NPU)
+                        .map(AsyncChip::from),
+                );
+            }
+            Ok(chips)
+        })
+        .await
+    }
+
+    pub async fn get_dcmi_version(&self) -> DCMIResult<String> {
+        self.run(DCMI::get_dcmi_version).await
+    }
+
+    pub async fn get_driver_version(&self) -> DCMIResult<String> {
+        self.run(DCMI::get_driver_version).await
+    }
+
+    pub async fn get_driver_health(&self) -> DCMIResult<HealthState> {
+        self.run(DCMI::get_driver_health).await
+    }
+
+    pub async fn get_driver_error_codes(&self) -> DCMIResult<Vec<u32>> {
+        self.run(DCMI::get_driver_error_codes).await
+    }
+
+    /// See [`DCMI::snapshot`]
+    pub async fn snapshot(&self) -> DCMIResult<SystemSnapshot> {
+        self.run(DCMI::snapshot).await
+    }
+
+    /// See [`DCMI::health_report`]
+    pub async fn health_report(&self) -> DCMIResult<NodeHealthReport> {
+        self.run(DCMI::health_report).await
+    }
+
+    /// Async stream of fault events, see [`DCMI::subscribe_fault_events`]
+    ///
+    /// The events are read by a dedicated thread, which exits within `timeout` after the stream
+    /// is dropped. Timeouts are not reported, [`AsyncEventStream::next`] waits until an event
+    /// arrives.
+    pub async fn fault_events(
+        &self,
+        filter: EventFilter,
+        timeout: Duration,
+    ) -> DCMIResult<AsyncEventStream> {
+        let dcmi = self.dcmi.clone();
+        let (events, rx) = mpsc::channel(EVENT_BUFFER);
+        let (ready, subscribed) = tokio::sync::oneshot::channel();
+        std::thread::Builder::new()
+            .name("dcmi-fault-events".to_string())
+            .spawn(move || {
+                let mut stream = match dcmi.subscribe_fault_events(filter, timeout) {
+                    Ok(stream) => {
+                        let _ = ready.send(Ok(()));
+                        stream
+                    }
+                    Err(e) => {
+                        let _ = ready.send(Err(e));
+                        return;
+                    }
+                };
+                loop {
+                    match stream.next_event() {
+                        Err(e) if e.kind() == &DCMIError::CodeTimeOut => {
+                            if events.is_closed() {
+                                break;
+                            }
+                        }
+                        Ok(event) => {
+                            if events.blocking_send(Ok(event)).is_err() {
+                                break;
+                            }
+                        }
+                        // the stream does not recover from other errors, report and stop
+                        Err(e) => {
+                            let _ = events.blocking_send(Err(e));
+                            break;
+                        }
+                    }
+                }
+            })
+            .map_err(|_| DCMIError::InnerError)?;
+        subscribed.await.map_err(|_| DCMIError::InnerError)??;
+        Ok(AsyncEventStream { rx })
+    }
+}
+
+/// Fault events delivered by [`AsyncDCMI::fault_events`]
+#[derive(Debug)]
+pub struct AsyncEventStream {
+    rx: mpsc::Receiver<DCMIResult<FaultEvent>>,
+}
+
+impl AsyncEventStream {
+    /// Wait for the next event
+    ///
+    /// # Returns
+    /// `None` after the reading thread stopped, which is preceded by the error that stopped it
+    pub async fn next(&mut self) -> Option<DCMIResult<FaultEvent>> {
+        self.rx.recv().await
+    }
+}
+
+/// Async handle to a chip
+#[derive(Debug, Clone)]
+pub struct AsyncChip {
+    chip: OwnedChip,
+}
+
+impl From<OwnedChip> for AsyncChip {
+    fn from(chip: OwnedChip) -> Self {
+        AsyncChip { chip }
+    }
+}
+
+/// Generate async wrappers of `Chip` queries
+macro_rules! async_queries {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        $(
+            #[doc = concat!("See [`Chip::", stringify!($name), "`]")]
+            pub async fn $name(&self $(, $arg: $ty)*) -> DCMIResult<$ret> {
+                self.run(move |chip| chip.$name($($arg),*)).await
+            }
+        )*
+    };
+}
+
+impl AsyncChip {
+    /// Id of the card this chip belongs to
+    pub fn card_id(&self) -> u32 {
+        self.chip.card_id()
+    }
+
+    /// Chip id inside its card
+    pub fn id(&self) -> u32 {
+        self.chip.id()
+    }
+
+    /// Synchronous handle
+    pub fn owned(&self) -> &OwnedChip {
+        &self.chip
+    }
+
+    /// Run any query of the chip on the blocking thread pool
+    pub async fn run<T, F>(&self, f: F) -> DCMIResult<T>
+    where
+        F: FnOnce(Chip<'_>) -> DCMIResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let chip = self.chip.clone();
+        blocking(move || f(chip.chip())).await
+    }
+
+    async_queries! {
+        get_health() -> HealthState;
+        get_temperature() -> Celsius;
+        get_temperatures() -> Temperatures;
+        get_temperature_thresholds() -> TemperatureThresholds;
+        get_power_info() -> Watts;
+        get_utilization_rate(utilization_type: UtilizationType) -> u32;
+        get_utilization_rates(utilization_types: Vec<UtilizationType>) -> Vec<(UtilizationType, u32)>;
+        get_ai_core_utilization_per_core() -> Vec<u32>;
+        get_frequency(frequency_type: FrequencyType) -> MegaHertz;
+        get_frequencies() -> Frequencies;
+        get_ctrl_cpu_info() -> CtrlCpuInfo;
+        get_vector_core_count() -> u32;
+        get_vector_core_utilization() -> u32;
+        get_vector_core_frequency() -> MegaHertz;
+        get_throttle_status() -> ThrottleStatus;
+        get_low_power_status() -> bool;
+        get_memory_info() -> MemoryInfo;
+        get_hbm_info() -> HBMInfo;
+        get_pcie_info() -> PCIEInfo;
+        get_ecc_summary() -> ECCSummary;
+        get_errors() -> Vec<ErrorRecord>;
+        get_processes() -> Vec<ProcessMemoryInfo>;
+        check_health() -> ChipHealth;
+    }
+
+    /// See [`Chip::snapshot`]
+    pub async fn snapshot(&self) -> DCMIResult<ChipSnapshot> {
+        self.run(|chip| Ok(chip.snapshot())).await
+    }
+
+    /// See [`Chip::status`]
+    pub async fn status(&self) -> DCMIResult<ChipStatus> {
+        self.run(|chip| Ok(chip.status())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_queries_on_blocking_pool() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let dcmi = AsyncDCMI::from(Arc::new(DCMI { _private: () }));
+        let thread = runtime
+            .block_on(dcmi.run(|_| Ok(std::thread::current().id())))
+            .unwrap();
+        assert_ne!(thread, std::thread::current().id());
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            runtime.block_on(dcmi.run(|_| -> DCMIResult<()> { panic!("query panicked") }))
+        }));
+        assert!(panicked.is_err());
+    }
+}
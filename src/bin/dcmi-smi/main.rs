@@ -0,0 +1,481 @@
+//! `dcmi-smi`: overview of the NPUs of a node, in the style of `nvidia-smi`
+//!
+//! ```sh
+//! cargo run --features cli --bin dcmi-smi
+//! ```
+
+use hw_dcmi::device::Chip;
+use hw_dcmi::enums::UtilizationType;
+use hw_dcmi::structs::ECCSummary;
+use hw_dcmi::units::{Celsius, Mebibytes, Watts};
+use hw_dcmi::DCMI;
+use output::{Format, Record, Section, Value};
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::process::ExitCode;
+use std::time::Duration;
+use watch::Changes;
+
+mod output;
+#[cfg(feature = "tui")]
+mod top;
+mod watch;
+
+const USAGE: &str = "\
+Usage: dcmi-smi [COMMAND] [OPTIONS]
+
+Commands:
+  info    table of all NPUs and the processes using them (default)
+  top     live graphs of utilization, HBM usage, power and temperature (`tui` feature)
+
+Options:
+  -f, --format <FORMAT>    table (default), json, csv or prometheus
+  -i, --interval <SECS>    refresh interval of `top`, 2 by default
+  -w, --watch <SECS>       repeat `info` every SECS seconds, highlighting changed values
+  -h, --help               print this help
+  -V, --version            print the version";
+
+/// Placeholder of values a chip does not report
+const MISSING: &str = "-";
+
+const CHIP_HEADERS: [&str; 9] = [
+    "NPU",
+    "Model",
+    "Bus-Id",
+    "Health",
+    "Temp",
+    "Power",
+    "AICore",
+    "Memory-Usage",
+    "ECC SB/DB",
+];
+const PROCESS_HEADERS: [&str; 3] = ["NPU", "PID", "Memory"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Info,
+    Top,
+    Help,
+    Version,
+}
+
+/// Parsed command line
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    command: Command,
+    format: Format,
+    interval: Option<Duration>,
+    watch: Option<Duration>,
+}
+
+fn parse_secs(secs: &str) -> Result<Duration, String> {
+    match secs.parse() {
+        Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+        _ => Err(format!("invalid number of seconds `{secs}`")),
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut command = None;
+    let mut format = Format::default();
+    let mut interval = None;
+    let mut watch = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("`{arg}` requires a value"));
+        match arg.as_str() {
+            "-f" | "--format" => format = value()?.parse()?,
+            "-i" | "--interval" => interval = Some(parse_secs(&value()?)?),
+            "-w" | "--watch" => watch = Some(parse_secs(&value()?)?),
+            "-h" | "--help" => return Ok(Args::only(Command::Help)),
+            "-V" | "--version" => return Ok(Args::only(Command::Version)),
+            "info" if command.is_none() => command = Some(Command::Info),
+            "top" if command.is_none() => command = Some(Command::Top),
+            other if other.starts_with('-') => return Err(format!("unknown option `{other}`")),
+            other => return Err(format!("unknown command `{other}`")),
+        }
+    }
+    let command = command.unwrap_or(Command::Info);
+    if interval.is_some() && command != Command::Top {
+        return Err("`--interval` only applies to `top`".to_string());
+    }
+    if watch.is_some() && command != Command::Info {
+        return Err("`--watch` only applies to `info`".to_string());
+    }
+    Ok(Args {
+        command,
+        format,
+        interval,
+        watch,
+    })
+}
+
+impl Args {
+    fn only(command: Command) -> Self {
+        Args {
+            command,
+            format: Format::default(),
+            interval: None,
+            watch: None,
+        }
+    }
+}
+
+/// State of a chip as reported by `info`
+#[derive(Debug, Serialize)]
+struct ChipRecord {
+    card_id: u32,
+    chip_id: u32,
+    model: Option<String>,
+    bus_id: Option<String>,
+    health: Option<String>,
+    temperature: Option<Celsius>,
+    power: Option<Watts>,
+    /// unit: %
+    aicore_utilization: Option<u32>,
+    /// HBM of chips which have it, DDR of the others
+    memory_used: Option<Mebibytes>,
+    memory_total: Option<Mebibytes>,
+    /// Single-bit ECC errors of all memories since the last statistics reset
+    ecc_single_bit_errors: Option<u32>,
+    /// Double-bit ECC errors of all memories since the last statistics reset
+    ecc_double_bit_errors: Option<u32>,
+}
+
+impl Record for ChipRecord {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("card_id", Value::text(self.card_id)),
+            ("chip_id", Value::text(self.chip_id)),
+            ("model", Value::Text(self.model.clone())),
+            ("bus_id", Value::Text(self.bus_id.clone())),
+            ("health", Value::Text(self.health.clone())),
+            (
+                "temperature",
+                Value::Number(self.temperature.map(f64::from)),
+            ),
+            ("power", Value::Number(self.power.map(f64::from))),
+            (
+                "aicore_utilization",
+                Value::Number(self.aicore_utilization.map(f64::from)),
+            ),
+            (
+                "memory_used",
+                Value::Number(self.memory_used.map(f64::from)),
+            ),
+            (
+                "memory_total",
+                Value::Number(self.memory_total.map(f64::from)),
+            ),
+            (
+                "ecc_single_bit_errors",
+                Value::Number(self.ecc_single_bit_errors.map(f64::from)),
+            ),
+            (
+                "ecc_double_bit_errors",
+                Value::Number(self.ecc_double_bit_errors.map(f64::from)),
+            ),
+        ]
+    }
+}
+
+/// Device memory used by a process
+#[derive(Debug, Serialize)]
+struct ProcessRecord {
+    card_id: u32,
+    chip_id: u32,
+    pid: i32,
+    memory_usage: Mebibytes,
+}
+
+impl Record for ProcessRecord {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("card_id", Value::text(self.card_id)),
+            ("chip_id", Value::text(self.chip_id)),
+            ("pid", Value::text(self.pid)),
+            (
+                "memory_usage",
+                Value::Number(Some(self.memory_usage.into())),
+            ),
+        ]
+    }
+}
+
+/// Render rows as a table with a border around every column
+///
+/// Cells flagged in `changed` are highlighted, rows without flags are not.
+fn render_table<const N: usize>(
+    headers: [&str; N],
+    rows: &[[String; N]],
+    changed: &[[bool; N]],
+) -> String {
+    let mut widths = headers.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let border: String = widths
+        .iter()
+        .map(|width| format!("+{}", "-".repeat(width + 2)))
+        .collect::<String>()
+        + "+";
+    let line = |cells: Vec<&str>, changed: Option<&[bool; N]>| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                let cell = format!("{cell:<width$}");
+                if changed.is_some_and(|changed| changed[column]) {
+                    format!("| {} ", watch::highlight(&cell))
+                } else {
+                    format!("| {cell} ")
+                }
+            })
+            .collect::<String>()
+            + "|"
+    };
+
+    let mut table = vec![border.clone(), line(headers.to_vec(), None), border.clone()];
+    for (index, row) in rows.iter().enumerate() {
+        let cells = row.iter().map(String::as_str).collect();
+        table.push(line(cells, changed.get(index)));
+    }
+    if !rows.is_empty() {
+        table.push(border);
+    }
+    table.join("\n")
+}
+
+fn or_missing<T>(value: Option<T>, format: impl FnOnce(T) -> String) -> String {
+    value.map_or_else(|| MISSING.to_string(), format)
+}
+
+fn chip_record(chip: &Chip) -> ChipRecord {
+    let snapshot = chip.snapshot();
+    // HBM is the device memory of chips which have it, DDR of the others
+    let memory = match (snapshot.hbm, snapshot.memory) {
+        (Some(hbm), _) => Some((hbm.memory_usage, hbm.memory_size)),
+        (None, Some(memory)) => Some((
+            memory.memory_size.saturating_sub(memory.memory_available),
+            memory.memory_size,
+        )),
+        (None, None) => None,
+    };
+    let ecc = chip.get_ecc_summary().ok();
+    ChipRecord {
+        card_id: chip.card_id(),
+        chip_id: chip.id(),
+        model: chip.identity().ok().map(|identity| identity.info.model()),
+        bus_id: chip.get_pcie_info().ok().map(|pcie| pcie.bdf()),
+        health: snapshot.health.map(|health| health.to_string()),
+        temperature: snapshot.temperatures.map(|temps| temps.chip),
+        power: snapshot.power,
+        aicore_utilization: snapshot
+            .utilization
+            .get(UtilizationType::AiCore.as_str())
+            .copied(),
+        memory_used: memory.map(|(used, _)| used),
+        memory_total: memory.map(|(_, total)| total),
+        ecc_single_bit_errors: ecc.as_ref().map(ECCSummary::single_bit_error_cnt),
+        ecc_double_bit_errors: ecc.as_ref().map(ECCSummary::double_bit_error_cnt),
+    }
+}
+
+fn chip_row(record: &ChipRecord) -> [String; 9] {
+    let memory = record.memory_used.zip(record.memory_total);
+    let ecc = record
+        .ecc_single_bit_errors
+        .zip(record.ecc_double_bit_errors);
+    [
+        format!("{}/{}", record.card_id, record.chip_id),
+        or_missing(record.model.clone(), |model| model),
+        or_missing(record.bus_id.clone(), |bus_id| bus_id),
+        or_missing(record.health.clone(), |health| health),
+        or_missing(record.temperature, |temp| temp.to_string()),
+        or_missing(record.power, |power| format!("{power:.1}")),
+        or_missing(record.aicore_utilization, |rate| format!("{rate}%")),
+        or_missing(memory, |(used, total)| format!("{} / {total}", used.0)),
+        or_missing(ecc, |(single, double)| format!("{single}/{double}")),
+    ]
+}
+
+fn process_records(chip: &Chip) -> Vec<ProcessRecord> {
+    chip.get_processes()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|process| ProcessRecord {
+            card_id: chip.card_id(),
+            chip_id: chip.id(),
+            pid: process.pid,
+            memory_usage: process.memory_usage,
+        })
+        .collect()
+}
+
+fn process_row(record: &ProcessRecord) -> [String; 3] {
+    [
+        format!("{}/{}", record.card_id, record.chip_id),
+        record.pid.to_string(),
+        record.memory_usage.to_string(),
+    ]
+}
+
+/// Tables of the previous round of `info`, when watching
+struct InfoChanges {
+    chips: Changes<9>,
+    processes: Changes<3>,
+}
+
+fn info(
+    dcmi: &DCMI,
+    format: Format,
+    mut changes: Option<&mut InfoChanges>,
+) -> hw_dcmi::error::DCMIResult<()> {
+    let chips = dcmi.all_chips()?;
+    let chip_records: Vec<_> = chips.iter().map(chip_record).collect();
+    let process_records: Vec<_> = chips.iter().flat_map(process_records).collect();
+    let sections = [
+        Section::new("chip", &chip_records),
+        Section::new("process", &process_records),
+    ];
+    if let Some(output) = output::render(format, &sections) {
+        println!("{output}");
+        return Ok(());
+    }
+
+    println!(
+        "DCMI version: {}    Driver version: {}",
+        dcmi.get_dcmi_version()
+            .unwrap_or_else(|_| MISSING.to_string()),
+        dcmi.get_driver_version()
+            .unwrap_or_else(|_| MISSING.to_string())
+    );
+    let rows: Vec<_> = chip_records.iter().map(chip_row).collect();
+    let changed = match changes.as_mut() {
+        Some(changes) => changes.chips.update(&rows),
+        None => Vec::new(),
+    };
+    println!("{}", render_table(CHIP_HEADERS, &rows, &changed));
+    println!();
+    let rows: Vec<_> = process_records.iter().map(process_row).collect();
+    let changed = match changes {
+        Some(changes) => changes.processes.update(&rows),
+        None => Vec::new(),
+    };
+    println!("{}", render_table(PROCESS_HEADERS, &rows, &changed));
+    if rows.is_empty() {
+        println!("No running processes found");
+    }
+    Ok(())
+}
+
+/// Repeat `info` forever
+///
+/// On a terminal the screen is redrawn and values which changed since the previous round are
+/// highlighted. Otherwise, and for machine-readable formats, every round is appended to the
+/// output, separated by an empty line except for JSON which takes one line per round.
+fn watch(dcmi: &DCMI, format: Format, every: Duration) -> hw_dcmi::error::DCMIResult<()> {
+    let redraw = format == Format::Table && std::io::stdout().is_terminal();
+    let mut changes = InfoChanges {
+        chips: Changes::new(1),
+        processes: Changes::new(2),
+    };
+    loop {
+        if redraw {
+            print!("{}", watch::CLEAR);
+            println!("Every {}s: dcmi-smi\n", every.as_secs());
+        }
+        info(dcmi, format, redraw.then_some(&mut changes))?;
+        if !redraw && format != Format::Json {
+            println!();
+        }
+        std::thread::sleep(every);
+    }
+}
+
+fn exit_code(result: Result<(), impl Display>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("dcmi-smi: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage_error(message: impl Display) -> ExitCode {
+    eprintln!("{message}\n\n{USAGE}");
+    ExitCode::from(2)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => return usage_error(message),
+    };
+    match args.command {
+        Command::Info => exit_code(DCMI::init().and_then(|dcmi| match args.watch {
+            Some(every) => watch(&dcmi, args.format, every),
+            None => info(&dcmi, args.format, None),
+        })),
+        #[cfg(feature = "tui")]
+        Command::Top => exit_code(top::run(
+            args.interval.unwrap_or(top::DEFAULT_INTERVAL),
+            args.format,
+        )),
+        #[cfg(not(feature = "tui"))]
+        Command::Top => usage_error("`top` requires dcmi-smi built with the `tui` feature"),
+        Command::Help => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Command::Version => {
+            println!("dcmi-smi {}", env!("CARGO_PKG_VERSION"));
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_bordered_table() {
+        let rows = [["0/0".to_string(), "12345".to_string(), "1024MB".to_string()]];
+        assert_eq!(
+            render_table(PROCESS_HEADERS, &rows, &[]),
+            "\
++-----+-------+--------+
+| NPU | PID   | Memory |
++-----+-------+--------+
+| 0/0 | 12345 | 1024MB |
++-----+-------+--------+"
+        );
+    }
+
+    #[test]
+    fn parses_options_anywhere() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(parse(&[]), Ok(Args::only(Command::Info)));
+        assert_eq!(
+            parse(&["--format", "json", "top", "-i", "5"]),
+            Ok(Args {
+                command: Command::Top,
+                format: Format::Json,
+                interval: Some(Duration::from_secs(5)),
+                watch: None,
+            })
+        );
+        assert_eq!(parse(&["info", "-V"]), Ok(Args::only(Command::Version)));
+        assert!(parse(&["info", "--interval", "5"]).is_err());
+        assert!(parse(&["top", "--watch", "5"]).is_err());
+        assert!(parse(&["--watch", "0"]).is_err());
+        assert!(parse(&["-f"]).is_err());
+        assert!(parse(&["info", "info"]).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Machine-readable output shared by all subcommands
+//!
+//! A subcommand collects its data as [`Record`]s grouped in [`Section`]s, which render as
+//! - JSON: one object per output mapping the section names to arrays of records, on one line
+//! - CSV: one table per section with a header row, sections separated by an empty line
+//! - Prometheus: text exposition format, numeric fields become gauges labeled with the text fields
+
+use serde::Serialize;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Human-readable output of the subcommand
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Prometheus,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "prometheus" => Ok(Format::Prometheus),
+            _ => Err(format!(
+                "unknown format `{s}`, expected table, json, csv or prometheus"
+            )),
+        }
+    }
+}
+
+/// Value of a record field
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Identifying or descriptive value, a label in Prometheus output
+    Text(Option<String>),
+    /// Measurement, a gauge in Prometheus output
+    Number(Option<f64>),
+}
+
+impl Value {
+    pub fn text(value: impl ToString) -> Self {
+        Value::Text(Some(value.to_string()))
+    }
+}
+
+/// Row of a subcommand output
+pub trait Record: Serialize {
+    /// Fields of the record in column order, named like the serialized fields
+    fn fields(&self) -> Vec<(&'static str, Value)>;
+}
+
+/// Records of one kind, e.g. the chips of `info`
+#[derive(Debug)]
+pub struct Section {
+    /// Key of the section in JSON output and infix of the Prometheus metrics, e.g. `chip`
+    name: &'static str,
+    json: Vec<serde_json::Value>,
+    fields: Vec<Vec<(&'static str, Value)>>,
+}
+
+impl Section {
+    pub fn new<R: Record>(name: &'static str, records: &[R]) -> Self {
+        Section {
+            name,
+            json: records
+                .iter()
+                .map(|record| serde_json::to_value(record).expect("records serialize to JSON"))
+                .collect(),
+            fields: records.iter().map(Record::fields).collect(),
+        }
+    }
+}
+
+/// Render sections in a machine-readable format, `None` for [`Format::Table`] which every
+/// subcommand renders itself
+pub fn render(format: Format, sections: &[Section]) -> Option<String> {
+    match format {
+        Format::Table => None,
+        Format::Json => Some(json(sections)),
+        Format::Csv => Some(csv(sections)),
+        Format::Prometheus => Some(prometheus(sections)),
+    }
+}
+
+fn json(sections: &[Section]) -> String {
+    let object: serde_json::Map<_, _> = sections
+        .iter()
+        .map(|section| (section.name.to_string(), section.json.clone().into()))
+        .collect();
+    serde_json::Value::Object(object).to_string()
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Text(Some(text)) if text.contains([',', '"', '\n']) => {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        }
+        Value::Text(Some(text)) => text.clone(),
+        Value::Number(Some(number)) => number.to_string(),
+        Value::Text(None) | Value::Number(None) => String::new(),
+    }
+}
+
+fn csv(sections: &[Section]) -> String {
+    let tables: Vec<String> = sections
+        .iter()
+        .filter_map(|section| {
+            let header = section.fields.first()?.iter().map(|(name, _)| *name);
+            let mut lines = vec![header.collect::<Vec<_>>().join(",")];
+            for fields in &section.fields {
+                let cells: Vec<String> = fields.iter().map(|(_, value)| csv_cell(value)).collect();
+                lines.push(cells.join(","));
+            }
+            Some(lines.join("\n"))
+        })
+        .collect();
+    tables.join("\n\n")
+}
+
+fn prometheus(sections: &[Section]) -> String {
+    let mut out = String::new();
+    for section in sections {
+        let Some(first) = section.fields.first() else {
+            continue;
+        };
+        for (name, _) in first
+            .iter()
+            .filter(|(_, value)| matches!(value, Value::Number(_)))
+        {
+            let metric = format!("dcmi_smi_{}_{name}", section.name);
+            let _ = writeln!(out, "# TYPE {metric} gauge");
+            for fields in &section.fields {
+                let mut labels = Vec::new();
+                let mut sample = None;
+                for (field, value) in fields {
+                    match value {
+                        Value::Text(Some(text)) => labels.push(format!(
+                            "{field}=\"{}\"",
+                            text.replace('\\', "\\\\").replace('"', "\\\"")
+                        )),
+                        Value::Number(number) if field == name => sample = *number,
+                        _ => {}
+                    }
+                }
+                if let Some(sample) = sample {
+                    let _ = writeln!(out, "{metric}{{{}}} {sample}", labels.join(","));
+                }
+            }
+        }
+    }
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Chip {
+        chip: u32,
+        model: Option<&'static str>,
+        power: Option<f64>,
+    }
+
+    impl Record for Chip {
+        fn fields(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("chip", Value::text(self.chip)),
+                ("model", Value::Text(self.model.map(String::from))),
+                ("power", Value::Number(self.power)),
+            ]
+        }
+    }
+
+    #[test]
+    fn renders_every_format() {
+        let chips = [
+            Chip {
+                chip: 0,
+                model: Some("Ascend910B3"),
+                power: Some(92.5),
+            },
+            Chip {
+                chip: 1,
+                model: Some("a,\"b\""),
+                power: None,
+            },
+        ];
+        let sections = [Section::new("chip", &chips)];
+        assert_eq!(render(Format::Table, &sections), None);
+        assert_eq!(
+            render(Format::Json, &sections).unwrap(),
+            r#"{"chip":[{"chip":0,"model":"Ascend910B3","power":92.5},{"chip":1,"model":"a,\"b\"","power":null}]}"#
+        );
+        assert_eq!(
+            render(Format::Csv, &sections).unwrap(),
+            "chip,model,power\n0,Ascend910B3,92.5\n1,\"a,\"\"b\"\"\","
+        );
+        assert_eq!(
+            render(Format::Prometheus, &sections).unwrap(),
+            "# TYPE dcmi_smi_chip_power gauge\n\
+             dcmi_smi_chip_power{chip=\"0\",model=\"Ascend910B3\"} 92.5"
+        );
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! `dcmi-smi top`: live graphs of the NPUs, in the style of `htop`
+//!
+//! The chips are read by a [`Sampler`] in the background, so a slow driver call delays the graphs
+//! but never the keyboard. With a machine-readable `--format` the latest values of every chip are
+//! printed once per interval instead, JSON as one line per interval, CSV and Prometheus followed
+//! by an empty line.
+
+use crate::output::{self, Format, Record, Section, Value};
+use hw_dcmi::device::OwnedChip;
+use hw_dcmi::enums::UnitType;
+use hw_dcmi::monitor::{Metric, Sampler, SamplerConfig};
+use hw_dcmi::DCMI;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Sparkline, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Metrics shown for every chip, in column order
+const METRICS: [Metric; 4] = [
+    Metric::AiCoreUtilization,
+    Metric::HbmUsage,
+    Metric::Power,
+    Metric::Temperature,
+];
+const TITLES: [&str; 4] = ["AICore %", "HBM %", "Power W", "Temp C"];
+
+/// Values kept per chip and metric, enough to fill a wide terminal
+const HISTORY: usize = 512;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Latest values of every metric of a chip, oldest first
+#[derive(Debug, Default)]
+struct ChipHistory {
+    values: [VecDeque<f64>; METRICS.len()],
+}
+
+impl ChipHistory {
+    fn push(&mut self, metric: usize, value: f64) {
+        let values = &mut self.values[metric];
+        if values.len() == HISTORY {
+            values.pop_front();
+        }
+        values.push_back(value);
+    }
+
+    fn latest(&self, metric: usize) -> Option<f64> {
+        self.values[metric].back().copied()
+    }
+
+    /// The last `width` values of a metric, rounded for a sparkline
+    fn sparkline(&self, metric: usize, width: usize) -> Vec<u64> {
+        let values = &self.values[metric];
+        let skip = values.len().saturating_sub(width);
+        values
+            .iter()
+            .skip(skip)
+            .map(|value| value.max(0.0).round() as u64)
+            .collect()
+    }
+}
+
+/// Latest values of a chip, names and units as in [`Metric`]
+#[derive(Debug, Serialize)]
+struct TopRecord {
+    card_id: u32,
+    chip_id: u32,
+    ai_core_utilization: Option<f64>,
+    hbm_usage: Option<f64>,
+    power: Option<f64>,
+    temperature: Option<f64>,
+}
+
+impl Record for TopRecord {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("card_id", Value::text(self.card_id)),
+            ("chip_id", Value::text(self.chip_id)),
+            (
+                "ai_core_utilization",
+                Value::Number(self.ai_core_utilization),
+            ),
+            ("hbm_usage", Value::Number(self.hbm_usage)),
+            ("power", Value::Number(self.power)),
+            ("temperature", Value::Number(self.temperature)),
+        ]
+    }
+}
+
+struct Top {
+    chips: Vec<OwnedChip>,
+    interval: Duration,
+    sampler: Sampler,
+    history: BTreeMap<(u32, u32), ChipHistory>,
+    table: TableState,
+}
+
+impl Top {
+    fn new(chips: Vec<OwnedChip>, interval: Duration) -> std::io::Result<Self> {
+        let sampler = spawn_sampler(&chips, interval)?;
+        Ok(Top {
+            chips,
+            interval,
+            sampler,
+            history: BTreeMap::new(),
+            table: TableState::default().with_selected(0),
+        })
+    }
+
+    /// Restart the sampler with another interval, the history is kept
+    fn set_interval(&mut self, interval: Duration) -> std::io::Result<()> {
+        let interval = interval.clamp(MIN_INTERVAL, MAX_INTERVAL);
+        if interval != self.interval {
+            self.collect();
+            self.sampler = spawn_sampler(&self.chips, interval)?;
+            self.interval = interval;
+        }
+        Ok(())
+    }
+
+    /// Move the samples of the sampler into the history, failed reads are skipped
+    fn collect(&mut self) {
+        for sample in self.sampler.drain() {
+            let Some(metric) = METRICS.iter().position(|metric| *metric == sample.metric) else {
+                continue;
+            };
+            if let Ok(value) = sample.value {
+                self.history
+                    .entry((sample.card_id, sample.chip_id))
+                    .or_default()
+                    .push(metric, value);
+            }
+        }
+    }
+
+    fn record(&self, chip: &OwnedChip) -> TopRecord {
+        let history = self.history.get(&(chip.card_id(), chip.id()));
+        let latest = |metric| history.and_then(|history: &ChipHistory| history.latest(metric));
+        TopRecord {
+            card_id: chip.card_id(),
+            chip_id: chip.id(),
+            ai_core_utilization: latest(0),
+            hbm_usage: latest(1),
+            power: latest(2),
+            temperature: latest(3),
+        }
+    }
+
+    fn selected(&self) -> Option<&OwnedChip> {
+        self.chips.get(self.table.selected()?)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, table, graphs] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(self.chips.len() as u16 + 3),
+            Constraint::Min(0),
+        ])
+        .areas(frame.area());
+
+        let help = format!(
+            "dcmi-smi top    interval {}s    q quit, +/- interval, up/down select",
+            self.interval.as_secs()
+        );
+        frame.render_widget(Paragraph::new(help), header);
+
+        let rows: Vec<Row> = self
+            .chips
+            .iter()
+            .map(|chip| {
+                let history = self.history.get(&(chip.card_id(), chip.id()));
+                let cells = (0..METRICS.len()).map(|metric| {
+                    let latest = history.and_then(|history| history.latest(metric));
+                    Cell::from(
+                        latest.map_or_else(|| "-".to_string(), |value| format!("{value:.1}")),
+                    )
+                });
+                Row::new(
+                    std::iter::once(Cell::from(format!("{}/{}", chip.card_id(), chip.id())))
+                        .chain(cells),
+                )
+            })
+            .collect();
+        let widths = [Constraint::Length(6)]
+            .into_iter()
+            .chain([Constraint::Length(10); METRICS.len()]);
+        let chip_table = Table::new(rows, widths)
+            .header(
+                Row::new(std::iter::once("NPU").chain(TITLES))
+                    .style(Style::new().add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .block(Block::bordered().title("NPUs"));
+        frame.render_stateful_widget(chip_table, table, &mut self.table);
+
+        if let Some(chip) = self.selected() {
+            let key = (chip.card_id(), chip.id());
+            let title = format!("npu {}/{}", key.0, key.1);
+            let history = self.history.get(&key);
+            for (metric, area) in grid(graphs).into_iter().enumerate() {
+                let width = area.width.saturating_sub(2) as usize;
+                let data =
+                    history.map_or_else(Vec::new, |history| history.sparkline(metric, width));
+                let mut sparkline = Sparkline::default()
+                    .block(Block::bordered().title(format!("{title} {}", TITLES[metric])))
+                    .data(&data);
+                // percentages keep their scale, power and temperature scale to their maximum
+                if TITLES[metric].ends_with('%') {
+                    sparkline = sparkline.max(100);
+                }
+                frame.render_widget(sparkline, area);
+            }
+        }
+    }
+
+    /// Handle a key, `false` if the user quits
+    fn key(&mut self, code: KeyCode) -> std::io::Result<bool> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Char('+') => self.set_interval(self.interval + MIN_INTERVAL)?,
+            KeyCode::Char('-') => self.set_interval(self.interval.saturating_sub(MIN_INTERVAL))?,
+            KeyCode::Down => self.table.select_next(),
+            KeyCode::Up => self.table.select_previous(),
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), Box<dyn Error>> {
+        loop {
+            self.collect();
+            terminal.draw(|frame| self.draw(frame))?;
+            // redraw at least once per interval, and right after every key
+            if event::poll(self.interval)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !self.key(key.code)? {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Top {
+    /// Print the latest values of every chip once per interval, forever
+    fn stream(&mut self, format: Format) -> Result<(), Box<dyn Error>> {
+        loop {
+            std::thread::sleep(self.interval);
+            self.collect();
+            let records: Vec<_> = self.chips.iter().map(|chip| self.record(chip)).collect();
+            let output = output::render(format, &[Section::new("chip", &records)]);
+            println!("{}", output.unwrap_or_default());
+            if format != Format::Json {
+                println!();
+            }
+        }
+    }
+}
+
+fn spawn_sampler(chips: &[OwnedChip], interval: Duration) -> std::io::Result<Sampler> {
+    let config = SamplerConfig::new(interval).metrics(METRICS);
+    Sampler::spawn(chips.to_vec(), config)
+}
+
+/// Split an area into a 2x2 grid, row by row
+fn grid(area: Rect) -> [Rect; 4] {
+    let [top, bottom] = Layout::vertical([Constraint::Percentage(50); 2]).areas(area);
+    let [top_left, top_right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(top);
+    let [bottom_left, bottom_right] =
+        Layout::horizontal([Constraint::Percentage(50); 2]).areas(bottom);
+    [top_left, top_right, bottom_left, bottom_right]
+}
+
+/// Show the live view until the user quits, or stream the values in a machine-readable `format`
+pub fn run(interval: Duration, format: Format) -> Result<(), Box<dyn Error>> {
+    let dcmi = Arc::new(DCMI::init()?);
+    let mut chips = Vec::new();
+    for card in dcmi.owned_cards()? {
+        let npus = card.get_chips()?.into_iter();
+        chips.extend(npus.filter(|chip| chip.unit_type() == UnitType::NPU));
+    }
+    let mut top = Top::new(chips, interval.clamp(MIN_INTERVAL, MAX_INTERVAL))?;
+    if format != Format::Table {
+        return top.stream(format);
+    }
+
+    let mut terminal = ratatui::init();
+    let result = top.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = ChipHistory::default();
+        for value in 0..HISTORY + 10 {
+            history.push(0, value as f64);
+        }
+        assert_eq!(history.values[0].len(), HISTORY);
+        assert_eq!(history.latest(0), Some((HISTORY + 9) as f64));
+        assert_eq!(history.latest(1), None);
+        let tail = (HISTORY as u64 + 7..HISTORY as u64 + 10).collect::<Vec<_>>();
+        assert_eq!(history.sparkline(0, 3), tail);
+        assert_eq!(history.sparkline(1, 3), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,66 @@
+//! `dcmi-smi --watch`: repeated output highlighting what changed since the previous round
+
+use std::collections::HashMap;
+
+/// Clears the terminal and moves the cursor home
+pub const CLEAR: &str = "\x1b[2J\x1b[H";
+const HIGHLIGHT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+/// Highlight a padded cell
+pub fn highlight(cell: &str) -> String {
+    format!("{HIGHLIGHT}{cell}{RESET}")
+}
+
+/// Rows of the previous round of a table, to find the cells which changed since then
+///
+/// Rows are matched by their first `key_columns` cells, e.g. the NPU and the PID of a process.
+#[derive(Debug)]
+pub struct Changes<const N: usize> {
+    key_columns: usize,
+    previous: Option<HashMap<Vec<String>, [String; N]>>,
+}
+
+impl<const N: usize> Changes<N> {
+    pub fn new(key_columns: usize) -> Self {
+        Changes {
+            key_columns,
+            previous: None,
+        }
+    }
+
+    /// Which cells of `rows` differ from the previous round, rows new since then are changed as a
+    /// whole, nothing is changed in the first round
+    pub fn update(&mut self, rows: &[[String; N]]) -> Vec<[bool; N]> {
+        let key = |row: &[String; N]| row[..self.key_columns].to_vec();
+        let changed = rows
+            .iter()
+            .map(|row| match &self.previous {
+                None => [false; N],
+                Some(previous) => match previous.get(&key(row)) {
+                    None => [true; N],
+                    Some(last) => std::array::from_fn(|column| row[column] != last[column]),
+                },
+            })
+            .collect();
+        self.previous = Some(rows.iter().map(|row| (key(row), row.clone())).collect());
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_changed_and_new_rows() {
+        let row = |npu: &str, temp: &str| [npu.to_string(), temp.to_string()];
+        let mut changes = Changes::new(1);
+        assert_eq!(changes.update(&[row("0/0", "50C")]), [[false; 2]]);
+        assert_eq!(
+            changes.update(&[row("0/0", "52C"), row("1/0", "40C")]),
+            [[false, true], [true; 2]]
+        );
+        assert_eq!(changes.update(&[row("1/0", "40C")]), [[false; 2]]);
+    }
+}
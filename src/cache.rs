@@ -0,0 +1,292 @@
+//! Cache of the static information of chips
+//!
+//! Chip information, electronic label, board information and PCIe ids do not change while a
+//! driver is loaded, yet exporters that label every sample with them query them on every
+//! scrape. A [`StaticInfoCache`] reads each of them once per chip and serves it until the entry
+//! is older than the configured time to live or [`DCMI::driver_generation`] moves on. Dynamic
+//! metrics are not cached, [`CachedChip`] passes them through to the driver.
+//!
+//! ```no_run
+//! # use hw_dcmi::DCMI;
+//! use hw_dcmi::cache::StaticInfoCache;
+//! use hw_dcmi::query::ChipQuery;
+//! use std::time::Duration;
+//!
+//! let dcmi = DCMI::init().unwrap();
+//! let cache = StaticInfoCache::new(Duration::from_secs(600));
+//! loop {
+//!     for chip in dcmi.all_chips().unwrap() {
+//!         let chip = cache.wrap(chip);
+//!         // served from the cache after the first round
+//!         let model = chip.get_chip_info().unwrap().model();
+//!         println!("{model}: {:?}", chip.get_temperature());
+//!     }
+//!     std::thread::sleep(Duration::from_secs(10));
+//! }
+//! ```
+//!
+//! [`DCMI::driver_generation`]: crate::DCMI::driver_generation
+
+use crate::device::Chip;
+use crate::enums::{DeviceType, FrequencyType, HealthState, UtilizationType};
+use crate::error::DCMIResult;
+use crate::query::ChipQuery;
+use crate::structs::{
+    BoardInfo, ChipInfo, ECCInfo, ELabelInfo, HBMInfo, MemoryInfo, PCIEInfo, ProcessMemoryInfo,
+};
+use crate::units::{Celsius, MegaHertz, Watts};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Value read from the driver, with when and in which driver generation
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    value: T,
+    read_at: Instant,
+    generation: u64,
+}
+
+/// Static information of one chip read so far
+#[derive(Debug, Default)]
+struct CachedInfo {
+    chip_info: Option<Entry<ChipInfo>>,
+    elabel: Option<Entry<ELabelInfo>>,
+    board: Option<Entry<BoardInfo>>,
+    pcie: Option<Entry<PCIEInfo>>,
+}
+
+/// Static information of chips, keyed by `(card_id, chip_id)`
+///
+/// Failed queries are not cached, they are retried on the next call.
+#[derive(Debug)]
+pub struct StaticInfoCache {
+    ttl: Duration,
+    chips: Mutex<HashMap<(u32, u32), CachedInfo>>,
+}
+
+impl StaticInfoCache {
+    /// Cache entries for `ttl` at most, `Duration::MAX` keeps them until the driver is upgraded
+    pub fn new(ttl: Duration) -> Self {
+        StaticInfoCache {
+            ttl,
+            chips: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Drop all entries
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Chip information, see [`Chip::get_chip_info`]
+    pub fn chip_info(&self, chip: &impl ChipQuery) -> DCMIResult<ChipInfo> {
+        self.get(
+            chip_key(chip),
+            |info| &mut info.chip_info,
+            || chip.get_chip_info(),
+        )
+    }
+
+    /// PCIe ids and address, see [`Chip::get_pcie_info`]
+    pub fn pcie_info(&self, chip: &impl ChipQuery) -> DCMIResult<PCIEInfo> {
+        self.get(
+            chip_key(chip),
+            |info| &mut info.pcie,
+            || chip.get_pcie_info(),
+        )
+    }
+
+    /// Electronic label, see [`Chip::get_elabel_info`]
+    pub fn elabel_info(&self, chip: &Chip) -> DCMIResult<ELabelInfo> {
+        self.get(
+            chip_key(chip),
+            |info| &mut info.elabel,
+            || chip.get_elabel_info(),
+        )
+    }
+
+    /// Board information, see [`Chip::get_board_info`]
+    pub fn board_info(&self, chip: &Chip) -> DCMIResult<BoardInfo> {
+        self.get(
+            chip_key(chip),
+            |info| &mut info.board,
+            || chip.get_board_info(),
+        )
+    }
+
+    /// Wrap `chip` so its static queries are served from this cache
+    pub fn wrap<C: ChipQuery>(&self, chip: C) -> CachedChip<'_, C> {
+        CachedChip { chip, cache: self }
+    }
+
+    fn get<T: Clone>(
+        &self,
+        key: (u32, u32),
+        field: fn(&mut CachedInfo) -> &mut Option<Entry<T>>,
+        read: impl FnOnce() -> DCMIResult<T>,
+    ) -> DCMIResult<T> {
+        self.get_at(
+            key,
+            crate::upgrade::generation(),
+            Instant::now(),
+            field,
+            read,
+        )
+    }
+
+    fn get_at<T: Clone>(
+        &self,
+        key: (u32, u32),
+        generation: u64,
+        now: Instant,
+        field: fn(&mut CachedInfo) -> &mut Option<Entry<T>>,
+        read: impl FnOnce() -> DCMIResult<T>,
+    ) -> DCMIResult<T> {
+        if let Some(entry) = field(self.lock().entry(key).or_default()) {
+            if entry.generation == generation
+                && now.saturating_duration_since(entry.read_at) < self.ttl
+            {
+                return Ok(entry.value.clone());
+            }
+        }
+        // read without holding the lock, a concurrent reader of the same chip does the same work
+        let value = read()?;
+        *field(self.lock().entry(key).or_default()) = Some(Entry {
+            value: value.clone(),
+            read_at: now,
+            generation,
+        });
+        Ok(value)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(u32, u32), CachedInfo>> {
+        self.chips.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn chip_key(chip: &impl ChipQuery) -> (u32, u32) {
+    (chip.card_id(), chip.id())
+}
+
+/// Chip whose chip and PCIe information come from a [`StaticInfoCache`], created by
+/// [`StaticInfoCache::wrap`]
+#[derive(Debug, Clone, Copy)]
+pub struct CachedChip<'c, C> {
+    chip: C,
+    cache: &'c StaticInfoCache,
+}
+
+impl<C> CachedChip<'_, C> {
+    /// Chip queried on cache misses and for dynamic metrics
+    pub fn inner(&self) -> &C {
+        &self.chip
+    }
+}
+
+impl<C: ChipQuery> ChipQuery for CachedChip<'_, C> {
+    fn card_id(&self) -> u32 {
+        self.chip.card_id()
+    }
+
+    fn id(&self) -> u32 {
+        self.chip.id()
+    }
+
+    fn get_chip_info(&self) -> DCMIResult<ChipInfo> {
+        self.cache.chip_info(&self.chip)
+    }
+
+    fn get_health(&self) -> DCMIResult<HealthState> {
+        self.chip.get_health()
+    }
+
+    fn get_temperature(&self) -> DCMIResult<Celsius> {
+        self.chip.get_temperature()
+    }
+
+    fn get_power_info(&self) -> DCMIResult<Watts> {
+        self.chip.get_power_info()
+    }
+
+    fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32> {
+        self.chip.get_utilization_rate(utilization_type)
+    }
+
+    fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz> {
+        self.chip.get_frequency(frequency_type)
+    }
+
+    fn get_memory_info(&self) -> DCMIResult<MemoryInfo> {
+        self.chip.get_memory_info()
+    }
+
+    fn get_hbm_info(&self) -> DCMIResult<HBMInfo> {
+        self.chip.get_hbm_info()
+    }
+
+    fn get_pcie_info(&self) -> DCMIResult<PCIEInfo> {
+        self.cache.pcie_info(&self.chip)
+    }
+
+    fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo> {
+        self.chip.get_ecc_info(device_type)
+    }
+
+    fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>> {
+        self.chip.get_processes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DCMIError;
+    use std::cell::Cell;
+
+    #[test]
+    fn entries_expire_after_ttl_and_upgrade() {
+        let cache = StaticInfoCache::new(Duration::from_secs(60));
+        let reads = Cell::new(0);
+        let start = Instant::now();
+        let get = |generation, secs| {
+            cache.get_at(
+                (0, 0),
+                generation,
+                start + Duration::from_secs(secs),
+                |info| &mut info.chip_info,
+                || {
+                    reads.set(reads.get() + 1);
+                    Ok(ChipInfo {
+                        chip_type: "Ascend".to_string(),
+                        chip_name: "910B3".to_string(),
+                        chip_version: "V1".to_string(),
+                        aicore_count: 20,
+                    })
+                },
+            )
+        };
+        get(0, 0).unwrap();
+        get(0, 59).unwrap();
+        assert_eq!(reads.get(), 1);
+        get(0, 60).unwrap();
+        assert_eq!(reads.get(), 2);
+        get(1, 61).unwrap();
+        assert_eq!(reads.get(), 3);
+
+        // errors are not cached
+        let failed = cache.get_at(
+            (0, 1),
+            0,
+            start,
+            |info| &mut info.pcie,
+            || Err(DCMIError::CodeTimeOut),
+        );
+        assert!(failed.is_err());
+        assert!(cache.lock()[&(0, 1)].pcie.is_none());
+    }
+}
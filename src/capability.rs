@@ -0,0 +1,154 @@
+//! Calls available with the installed DCMI library and driver
+//!
+//! Newer DCMI functions fail with [`DCMIError::NotSupport`] on older drivers. Wrappers with an
+//! older variant of a call check [`DCMI::supports`] to skip calls the loaded library does not
+//! export, and fall back to the older variant when the driver rejects the newer one. Huawei does
+//! not document which driver release introduced which call, so versions are not used to decide
+//! support.
+//!
+//! [`DCMI::init`] reads the versions, they are read again once [`DCMI::driver_generation`] moves
+//! on after an in-place driver upgrade.
+//!
+//! [`DCMIError::NotSupport`]: crate::error::DCMIError::NotSupport
+//! [`DCMI::init`]: crate::DCMI::init
+//! [`DCMI::supports`]: crate::DCMI::supports
+//! [`DCMI::driver_generation`]: crate::DCMI::driver_generation
+
+use crate::utils::impl_as_str;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Release version of the DCMI library or the NPU driver, e.g. `24.1.rc2`
+///
+/// Only the first three components are compared, a release candidate (`rc2`) counts as patch
+/// level 0 and further components are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('.');
+        let mut number = |name| {
+            parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(|| format!("version `{s}` lacks a numeric {name} component"))
+        };
+        let major = number("major")?;
+        let minor = number("minor")?;
+        let patch = number("patch").unwrap_or(0);
+        Ok(Version::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// DCMI call which older drivers lack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Feature {
+    /// `dcmi_get_device_memory_info_v3`, with available memory and huge pages
+    MemoryInfoV3,
+    /// `dcmi_get_device_pcie_info_v2`, with the PCI domain
+    PcieInfoV2,
+}
+
+impl_as_str!(Feature);
+
+impl Feature {
+    /// Whether the loaded library exports the call
+    #[cfg(feature = "dynamic-loading")]
+    pub(crate) fn is_loaded(self) -> bool {
+        let Some(library) = crate::loader::library() else {
+            return true;
+        };
+        match self {
+            Feature::MemoryInfoV3 => library.dcmi_get_device_memory_info_v3.is_ok(),
+            Feature::PcieInfoV2 => library.dcmi_get_device_pcie_info_v2.is_ok(),
+        }
+    }
+
+    #[cfg(not(feature = "dynamic-loading"))]
+    pub(crate) fn is_loaded(self) -> bool {
+        true
+    }
+}
+
+/// Versions read at initialization, `None` if a version could not be read or parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Capabilities {
+    pub dcmi_version: Option<Version>,
+    pub driver_version: Option<Version>,
+}
+
+impl Capabilities {
+    /// Whether `feature` can be used, i.e. the loaded library exports the call
+    ///
+    /// Always true when libdcmi is linked, callers still fall back to the older call when the
+    /// driver rejects the newer one with [`DCMIError::NotSupport`](crate::error::DCMIError::NotSupport).
+    pub fn supports(&self, feature: Feature) -> bool {
+        feature.is_loaded()
+    }
+}
+
+/// Capabilities of the library initialized in this process and the driver generation they were
+/// read in
+static CAPABILITIES: Mutex<Option<(u64, Capabilities)>> = Mutex::new(None);
+
+pub(crate) fn set(generation: u64, capabilities: Capabilities) {
+    *CAPABILITIES.lock().unwrap_or_else(|e| e.into_inner()) = Some((generation, capabilities));
+}
+
+/// Capabilities read in driver generation `generation`, `None` if they must be read again
+pub(crate) fn get(generation: u64) -> Option<Capabilities> {
+    CAPABILITIES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .filter(|&(read_in, _)| read_in == generation)
+        .map(|(_, capabilities)| capabilities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_versions_and_expires_with_generation() {
+        assert_eq!("24.1.rc2".parse(), Ok(Version::new(24, 1, 0)));
+        assert_eq!("7.0.0.5.242".parse(), Ok(Version::new(7, 0, 0)));
+        assert!("unknown".parse::<Version>().is_err());
+
+        // the version does not decide support, an old driver may still have the call
+        let old = Capabilities {
+            dcmi_version: None,
+            driver_version: Some(Version::new(7, 0, 0)),
+        };
+        assert!(old.supports(Feature::MemoryInfoV3));
+
+        set(3, old);
+        assert_eq!(get(3), Some(old));
+        assert_eq!(get(4), None);
+    }
+}
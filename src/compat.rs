@@ -0,0 +1,41 @@
+//! Deprecated signatures kept while users migrate to a reshaped API
+//!
+//! When a public signature changes (e.g. tuples replaced by iterators or raw integers replaced
+//! by unit newtypes), the old signature moves here, marked `#[deprecated]` with the version it
+//! was replaced in and a note naming its replacement, and delegates to the new one. Shims of
+//! methods are provided through extension traits, importing `hw_dcmi::compat::*` brings them
+//! back into scope:
+//!
+//! ```
+//! use hw_dcmi::compat::*;
+//! ```
+//!
+//! Shims are kept for at least one major version after the change they cover and are removed
+//! with the following major release.
+//!
+//! A method whose return type changed under the same name cannot be shimmed, the inherent
+//! method always takes precedence over a trait method of the same name. Struct fields cannot be
+//! shimmed either. The unit newtypes of 0.2.0 are exempt for these reasons:
+//! [`Chip::get_temperature`], [`Chip::get_voltage`], [`Chip::get_power_info`] and
+//! [`Chip::get_frequency`] as well as the memory sizes of the memory, HBM, process and vNPU
+//! structs. `.0` gives back the raw number; power and voltage are now W and mV instead of the
+//! 0.1 W and 0.01 V steps reported by DCMI.
+
+use crate::device::Chip;
+use crate::error::DCMIResult;
+
+/// Shims of [`Chip`] methods
+pub trait ChipCompat {
+    /// Query the error codes of the faults currently active on the chip
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `Chip::get_errors`, which resolves descriptions and severities"
+    )]
+    fn get_error_codes(&self) -> DCMIResult<Vec<u32>>;
+}
+
+impl ChipCompat for Chip<'_> {
+    fn get_error_codes(&self) -> DCMIResult<Vec<u32>> {
+        self.error_codes()
+    }
+}
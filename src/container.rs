@@ -0,0 +1,263 @@
+//! Detection of containers and of the compute-split (vNPU) container scenario
+//!
+//! Inside a container several DCMI queries either fail with
+//! [`DCMIError::NotSupportInContainer`](crate::error::DCMIError::NotSupportInContainer) or, when
+//! the container is given a vNPU split from a physical chip, succeed with values that do not
+//! describe the vNPU (bandwidth utilizations are reported as 0). [`ContainerRestrictions`] tells
+//! which queries are meaningful, [`ContainerUsageReport`] joins the NPU usage with the CPU and
+//! memory accounting of the container's cgroup.
+
+use crate::device::{sysfs_error, Chip};
+use crate::enums::UtilizationType;
+use crate::error::DCMIResult;
+use crate::snapshot::SystemSnapshot;
+use crate::DCMI;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Marker files created by container runtimes
+const CONTAINER_MARKERS: [&str; 2] = ["/.dockerenv", "/run/.containerenv"];
+/// cgroup path components of processes run by container runtimes and orchestrators
+const CONTAINER_CGROUPS: [&str; 5] = ["docker", "kubepods", "containerd", "libpod", "lxc"];
+/// Prefix of the device files of vNPUs mounted into a container
+const VCHIP_DEVICE_PREFIX: &str = "vdavinci";
+
+/// Mount point of the unified (v2) cgroup hierarchy
+const CGROUP2_ROOT: &str = "/sys/fs/cgroup";
+
+/// Environment of the current process, detected once
+static ENVIRONMENT: OnceLock<ContainerRestrictions> = OnceLock::new();
+
+fn environment() -> ContainerRestrictions {
+    *ENVIRONMENT.get_or_init(|| {
+        let in_container = CONTAINER_MARKERS
+            .iter()
+            .any(|path| Path::new(path).exists())
+            || std::fs::read_to_string("/proc/1/cgroup")
+                .is_ok_and(|cgroup| is_container_cgroup(&cgroup));
+        let compute_split = in_container && has_vchip_devices(Path::new("/dev"));
+        ContainerRestrictions {
+            in_container,
+            compute_split,
+        }
+    })
+}
+
+/// Whether the content of `/proc/<pid>/cgroup` belongs to a containerized process
+fn is_container_cgroup(cgroup: &str) -> bool {
+    cgroup.lines().any(|line| {
+        let path = line.splitn(3, ':').nth(2).unwrap_or_default();
+        path.split(['/', '-', '.'])
+            .any(|component| CONTAINER_CGROUPS.contains(&component))
+    })
+}
+
+fn has_vchip_devices(dev: &Path) -> bool {
+    std::fs::read_dir(dev).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(VCHIP_DEVICE_PREFIX)
+        })
+    })
+}
+
+/// Queries which are meaningful for a chip in the current environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContainerRestrictions {
+    /// The process runs inside a container
+    pub in_container: bool,
+    /// The container was given vNPUs split from a physical chip
+    pub compute_split: bool,
+}
+
+impl ContainerRestrictions {
+    /// Whether [`Chip::get_utilization_rate`] reports a meaningful value for `utilization_type`
+    ///
+    /// Bandwidth utilizations are measured on the physical chip and read as 0 for a vNPU.
+    pub fn supports_utilization(&self, utilization_type: UtilizationType) -> bool {
+        !(self.compute_split
+            && matches!(
+                utilization_type,
+                UtilizationType::MemoryBandwidth | UtilizationType::HbmBandwidth
+            ))
+    }
+
+    /// Whether vNPUs can be created and destroyed, which DCMI only allows on the host
+    pub fn can_manage_vchips(&self) -> bool {
+        !self.in_container
+    }
+
+    /// Whether host-side configuration (PCIe settings, passthrough, recover mode) can be changed
+    pub fn can_configure_host(&self) -> bool {
+        !self.in_container
+    }
+}
+
+/// CPU and memory accounting of a cgroup v2
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CgroupUsage {
+    pub path: PathBuf,
+    /// CPU time consumed by the cgroup, unit: µs
+    pub cpu_usage_usec: u64,
+    /// CPU limit from `cpu.max`, unit: CPUs, `None` if unlimited
+    pub cpu_limit: Option<f64>,
+    /// Memory in use, unit: byte
+    pub memory_current: u64,
+    /// Memory limit from `memory.max`, unit: byte, `None` if unlimited
+    pub memory_max: Option<u64>,
+}
+
+impl CgroupUsage {
+    /// Read the accounting files of the cgroup directory `path`
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let read = |name: &str| std::fs::read_to_string(path.join(name));
+        let cpu_usage_usec = read("cpu.stat")?
+            .lines()
+            .find_map(|line| line.strip_prefix("usage_usec "))
+            .and_then(|usage| usage.trim().parse().ok())
+            .ok_or_else(|| invalid_data("cpu.stat without usage_usec"))?;
+        Ok(CgroupUsage {
+            path: path.to_path_buf(),
+            cpu_usage_usec,
+            cpu_limit: parse_cpu_max(&read("cpu.max")?)?,
+            memory_current: read("memory.current")?
+                .trim()
+                .parse()
+                .map_err(|_| invalid_data("invalid memory.current"))?,
+            memory_max: parse_limit(&read("memory.max")?)?,
+        })
+    }
+
+    /// Read the cgroup of the current process
+    ///
+    /// # Errors
+    /// [`io::ErrorKind::Unsupported`] if the unified (v2) hierarchy is not mounted
+    pub fn current() -> io::Result<Self> {
+        let root = Path::new(CGROUP2_ROOT);
+        if !root.join("cgroup.controllers").exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "cgroup v2 is not mounted",
+            ));
+        }
+        let cgroup = std::fs::read_to_string("/proc/self/cgroup")?;
+        let relative = cgroup
+            .lines()
+            .find_map(|line| line.strip_prefix("0::"))
+            .ok_or_else(|| invalid_data("process is not in a cgroup v2"))?;
+        let path = root.join(relative.trim().trim_start_matches('/'));
+        // the cgroup may lack the accounting files (e.g. the root cgroup on the host), fall
+        // back to the namespace root which is the container's own cgroup
+        Self::read(&path).or_else(|_| Self::read(root))
+    }
+}
+
+/// Parse a `<value>` or `max` limit
+fn parse_limit(value: &str) -> io::Result<Option<u64>> {
+    match value.trim() {
+        "max" => Ok(None),
+        value => value
+            .parse()
+            .map(Some)
+            .map_err(|_| invalid_data("invalid limit")),
+    }
+}
+
+/// Parse `cpu.max` (`<quota> <period>`) into a number of CPUs
+fn parse_cpu_max(value: &str) -> io::Result<Option<f64>> {
+    let mut fields = value.split_whitespace();
+    let quota = parse_limit(fields.next().unwrap_or_default())?;
+    let period: u64 = fields
+        .next()
+        .and_then(|period| period.parse().ok())
+        .filter(|&period| period > 0)
+        .ok_or_else(|| invalid_data("invalid cpu.max"))?;
+    Ok(quota.map(|quota| quota as f64 / period as f64))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// NPU usage visible to a container joined with the CPU and memory usage of its cgroup
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ContainerUsageReport {
+    pub cgroup: CgroupUsage,
+    /// Chips (vNPUs in a compute-split container) visible to the container
+    pub npu: SystemSnapshot,
+}
+
+impl DCMI {
+    /// Report the NPU usage visible to the process together with the usage of its cgroup
+    ///
+    /// # Errors
+    /// `NotSupport` if the unified (v2) cgroup hierarchy is not mounted
+    pub fn container_usage_report(&self) -> DCMIResult<ContainerUsageReport> {
+        let cgroup = CgroupUsage::current().map_err(sysfs_error)?;
+        Ok(ContainerUsageReport {
+            cgroup,
+            npu: self.snapshot()?,
+        })
+    }
+
+    /// Whether the process runs inside a container
+    ///
+    /// Detected once from the runtime marker files and the cgroup of the init process.
+    pub fn is_in_container(&self) -> bool {
+        environment().in_container
+    }
+}
+
+impl Chip<'_> {
+    /// Report which queries are meaningful for the chip in the current environment
+    pub fn container_restrictions(&self) -> ContainerRestrictions {
+        environment()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_container_cgroups() {
+        assert!(is_container_cgroup(
+            "0::/kubepods/besteffort/pod1234/0123456789abcdef\n"
+        ));
+        assert!(is_container_cgroup(
+            "12:memory:/system.slice/docker-0123456789abcdef.scope\n"
+        ));
+        assert!(!is_container_cgroup("0::/init.scope\n"));
+        assert!(!is_container_cgroup("0::/user.slice/user-1000.slice\n"));
+
+        let split = ContainerRestrictions {
+            in_container: true,
+            compute_split: true,
+        };
+        assert!(!split.supports_utilization(UtilizationType::HbmBandwidth));
+        assert!(split.supports_utilization(UtilizationType::AiCore));
+        assert!(!split.can_manage_vchips());
+    }
+
+    #[test]
+    fn reads_cgroup_usage() {
+        let dir = std::env::temp_dir().join(format!("hw_dcmi_cgroup_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("cpu.stat"), "usage_usec 1500\nuser_usec 1000\n").unwrap();
+        std::fs::write(dir.join("cpu.max"), "200000 100000\n").unwrap();
+        std::fs::write(dir.join("memory.current"), "4096\n").unwrap();
+        std::fs::write(dir.join("memory.max"), "max\n").unwrap();
+        let usage = CgroupUsage::read(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(usage.cpu_usage_usec, 1500);
+        assert_eq!(usage.cpu_limit, Some(2.0));
+        assert_eq!((usage.memory_current, usage.memory_max), (4096, None));
+        assert!(CgroupUsage::read(&dir).is_err());
+    }
+}
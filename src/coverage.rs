@@ -0,0 +1,188 @@
+//! Coverage of the DCMI API by this crate
+//!
+//! Lists every function declared in the bound `dcmi_interface_api.h` and whether a safe wrapper
+//! of this crate calls it, so gaps in the wrappers stay visible.
+//!
+//! ```
+//! let unwrapped = hw_dcmi::coverage::unwrapped().count();
+//! let (wrapped, total) = hw_dcmi::coverage::summary();
+//! assert_eq!(wrapped + unwrapped, total);
+//! ```
+
+/// Bindings generated from the DCMI header, the reference list of functions
+#[cfg(not(feature = "dynamic-loading"))]
+const BINDINGS: &str = include_str!("hw_dcmi_sys.rs");
+
+/// Sources of every module of the crate, searched for calls into the bindings
+///
+/// Must list every module file of the library except the bindings and this one, which is checked
+/// by the tests. Binaries under `src/bin` only use the safe API and are not listed.
+const SOURCES: &[(&str, &str)] = &[
+    ("aio.rs", include_str!("aio.rs")),
+    ("cache.rs", include_str!("cache.rs")),
+    ("capability.rs", include_str!("capability.rs")),
+    ("compat.rs", include_str!("compat.rs")),
+    ("container.rs", include_str!("container.rs")),
+    ("delta.rs", include_str!("delta.rs")),
+    ("device/info.rs", include_str!("device/info.rs")),
+    ("device/mod.rs", include_str!("device/mod.rs")),
+    ("device/numa.rs", include_str!("device/numa.rs")),
+    ("device/optional.rs", include_str!("device/optional.rs")),
+    ("device/owned.rs", include_str!("device/owned.rs")),
+    (
+        "device/passthrough.rs",
+        include_str!("device/passthrough.rs"),
+    ),
+    ("device/pci_config.rs", include_str!("device/pci_config.rs")),
+    ("enums.rs", include_str!("enums.rs")),
+    ("error.rs", include_str!("error.rs")),
+    ("event.rs", include_str!("event.rs")),
+    ("health.rs", include_str!("health.rs")),
+    ("identity.rs", include_str!("identity.rs")),
+    ("k8s.rs", include_str!("k8s.rs")),
+    ("lib.rs", include_str!("lib.rs")),
+    ("loader.rs", include_str!("loader.rs")),
+    ("mock.rs", include_str!("mock.rs")),
+    ("monitor/bandwidth.rs", include_str!("monitor/bandwidth.rs")),
+    ("monitor/counter.rs", include_str!("monitor/counter.rs")),
+    ("monitor/health.rs", include_str!("monitor/health.rs")),
+    ("monitor/metrics.rs", include_str!("monitor/metrics.rs")),
+    ("monitor/mod.rs", include_str!("monitor/mod.rs")),
+    ("monitor/pcie.rs", include_str!("monitor/pcie.rs")),
+    ("monitor/rules.rs", include_str!("monitor/rules.rs")),
+    ("monitor/sampler.rs", include_str!("monitor/sampler.rs")),
+    ("monitor/thread.rs", include_str!("monitor/thread.rs")),
+    ("parallel.rs", include_str!("parallel.rs")),
+    ("query.rs", include_str!("query.rs")),
+    ("ranktable.rs", include_str!("ranktable.rs")),
+    ("remote.rs", include_str!("remote.rs")),
+    ("retry.rs", include_str!("retry.rs")),
+    ("snapshot.rs", include_str!("snapshot.rs")),
+    ("structs.rs", include_str!("structs.rs")),
+    ("topology.rs", include_str!("topology.rs")),
+    ("units.rs", include_str!("units.rs")),
+    ("upgrade.rs", include_str!("upgrade.rs")),
+    ("utils.rs", include_str!("utils.rs")),
+    ("vnpu/mod.rs", include_str!("vnpu/mod.rs")),
+    ("vnpu/templates.rs", include_str!("vnpu/templates.rs")),
+    ("watchdog.rs", include_str!("watchdog.rs")),
+];
+
+/// Coverage of a single DCMI function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionCoverage {
+    /// Name of the C function, e.g. `dcmi_get_device_info`
+    pub name: &'static str,
+    /// Module files of this crate calling the function, relative to `src`, empty if the
+    /// function is not wrapped
+    pub wrapped_in: Vec<&'static str>,
+}
+
+impl FunctionCoverage {
+    /// Whether a wrapper of this crate calls the function
+    pub fn is_wrapped(&self) -> bool {
+        !self.wrapped_in.is_empty()
+    }
+}
+
+/// Every function of the DCMI header, in header order
+pub fn functions() -> impl Iterator<Item = FunctionCoverage> {
+    declared_functions().map(|name| {
+        let wrapped_in = SOURCES
+            .iter()
+            .filter(|(_, source)| calls(source, name))
+            .map(|(file, _)| *file)
+            .collect();
+        FunctionCoverage { name, wrapped_in }
+    })
+}
+
+/// Functions wrapped by this crate
+pub fn wrapped() -> impl Iterator<Item = FunctionCoverage> {
+    functions().filter(FunctionCoverage::is_wrapped)
+}
+
+/// Functions of the header without a wrapper yet
+pub fn unwrapped() -> impl Iterator<Item = FunctionCoverage> {
+    functions().filter(|function| !function.is_wrapped())
+}
+
+/// Coverage of a single function, `None` if the header does not declare it
+pub fn function(name: &str) -> Option<FunctionCoverage> {
+    functions().find(|function| function.name == name)
+}
+
+/// Number of wrapped functions and total number of functions in the header
+pub fn summary() -> (usize, usize) {
+    functions().fold((0, 0), |(wrapped, total), function| {
+        (wrapped + function.is_wrapped() as usize, total + 1)
+    })
+}
+
+#[cfg(feature = "dynamic-loading")]
+fn declared_functions() -> impl Iterator<Item = &'static str> {
+    crate::hw_dcmi_sys::DCMI_SYMBOLS.iter().copied()
+}
+
+#[cfg(not(feature = "dynamic-loading"))]
+fn declared_functions() -> impl Iterator<Item = &'static str> {
+    BINDINGS.lines().filter_map(|line| {
+        let name = line.trim_start().strip_prefix("pub fn ")?;
+        Some(&name[..name.find('(')?])
+    })
+}
+
+/// Whether `source` references `name` outside of comments
+fn calls(source: &str, name: &str) -> bool {
+    source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .any(|line| {
+            line.match_indices(name).any(|(start, _)| {
+                let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+                !line[..start].ends_with(is_ident)
+                    && !line[start + name.len()..].starts_with(is_ident)
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_cover_every_module() {
+        let src = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut files = Vec::new();
+        let mut dirs = vec![src.clone()];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    let file = path.strip_prefix(&src).unwrap().to_str().unwrap();
+                    files.push(file.replace('\\', "/"));
+                }
+            }
+        }
+        files.retain(|file| {
+            file != "hw_dcmi_sys.rs" && file != "coverage.rs" && !file.starts_with("bin/")
+        });
+        files.sort();
+        let listed: Vec<_> = SOURCES.iter().map(|(file, _)| file.to_string()).collect();
+        assert_eq!(files, listed);
+
+        assert!(function("dcmi_init")
+            .unwrap()
+            .wrapped_in
+            .contains(&"lib.rs"));
+        assert!(function("dcmi_get_card_list").unwrap().is_wrapped());
+        assert!(function("dcmi_not_a_function").is_none());
+        assert!(!calls(
+            "call(dcmi_get_device_info_v2)",
+            "dcmi_get_device_info"
+        ));
+        assert!(!calls("// dcmi_init", "dcmi_init"));
+    }
+}
@@ -0,0 +1,149 @@
+//! JSON Patch (RFC 6902) deltas between consecutive snapshots
+//!
+//! Live dashboards only need what changed since the previous update. [`DeltaStream`] keeps the
+//! last serialized snapshot and emits the operations turning it into the next one.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// JSON Patch operation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOp {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+}
+
+/// Turns a sequence of snapshots into JSON Patch operations
+///
+/// The first snapshot, and the first one after [`DeltaStream::reset`], is emitted as a single
+/// `replace` of the whole document. Arrays changing length are replaced as a whole.
+///
+/// ```
+/// use hw_dcmi::delta::{DeltaStream, PatchOp};
+/// use serde_json::json;
+///
+/// let mut stream = DeltaStream::new();
+/// stream.update(&json!({"temperature": 50, "health": "normal"})).unwrap();
+/// let ops = stream.update(&json!({"temperature": 52, "health": "normal"})).unwrap();
+/// assert_eq!(
+///     ops,
+///     [PatchOp::Replace {
+///         path: "/temperature".to_string(),
+///         value: json!(52)
+///     }]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DeltaStream {
+    last: Option<Value>,
+}
+
+impl DeltaStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Operations turning the previous snapshot into `snapshot`
+    pub fn update<T: Serialize>(&mut self, snapshot: &T) -> serde_json::Result<Vec<PatchOp>> {
+        let value = serde_json::to_value(snapshot)?;
+        let mut ops = Vec::new();
+        match &self.last {
+            Some(last) => diff(last, &value, &mut String::new(), &mut ops),
+            None => ops.push(PatchOp::Replace {
+                path: String::new(),
+                value: value.clone(),
+            }),
+        }
+        self.last = Some(value);
+        Ok(ops)
+    }
+
+    /// Forget the previous snapshot, e.g. when a new client connects
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+fn diff(old: &Value, new: &Value, path: &mut String, ops: &mut Vec<PatchOp>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => diff_objects(old, new, path, ops),
+        (Value::Array(old), Value::Array(new)) if old.len() == new.len() => {
+            for (index, (old, new)) in old.iter().zip(new).enumerate() {
+                with_segment(path, &index.to_string(), |path| diff(old, new, path, ops));
+            }
+        }
+        (old, new) if old != new => ops.push(PatchOp::Replace {
+            path: path.clone(),
+            value: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    path: &mut String,
+    ops: &mut Vec<PatchOp>,
+) {
+    for key in old.keys().filter(|key| !new.contains_key(*key)) {
+        with_segment(path, key, |path| {
+            ops.push(PatchOp::Remove { path: path.clone() })
+        });
+    }
+    for (key, new) in new {
+        with_segment(path, key, |path| match old.get(key) {
+            Some(old) => diff(old, new, path, ops),
+            None => ops.push(PatchOp::Add {
+                path: path.clone(),
+                value: new.clone(),
+            }),
+        });
+    }
+}
+
+/// Run `f` with `segment` appended to the JSON Pointer `path`
+fn with_segment(path: &mut String, segment: &str, f: impl FnOnce(&mut String)) {
+    let len = path.len();
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    f(path);
+    path.truncate(len);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn emits_minimal_operations() {
+        let mut stream = DeltaStream::new();
+        let first = stream.update(&json!({"a": 1})).unwrap();
+        assert_eq!(
+            first,
+            [PatchOp::Replace {
+                path: String::new(),
+                value: json!({"a": 1})
+            }]
+        );
+
+        let old = json!({"chips": [{"temp": 50}, {"temp": 60}], "gone": true, "a/b": 1});
+        let new = json!({"chips": [{"temp": 50}, {"temp": 61}], "new": null, "a/b": 2});
+        stream.reset();
+        stream.update(&old).unwrap();
+        let ops = stream.update(&new).unwrap();
+        assert_eq!(
+            serde_json::to_value(&ops).unwrap(),
+            json!([
+                {"op": "remove", "path": "/gone"},
+                {"op": "replace", "path": "/a~1b", "value": 2},
+                {"op": "replace", "path": "/chips/1/temp", "value": 61},
+                {"op": "add", "path": "/new", "value": null},
+            ])
+        );
+        assert!(stream.update(&new).unwrap().is_empty());
+    }
+}
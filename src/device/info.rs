@@ -0,0 +1,400 @@
+//! Typed access to the `dcmi_get_device_info` sub command interface
+//!
+//! Many capabilities of newer DCMI versions are only reachable through this multiplexer, which
+//! takes a main command, a sub command and an untyped buffer.
+
+use super::Chip;
+use crate::enums::{FrequencyType, PowerMode, UtilizationType, VoltageRail};
+use crate::error::{call_dcmi_function, convert, DCMIError, DCMIResult};
+use crate::hw_dcmi_sys::*;
+use crate::structs::{
+    ComputeTokenInfo, CtrlCpuInfo, HccsLaneInfo, HccsStatistics, HostAiCpuInfo, SioCrcErrors,
+    SuperPodInfo, ThrottleStatus, VChipFreeResources, VoltageRailInfo, WorkTops,
+};
+use crate::units::Millivolts;
+use std::ffi::c_void;
+
+/// Buffer of the `DCMI_EX_COMPUTING_SUB_CMD_TOKEN` sub command, not part of the bindings
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct dcmi_computing_token_stru {
+    pub value: f32,
+    pub token_type: u8,
+    pub reserve_c: u8,
+    pub reserve_s: u16,
+}
+
+/// Largest buffer accepted by [`InfoQuery::Raw`]
+pub const RAW_INFO_MAX_LEN: usize = 4096;
+
+/// Sub command of `dcmi_get_device_info`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InfoQuery {
+    /// AI CPUs lent to the host
+    HostAiCpu,
+    /// Link status of the HCCS ports
+    HccsStatus,
+    HccsLaneInfo,
+    /// Traffic and CRC error counters of the HCCS ports
+    HccsStatistics,
+    /// CRC error counters of the SIO bus between the dies
+    SioCrcErrors,
+    /// Position of the chip inside a super pod
+    SuperPodInfo,
+    /// Configured computing power tier
+    WorkTops,
+    VoltageRail(VoltageRail),
+    /// Computing power token of license-gated products
+    ComputeToken,
+    /// Any other sub command, the result is the buffer as filled by DCMI
+    ///
+    /// `len` is the size of the buffer passed to DCMI, at most [`RAW_INFO_MAX_LEN`]
+    Raw {
+        main_cmd: dcmi_main_cmd,
+        sub_cmd: u32,
+        len: usize,
+    },
+}
+
+/// Result of a [`InfoQuery`], the variant matches the variant of the query
+#[derive(Debug, Clone, PartialEq)]
+pub enum InfoResult {
+    HostAiCpu(HostAiCpuInfo),
+    /// PCS status, 0 means the links are up
+    HccsStatus(u32),
+    HccsLaneInfo(HccsLaneInfo),
+    HccsStatistics(HccsStatistics),
+    SioCrcErrors(SioCrcErrors),
+    SuperPodInfo(SuperPodInfo),
+    WorkTops(WorkTops),
+    VoltageRail(VoltageRailInfo),
+    ComputeToken(ComputeTokenInfo),
+    Raw(Vec<u8>),
+}
+
+impl<'a> Chip<'a> {
+    /// Query information through the `dcmi_get_device_info` sub command interface
+    ///
+    /// # Errors
+    /// `NotSupport` if the chip or driver does not implement the sub command, `InvalidParameter`
+    /// if the buffer of a raw query is larger than [`RAW_INFO_MAX_LEN`]
+    pub fn query_info(&self, query: InfoQuery) -> DCMIResult<InfoResult> {
+        Ok(match query {
+            InfoQuery::HostAiCpu => {
+                let info: dcmi_host_aicpu_info = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_HOST_AICPU,
+                    DCMI_HOST_AICPU_SUB_CMD_DCMI_SUB_CMD_HOST_AICPU_INFO,
+                )?;
+                InfoResult::HostAiCpu(convert(info)?)
+            }
+            InfoQuery::HccsStatus => {
+                let status: dcmi_hccs_statues = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_HCCS,
+                    DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_STATUS,
+                )?;
+                InfoResult::HccsStatus(status.pcs_status)
+            }
+            InfoQuery::HccsLaneInfo => {
+                let info: dcmi_hccs_lane_info = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_HCCS,
+                    DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_LANE_INFO,
+                )?;
+                InfoResult::HccsLaneInfo(convert(info)?)
+            }
+            InfoQuery::HccsStatistics => {
+                let info: dcmi_hccs_statistic_info = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_HCCS,
+                    DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_STATISTIC_INFO,
+                )?;
+                InfoResult::HccsStatistics(convert(info)?)
+            }
+            InfoQuery::SioCrcErrors => {
+                let info: dcmi_sio_crc_err_statistics_info = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_SIO,
+                    DCMI_SIO_SUB_CMD_DCMI_SIO_SUB_CMD_CRC_ERR_STATISTICS,
+                )?;
+                InfoResult::SioCrcErrors(convert(info)?)
+            }
+            InfoQuery::SuperPodInfo => InfoResult::SuperPodInfo(self.get_super_pod_info()?),
+            InfoQuery::WorkTops => {
+                let info: dcmi_lp_work_tops_stru = self.get_device_info(
+                    dcmi_main_cmd_DCMI_MAIN_CMD_LP,
+                    DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_GET_WORK_TOPS,
+                )?;
+                InfoResult::WorkTops(convert(info)?)
+            }
+            InfoQuery::VoltageRail(rail) => InfoResult::VoltageRail(self.get_voltage_rail(rail)?),
+            InfoQuery::ComputeToken => InfoResult::ComputeToken(self.get_compute_token_info()?),
+            InfoQuery::Raw {
+                main_cmd,
+                sub_cmd,
+                len,
+            } => {
+                if len > RAW_INFO_MAX_LEN {
+                    return Err(DCMIError::InvalidParameter);
+                }
+                let mut buf = vec![0u8; len];
+                // SAFETY: the buffer is valid for `len` bytes
+                let filled = unsafe {
+                    self.get_device_info_raw(main_cmd, sub_cmd, buf.as_mut_ptr().cast(), len)?
+                };
+                buf.truncate(filled);
+                InfoResult::Raw(buf)
+            }
+        })
+    }
+
+    /// Query the computing power token of the chip
+    ///
+    /// On products whose AI core count is license-gated the token reflects the computing power
+    /// unlocked by the installed license.
+    ///
+    /// # Errors
+    /// `NotSupport` on products without computing power licensing
+    pub fn get_compute_token_info(&self) -> DCMIResult<ComputeTokenInfo> {
+        let token: dcmi_computing_token_stru = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_EX_COMPUTING,
+            DCMI_EX_COMPUTING_SUB_CMD_TOKEN,
+        )?;
+        convert(token)
+    }
+
+    /// Query whether the AI cores of the chip are throttled
+    ///
+    /// Performance regressions are often throttling, a throttled chip runs its AI cores below
+    /// [`FrequencyType::AiCoreMax`](crate::enums::FrequencyType::AiCoreMax). The cause is
+    /// returned as reported, the DCMI header does not define its values.
+    ///
+    /// # Errors
+    /// `NotSupport` if the driver does not report the frequency reduction cause
+    pub fn get_throttle_status(&self) -> DCMIResult<ThrottleStatus> {
+        let cause: u32 = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_LP,
+            DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_AICORE_FREQREDUC_CAUSE,
+        )?;
+        Ok(cause.into())
+    }
+
+    /// Query whether the chip is in its low-power state right now
+    ///
+    /// This is the current state, not the mode configured by [`Chip::set_power_mode`]: a chip
+    /// set to [`PowerMode::LowPower`] reports `false` while it runs tasks. DCMI cannot read the
+    /// configured mode back.
+    ///
+    /// # Errors
+    /// `NotSupport` on chips without idle power states, only some Atlas inference cards have
+    /// them
+    pub fn get_low_power_status(&self) -> DCMIResult<bool> {
+        let status: u32 = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_LP,
+            DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_STATUS,
+        )?;
+        Ok(status != 0)
+    }
+
+    /// Set whether the chip enters its idle power state when no task runs on it
+    ///
+    /// # Errors
+    /// `NotSupport` on chips without idle power states
+    ///
+    /// # Warning
+    /// Requires root
+    pub fn set_power_mode(&self, mode: PowerMode) -> DCMIResult<()> {
+        let switch: u32 = match mode {
+            PowerMode::Normal => 0,
+            PowerMode::LowPower => 1,
+        };
+        self.set_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_LP,
+            DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_SET_IDLE_SWITCH,
+            &switch,
+        )
+    }
+
+    /// Query the core count, frequency and utilization of the control CPU
+    ///
+    /// A saturated control CPU delays the launch of tasks, a common cause of inference latency
+    /// spikes.
+    pub fn get_ctrl_cpu_info(&self) -> DCMIResult<CtrlCpuInfo> {
+        let domain = self.get_domain_info()?;
+        Ok(CtrlCpuInfo {
+            core_count: domain.ctrl_cpu_num.max(0) as u32,
+            frequency: self.get_frequency(FrequencyType::CtrlCpu)?,
+            utilization: self.get_utilization_rate(UtilizationType::CtrlCpu)?,
+        })
+    }
+
+    /// Query the utilization of every AI core, indexed by core, unit: %
+    ///
+    /// Load imbalance between the cores, e.g. of kernels launching fewer blocks than there are
+    /// cores, is invisible in [`UtilizationType::AiCore`], the average over all cores.
+    ///
+    /// # Errors
+    /// `NotSupport` if the driver does not fill in a rate for every core, e.g. when it only
+    /// reports the average
+    pub fn get_ai_core_utilization_per_core(&self) -> DCMIResult<Vec<u32>> {
+        let cores = self.get_chip_info()?.aicore_count as usize;
+        let mut rates = vec![0u32; cores];
+        // SAFETY: the buffer is valid for `cores` rates
+        let filled = unsafe {
+            self.get_device_info_raw(
+                dcmi_main_cmd_DCMI_MAIN_CMD_TS,
+                DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_AICORE_UTILIZATION_RATE,
+                rates.as_mut_ptr().cast(),
+                std::mem::size_of_val(rates.as_slice()),
+            )?
+        };
+        per_core_rates(rates, filled)
+    }
+
+    /// Query how the cores of the SoC are split between AI CPU, control CPU and data CPU
+    pub(crate) fn get_domain_info(&self) -> DCMIResult<dcmi_domain_info> {
+        self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_SOC_INFO,
+            DCMI_SOC_INFO_SUB_CMD_DCMI_SOC_INFO_SUB_CMD_DOMAIN_INFO,
+        )
+    }
+
+    /// Query the position of the chip inside a super pod
+    pub fn get_super_pod_info(&self) -> DCMIResult<SuperPodInfo> {
+        let info: dcmi_spod_info = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_CHIP_INF,
+            DCMI_CHIP_INFO_SUB_CMD_DCMI_CHIP_INF_SUB_CMD_SPOD_INFO,
+        )?;
+        convert(info)
+    }
+
+    /// Query the resources of the chip that are still available for new vNPUs
+    pub fn get_vchip_free_resources(&self) -> DCMIResult<VChipFreeResources> {
+        let free: dcmi_soc_free_resource = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_VDEV_MNG,
+            DCMI_VDEV_MNG_SUB_CMD_DCMI_VMNG_SUB_CMD_GET_FREE_RESOURCE,
+        )?;
+        convert(free)
+    }
+
+    pub(super) fn get_voltage_rail(&self, rail: VoltageRail) -> DCMIResult<VoltageRailInfo> {
+        // voltage followed by current
+        let reading: [u32; 2] =
+            self.get_device_info(dcmi_main_cmd_DCMI_MAIN_CMD_LP, rail.into())?;
+        Ok(VoltageRailInfo {
+            rail,
+            voltage: Millivolts(reading[0]),
+            current: reading[1],
+        })
+    }
+
+    /// Read a plain C value through `dcmi_get_device_info`
+    ///
+    /// `T` must be a plain C struct or array for which all-zero is a valid value.
+    pub(crate) fn get_device_info<T: Copy>(
+        &self,
+        main_cmd: dcmi_main_cmd,
+        sub_cmd: u32,
+    ) -> DCMIResult<T> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        self.get_device_info_with(main_cmd, sub_cmd, unsafe { std::mem::zeroed() })
+    }
+
+    /// Like [`Chip::get_device_info`], for sub commands that read their input from the buffer
+    pub(crate) fn get_device_info_with<T: Copy>(
+        &self,
+        main_cmd: dcmi_main_cmd,
+        sub_cmd: u32,
+        mut value: T,
+    ) -> DCMIResult<T> {
+        // SAFETY: the buffer is a `T`
+        unsafe {
+            self.get_device_info_raw(
+                main_cmd,
+                sub_cmd,
+                (&mut value as *mut T).cast(),
+                std::mem::size_of::<T>(),
+            )?
+        };
+        Ok(value)
+    }
+
+    /// Write a plain C value through `dcmi_set_device_info`
+    pub(crate) fn set_device_info<T: Copy>(
+        &self,
+        main_cmd: dcmi_main_cmd,
+        sub_cmd: u32,
+        value: &T,
+    ) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_device_info,
+            self.card_id as i32,
+            self.id as i32,
+            main_cmd,
+            sub_cmd,
+            (value as *const T).cast(),
+            std::mem::size_of::<T>() as u32
+        )
+    }
+
+    /// Fill `buf` through `dcmi_get_device_info`, returns the number of bytes written
+    ///
+    /// # Safety
+    /// `buf` must be valid for writes of `len` bytes
+    unsafe fn get_device_info_raw(
+        &self,
+        main_cmd: dcmi_main_cmd,
+        sub_cmd: u32,
+        buf: *mut c_void,
+        len: usize,
+    ) -> DCMIResult<usize> {
+        let mut size = len as u32;
+        call_dcmi_function!(
+            dcmi_get_device_info,
+            self.card_id as i32,
+            self.id as i32,
+            main_cmd,
+            sub_cmd,
+            buf,
+            &mut size
+        )?;
+        Ok((size as usize).min(len))
+    }
+}
+
+/// Rates of all cores, `NotSupport` unless DCMI filled in exactly one rate per core
+///
+/// A driver reporting only the average fills in a single rate, which must not pass for the rate
+/// of core 0.
+fn per_core_rates(rates: Vec<u32>, filled: usize) -> DCMIResult<Vec<u32>> {
+    if filled != std::mem::size_of_val(rates.as_slice()) {
+        return Err(DCMIError::NotSupport);
+    }
+    Ok(rates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::UnitType;
+    use crate::DCMI;
+
+    #[test]
+    fn raw_query_rejects_oversized_buffer() {
+        let dcmi = DCMI { _private: () };
+        let chip = Chip::new(&dcmi, 0, 0, UnitType::NPU);
+        let query = InfoQuery::Raw {
+            main_cmd: dcmi_main_cmd_DCMI_MAIN_CMD_SOC_INFO,
+            sub_cmd: 0,
+            len: RAW_INFO_MAX_LEN + 1,
+        };
+        assert_eq!(chip.query_info(query), Err(DCMIError::InvalidParameter));
+    }
+
+    #[test]
+    fn per_core_rates_need_every_core() {
+        assert_eq!(per_core_rates(vec![10, 20, 30], 12), Ok(vec![10, 20, 30]));
+        // only the average
+        assert_eq!(
+            per_core_rates(vec![20, 0, 0], 4),
+            Err(DCMIError::NotSupport)
+        );
+        assert_eq!(per_core_rates(vec![10, 20], 12), Err(DCMIError::NotSupport));
+    }
+}
@@ -0,0 +1,1314 @@
+mod info;
+mod numa;
+mod optional;
+mod owned;
+mod passthrough;
+mod pci_config;
+
+pub(crate) use info::dcmi_computing_token_stru;
+pub use info::{InfoQuery, InfoResult, RAW_INFO_MAX_LEN};
+pub use owned::{OwnedCard, OwnedChip};
+pub(crate) use passthrough::PASSTHROUGH_DRIVER;
+
+use crate::capability::Feature;
+use crate::enums::{
+    Channel, DeviceType, DieType, FrequencyType, HealthState, PortType, TopologyType, UnitType,
+    UtilizationType, VoltageRail,
+};
+use crate::error::{call_dcmi_function, convert, convert_string, DCMIError, DCMIResult};
+use crate::event::{self, ErrorRecord};
+use crate::hw_dcmi_sys::*;
+use crate::structs::{
+    BoardInfo, ChipInfo, DieInfo, ECCAddressRecord, ECCInfo, ECCSummary, ELabelInfo, Frequencies,
+    HBMInfo, IdentityCheck, MemoryInfo, PCIEErrorInfo, PCIEInfo, PCIELinkStatus,
+    PCIEPayloadSettings, PassthroughReadiness, ProcessMemoryInfo, TemperatureThresholds,
+    Temperatures, VirtualFunction, VoltageRailInfo,
+};
+use crate::units::{Celsius, MegaHertz, Millivolts, Watts};
+use crate::utils::query_list;
+use crate::DCMI;
+use pci_config::SizeField;
+use std::ffi::CString;
+use std::fmt;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+
+/// Maximum size in bytes of a single user configuration item
+pub const USER_CONFIG_MAX_LEN: usize = 1024;
+
+/// Number of error codes queried from a chip at first, more are queried if the chip reports as
+/// many
+pub const MAX_ERROR_CODES: usize = 128;
+/// Most error codes queried from a chip
+pub(crate) const ERROR_CODES_LIMIT: usize = 4096;
+
+/// A card (management unit) managed by DCMI
+///
+/// `Debug` and `Display` show the card id, not the `DCMI` instance.
+#[derive(Clone, Copy)]
+pub struct Card<'a> {
+    pub(crate) dcmi: &'a DCMI,
+    pub(crate) id: u32,
+}
+
+impl<'a> Card<'a> {
+    pub(crate) fn new(dcmi: &'a DCMI, id: u32) -> Self {
+        Card { dcmi, id }
+    }
+
+    /// Card id used by DCMI
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Query the number of NPU chips on the card
+    pub fn get_chip_num(&self) -> DCMIResult<u32> {
+        let mut chip_num = 0;
+        call_dcmi_function!(dcmi_get_device_num_in_card, self.id as i32, &mut chip_num)?;
+        Ok(chip_num as u32)
+    }
+
+    /// Trigger a dump of the MCU logs of the card
+    ///
+    /// The MCU writes the collected logs to the host log directory configured for the driver,
+    /// DCMI neither lets the caller choose the destination nor reports progress, the call
+    /// returns once the collection has finished.
+    ///
+    /// # Parameters
+    /// - log_type: kind of log to collect, as defined in the DCMI API reference of the product
+    ///
+    /// # Warning
+    /// Requires root privileges and is only supported on cards with an MCU
+    pub fn collect_mcu_logs(&self, log_type: i32) -> DCMIResult<()> {
+        call_dcmi_function!(dcmi_mcu_collect_log, self.id as i32, log_type)
+    }
+
+    /// Query the electronic label of the card, as read by its MCU
+    pub fn get_elabel_info(&self) -> DCMIResult<ELabelInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut elabel: dcmi_elabel_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(dcmi_get_card_elabel_v2, self.id as i32, &mut elabel)?;
+        convert(elabel)
+    }
+
+    /// Compare the serial number read by the MCU with the serial numbers reported by the chips
+    ///
+    /// See [`IdentityCheck::is_consistent`].
+    pub fn verify_identity(&self) -> DCMIResult<IdentityCheck> {
+        let card = self.get_elabel_info()?;
+        let mut chips = Vec::new();
+        for chip in self.get_chips()? {
+            if chip.unit_type() == UnitType::NPU {
+                chips.push((chip.id(), chip.get_elabel_info()?));
+            }
+        }
+        Ok(IdentityCheck { card, chips })
+    }
+
+    /// Query all chips on the card
+    ///
+    /// # Returns
+    /// NPU chips first, followed by the MCU and the control CPU if the card has them
+    pub fn get_chips(&self) -> DCMIResult<Vec<Chip<'a>>> {
+        let mut device_id_max = 0;
+        let mut mcu_id = 0;
+        let mut cpu_id = 0;
+        call_dcmi_function!(
+            dcmi_get_device_id_in_card,
+            self.id as i32,
+            &mut device_id_max,
+            &mut mcu_id,
+            &mut cpu_id
+        )?;
+
+        let mut chips: Vec<Chip> = (0..device_id_max.max(0) as u32)
+            .map(|id| Chip::new(self.dcmi, self.id, id, UnitType::NPU))
+            .collect();
+        if mcu_id >= 0 {
+            chips.push(Chip::new(self.dcmi, self.id, mcu_id as u32, UnitType::MCU));
+        }
+        if cpu_id >= 0 {
+            chips.push(Chip::new(self.dcmi, self.id, cpu_id as u32, UnitType::CPU));
+        }
+        Ok(chips)
+    }
+}
+
+/// A chip (NPU, MCU or CPU) on a card
+///
+/// `Debug` shows the card id, chip id and unit type without calling DCMI. `Display` adds the
+/// model (from the cached [`Chip::identity`]) and PCI address of NPUs, which are left out if the
+/// queries fail, e.g. `npu 0/1 (Ascend910B3, 0000:c1:00.0)`.
+#[derive(Clone, Copy)]
+pub struct Chip<'a> {
+    pub(crate) dcmi: &'a DCMI,
+    pub(crate) card_id: u32,
+    pub(crate) id: u32,
+    pub(crate) unit_type: UnitType,
+}
+
+impl<'a> Chip<'a> {
+    pub(crate) fn new(dcmi: &'a DCMI, card_id: u32, id: u32, unit_type: UnitType) -> Self {
+        Chip {
+            dcmi,
+            card_id,
+            id,
+            unit_type,
+        }
+    }
+
+    /// Id of the card this chip belongs to
+    pub fn card_id(&self) -> u32 {
+        self.card_id
+    }
+
+    /// Card this chip belongs to
+    pub fn card(&self) -> Card<'a> {
+        Card::new(self.dcmi, self.card_id)
+    }
+
+    /// Chip id inside its card
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Kind of the chip
+    pub fn unit_type(&self) -> UnitType {
+        self.unit_type
+    }
+
+    /// Index of the chip as displayed by `npu-smi`
+    ///
+    /// `npu-smi` numbers NPU chips by their logic id (the `Device` column of `npu-smi info`),
+    /// which is assigned by the driver in card order and then chip order, skipping MCU and CPU
+    /// units. Use it to correlate chips with the vendor tooling and with
+    /// `ASCEND_RT_VISIBLE_DEVICES`.
+    ///
+    /// # Errors
+    /// [`DCMIError::InvalidDeviceId`] if the chip is not an NPU
+    pub fn npu_smi_index(&self) -> DCMIResult<u32> {
+        if self.unit_type != UnitType::NPU {
+            return Err(DCMIError::InvalidDeviceId);
+        }
+        let mut logic_id = 0;
+        call_dcmi_function!(
+            dcmi_get_device_logic_id,
+            &mut logic_id,
+            self.card_id as i32,
+            self.id as i32
+        )?;
+        Ok(logic_id as u32)
+    }
+
+    /// Query the physical id of the chip, as used by HCCL rank tables
+    ///
+    /// # Errors
+    /// [`DCMIError::InvalidDeviceId`] if the chip is not an NPU
+    pub fn get_phy_id(&self) -> DCMIResult<u32> {
+        let mut phy_id = 0;
+        call_dcmi_function!(
+            dcmi_get_device_phyid_from_logicid,
+            self.npu_smi_index()?,
+            &mut phy_id
+        )?;
+        Ok(phy_id)
+    }
+
+    /// Query the IP address and netmask of a network port of the chip
+    ///
+    /// # Parameters
+    /// - port_type: `Roce` for the RoCE NIC used by HCCL, `Vnic` for the virtual NIC
+    /// - port_id: index of the port, 0 on chips with a single port
+    pub fn get_device_ip(&self, port_type: PortType, port_id: u32) -> DCMIResult<(IpAddr, IpAddr)> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut ip: dcmi_ip_addr = unsafe { std::mem::zeroed() };
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut mask: dcmi_ip_addr = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_ip,
+            self.card_id as i32,
+            self.id as i32,
+            port_type.into(),
+            port_id as i32,
+            &mut ip,
+            &mut mask
+        )?;
+        Ok((ip_addr_from_raw(&ip), ip_addr_from_raw(&mask)))
+    }
+
+    /// Query the electronic label reported by the chip
+    pub fn get_elabel_info(&self) -> DCMIResult<ELabelInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut elabel: dcmi_elabel_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_elabel_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut elabel
+        )?;
+        convert(elabel)
+    }
+
+    /// Query the board the chip is mounted on
+    pub fn get_board_info(&self) -> DCMIResult<BoardInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut board_info: dcmi_board_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_board_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut board_info
+        )?;
+        convert(board_info)
+    }
+
+    /// Query the firmware version of the chip
+    pub fn get_firmware_version(&self) -> DCMIResult<String> {
+        let mut version = [0; MAX_VER_LEN as usize + 1];
+        let mut len = 0;
+        call_dcmi_function!(
+            dcmi_get_version,
+            self.card_id as i32,
+            self.id as i32,
+            version.as_mut_ptr(),
+            version.len() as u32,
+            &mut len
+        )?;
+        convert_string(&version)
+    }
+
+    /// Query the model information of the chip
+    pub fn get_chip_info(&self) -> DCMIResult<ChipInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut chip_info: dcmi_chip_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_chip_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut chip_info
+        )?;
+        convert(chip_info)
+    }
+
+    /// Query the health state of the chip, see [`DCMI::get_driver_health`] for the driver
+    pub fn get_health(&self) -> DCMIResult<HealthState> {
+        let mut health = 0;
+        call_dcmi_function!(
+            dcmi_get_device_health,
+            self.card_id as i32,
+            self.id as i32,
+            &mut health
+        )?;
+        convert(health)
+    }
+
+    /// Query the faults currently active on the chip, with their descriptions
+    ///
+    /// Faults of the AI cores (exceptions, task timeouts) are reported here as well, DCMI has
+    /// no separate counters for them. The driver has no severity per error code, it is taken from
+    /// the fault events with the same id the process has seen, see [`ErrorRecord::severity`].
+    pub fn get_errors(&self) -> DCMIResult<Vec<ErrorRecord>> {
+        Ok(self
+            .error_codes()?
+            .into_iter()
+            .map(|code| ErrorRecord {
+                code,
+                description: self
+                    .get_error_code_description(code)
+                    .ok()
+                    .filter(|description| !description.is_empty()),
+                severity: event::known_severity(code),
+            })
+            .collect())
+    }
+
+    /// Query the error codes of the faults currently active on the chip
+    pub(crate) fn error_codes(&self) -> DCMIResult<Vec<u32>> {
+        query_list(
+            MAX_ERROR_CODES,
+            ERROR_CODES_LIMIT,
+            |error_codes: &mut [u32]| -> DCMIResult<usize> {
+                let mut error_count = 0;
+                call_dcmi_function!(
+                    dcmi_get_device_errorcode_v2,
+                    self.card_id as i32,
+                    self.id as i32,
+                    &mut error_count,
+                    error_codes.as_mut_ptr(),
+                    error_codes.len() as u32
+                )?;
+                Ok(usize::try_from(error_count).unwrap_or(0))
+            },
+        )
+    }
+
+    /// Query the description of an error code reported by [`Chip::get_errors`]
+    pub fn get_error_code_description(&self, error_code: u32) -> DCMIResult<String> {
+        let mut description = [0; MAX_LENTH as usize];
+        call_dcmi_function!(
+            dcmi_get_device_errorcode_string,
+            self.card_id as i32,
+            self.id as i32,
+            error_code,
+            description.as_mut_ptr().cast(),
+            description.len() as i32
+        )?;
+        convert_string(&description)
+    }
+
+    /// Query the temperature of the chip
+    ///
+    /// # Returns
+    /// temperature of the chip
+    pub fn get_temperature(&self) -> DCMIResult<Celsius> {
+        let mut temperature = 0;
+        call_dcmi_function!(
+            dcmi_get_device_temperature,
+            self.card_id as i32,
+            self.id as i32,
+            &mut temperature
+        )?;
+        Ok(Celsius(temperature))
+    }
+
+    /// Query the temperatures of all sensors of the chip in one go
+    ///
+    /// DCMI has no single call for every sensor, they are read back to back so the readings are
+    /// as close in time as possible. Sensors the chip does not have are left out.
+    pub fn get_temperatures(&self) -> DCMIResult<Temperatures> {
+        let chip = self.get_temperature()?;
+        let mut ai_core = None;
+        for sensor_id in [
+            dcmi_manager_sensor_id_DCMI_AICORE0_TEMP_ID,
+            dcmi_manager_sensor_id_DCMI_AICORE1_TEMP_ID,
+        ] {
+            if let Some(info) = self.get_sensor_info(sensor_id)? {
+                ai_core = ai_core.max(Some(Celsius(unsafe { info.uchar } as i32)));
+            }
+        }
+        let hbm = self
+            .get_sensor_info(dcmi_manager_sensor_id_DCMI_HBM_TEMP_ID)?
+            .map(|info| Celsius(unsafe { info.uchar } as i32));
+        let board = self
+            .get_sensor_info(dcmi_manager_sensor_id_DCMI_NTC_TEMP_ID)?
+            .and_then(|info| unsafe { info.ntc_tmp }.into_iter().max())
+            .map(Celsius);
+        Ok(Temperatures::new(chip, ai_core, hbm, board))
+    }
+
+    /// Query the over-temperature thresholds of the chip
+    ///
+    /// DCMI has no call to change them, they are set by the firmware.
+    pub fn get_temperature_thresholds(&self) -> DCMIResult<TemperatureThresholds> {
+        let info = self
+            .get_sensor_info(dcmi_manager_sensor_id_DCMI_THERMAL_THRESHOLD_ID)?
+            .ok_or(DCMIError::NotSupport)?;
+        convert(unsafe { info.temp })
+    }
+
+    /// Read a sensor, `None` if the chip does not have it
+    fn get_sensor_info(
+        &self,
+        sensor_id: dcmi_manager_sensor_id,
+    ) -> DCMIResult<Option<dcmi_sensor_info>> {
+        let mut sensor_info = dcmi_sensor_info { data: [0; 16] };
+        match call_dcmi_function!(
+            dcmi_get_device_sensor_info,
+            self.card_id as i32,
+            self.id as i32,
+            sensor_id,
+            &mut sensor_info
+        ) {
+            Ok(()) => Ok(Some(sensor_info)),
+            Err(e) if e.kind() == &DCMIError::NotSupport => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Query the voltage of the chip
+    ///
+    /// # Returns
+    /// voltage of the chip, DCMI reports it in 0.01V steps
+    pub fn get_voltage(&self) -> DCMIResult<Millivolts> {
+        let mut voltage = 0;
+        call_dcmi_function!(
+            dcmi_get_device_voltage,
+            self.card_id as i32,
+            self.id as i32,
+            &mut voltage
+        )?;
+        Ok(Millivolts::from_centivolts(voltage))
+    }
+
+    /// Query voltage and current of every rail the chip reports
+    ///
+    /// Rails the chip does not report are skipped. HBM and board input rails are not exposed by
+    /// DCMI.
+    pub fn get_voltage_rails(&self) -> DCMIResult<Vec<VoltageRailInfo>> {
+        let mut rails = Vec::new();
+        for rail in VoltageRail::iter() {
+            match self.get_voltage_rail(rail) {
+                Ok(info) => rails.push(info),
+                Err(e) if e.kind() == &DCMIError::NotSupport => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(rails)
+    }
+
+    /// Query the power consumption of the chip
+    ///
+    /// # Returns
+    /// power consumption of the chip, DCMI reports it in 0.1W steps
+    pub fn get_power_info(&self) -> DCMIResult<Watts> {
+        let mut power = 0;
+        call_dcmi_function!(
+            dcmi_get_device_power_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut power
+        )?;
+        Ok(Watts::from_deciwatts(power as u32))
+    }
+
+    /// Query the utilization of a component of the chip
+    ///
+    /// Some utilizations read as 0 for a vNPU in a container, see
+    /// [`Chip::container_restrictions`].
+    ///
+    /// # Returns
+    /// utilization, unit: %
+    pub fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32> {
+        let mut utilization_rate = 0;
+        call_dcmi_function!(
+            dcmi_get_device_utilization_rate,
+            self.card_id as i32,
+            self.id as i32,
+            utilization_type.into(),
+            &mut utilization_rate
+        )?;
+        Ok(utilization_rate)
+    }
+
+    /// Query several utilization rates of the chip in one go
+    ///
+    /// DCMI has no single call for several components, they are read back to back so the rates
+    /// are as close in time as possible. Components the chip does not have are left out.
+    ///
+    /// ```no_run
+    /// # use hw_dcmi::DCMI;
+    /// use hw_dcmi::enums::UtilizationType;
+    /// use strum::IntoEnumIterator;
+    ///
+    /// let dcmi = DCMI::init().unwrap();
+    /// for chip in dcmi.all_chips().unwrap() {
+    ///     let rates = chip.get_utilization_rates(UtilizationType::iter()).unwrap();
+    ///     println!("{chip}: {rates:?}");
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    /// components with their utilization, in the order requested, unit: %
+    pub fn get_utilization_rates(
+        &self,
+        utilization_types: impl IntoIterator<Item = UtilizationType>,
+    ) -> DCMIResult<Vec<(UtilizationType, u32)>> {
+        let mut rates = Vec::new();
+        for utilization_type in utilization_types {
+            match self.get_utilization_rate(utilization_type) {
+                Ok(rate) => rates.push((utilization_type, rate)),
+                Err(e) if e.is_unsupported() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(rates)
+    }
+
+    /// Query the frequency of a clock of the chip
+    ///
+    /// # Returns
+    /// frequency of the clock
+    pub fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz> {
+        let mut frequency = 0;
+        call_dcmi_function!(
+            dcmi_get_device_frequency,
+            self.card_id as i32,
+            self.id as i32,
+            frequency_type.into(),
+            &mut frequency
+        )?;
+        Ok(MegaHertz(frequency))
+    }
+
+    /// Query the frequencies of all clocks of the chip in one go
+    ///
+    /// DCMI has no single call for every clock, they are read back to back. Clocks the chip does
+    /// not have are left out.
+    pub fn get_frequencies(&self) -> DCMIResult<Frequencies> {
+        let read = |frequency_type| match self.get_frequency(frequency_type) {
+            Ok(frequency) => Ok(Some(frequency)),
+            Err(e) if e.is_unsupported() => Ok(None),
+            Err(e) => Err(e),
+        };
+        Ok(Frequencies {
+            ddr: read(FrequencyType::Ddr)?,
+            ctrl_cpu: read(FrequencyType::CtrlCpu)?,
+            hbm: read(FrequencyType::Hbm)?,
+            ai_core_current: read(FrequencyType::AiCoreCurrent)?,
+            ai_core_max: read(FrequencyType::AiCoreMax)?,
+            vector_core_current: read(FrequencyType::VectorCoreCurrent)?,
+        })
+    }
+
+    /// Query the number of vector cores of the chip, 0 on chips without vector cores
+    ///
+    /// Vector cores run the vector operators on Ascend 310P chips, next to the AI cores counted
+    /// by [`ChipInfo::aicore_count`].
+    pub fn get_vector_core_count(&self) -> DCMIResult<u32> {
+        Ok(self.get_domain_info()?.vector_core_num.max(0) as u32)
+    }
+
+    /// Query the utilization of the vector cores, unit: %
+    pub fn get_vector_core_utilization(&self) -> DCMIResult<u32> {
+        self.get_utilization_rate(UtilizationType::VectorCore)
+    }
+
+    /// Query the current frequency of the vector cores
+    pub fn get_vector_core_frequency(&self) -> DCMIResult<MegaHertz> {
+        self.get_frequency(FrequencyType::VectorCoreCurrent)
+    }
+
+    /// Query the memory (DDR) information of the chip
+    ///
+    /// Uses `dcmi_get_device_memory_info_v3` and falls back to the v2 and v1 calls while the
+    /// driver reports [`DCMIError::NotSupport`]. Drivers without [`Feature::MemoryInfoV3`] report
+    /// no huge pages, their available memory is derived from the utilization.
+    pub fn get_memory_info(&self) -> DCMIResult<MemoryInfo> {
+        if self.dcmi.supports(Feature::MemoryInfoV3) {
+            match self.get_memory_info_v3() {
+                Err(e) if e.kind() == &DCMIError::NotSupport => {}
+                result => return result,
+            }
+        }
+        match self.get_memory_info_v2() {
+            Err(e) if e.kind() == &DCMIError::NotSupport => self.get_memory_info_v1(),
+            result => result,
+        }
+    }
+
+    fn get_memory_info_v3(&self) -> DCMIResult<MemoryInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut memory_info: dcmi_get_memory_info_stru = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_memory_info_v3,
+            self.card_id as i32,
+            self.id as i32,
+            &mut memory_info
+        )?;
+        convert(memory_info)
+    }
+
+    fn get_memory_info_v2(&self) -> DCMIResult<MemoryInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut memory_info: dcmi_memory_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_memory_info_v2,
+            self.card_id as i32,
+            self.id as i32,
+            &mut memory_info
+        )?;
+        convert(memory_info)
+    }
+
+    fn get_memory_info_v1(&self) -> DCMIResult<MemoryInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut memory_info: dcmi_memory_info_stru = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_memory_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut memory_info
+        )?;
+        convert(memory_info)
+    }
+
+    /// Query the HBM information of the chip
+    ///
+    /// # Warning
+    /// Only supported by chips with HBM (e.g. Ascend 910 series)
+    pub fn get_hbm_info(&self) -> DCMIResult<HBMInfo> {
+        let mut hbm_info = dcmi_hbm_info {
+            memory_size: 0,
+            freq: 0,
+            memory_usage: 0,
+            temp: 0,
+            bandwith_util_rate: 0,
+        };
+        call_dcmi_function!(
+            dcmi_get_device_hbm_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut hbm_info
+        )?;
+        convert(hbm_info)
+    }
+
+    /// Query the PCIe identity and address of the chip
+    ///
+    /// Uses `dcmi_get_device_pcie_info_v2` and falls back to the v1 call if the driver reports
+    /// [`DCMIError::NotSupport`]. Drivers without [`Feature::PcieInfoV2`] do not report the PCI
+    /// domain, it is 0.
+    pub fn get_pcie_info(&self) -> DCMIResult<PCIEInfo> {
+        if self.dcmi.supports(Feature::PcieInfoV2) {
+            // SAFETY: plain C struct, all-zero is a valid value
+            let mut pcie_info: dcmi_pcie_info_all = unsafe { std::mem::zeroed() };
+            match call_dcmi_function!(
+                dcmi_get_device_pcie_info_v2,
+                self.card_id as i32,
+                self.id as i32,
+                &mut pcie_info
+            ) {
+                Err(e) if e.kind() == &DCMIError::NotSupport => {}
+                result => return result.and_then(|()| convert(pcie_info)),
+            }
+        }
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut pcie_info: dcmi_pcie_info = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_pcie_info,
+            self.card_id as i32,
+            self.id as i32,
+            &mut pcie_info
+        )?;
+        convert(pcie_info)
+    }
+
+    /// List the SR-IOV virtual functions of the chip and the drivers they are bound to
+    ///
+    /// DCMI does not manage VFs, they are read from `/sys/bus/pci/devices/<bdf>/virtfn*`. Returns
+    /// an empty list if SR-IOV is disabled. This is unrelated to vNPUs created by compute
+    /// splitting.
+    pub fn get_virtual_functions(&self) -> DCMIResult<Vec<VirtualFunction>> {
+        read_virtual_functions(&self.sysfs_pci_dir()?).map_err(|_| DCMIError::FileOperateFail)
+    }
+
+    /// Query the negotiated PCIe link speed and width of the chip
+    ///
+    /// DCMI does not expose the link state, it is read from the link attributes in sysfs.
+    pub fn get_pcie_link_status(&self) -> DCMIResult<PCIELinkStatus> {
+        pci_config::read_link_status(&self.sysfs_pci_dir()?).map_err(sysfs_error)
+    }
+
+    /// Query the PCIe max payload size and max read request size of the chip
+    ///
+    /// DCMI does not expose these settings, they are read from the PCI config space in sysfs.
+    ///
+    /// # Warning
+    /// Reading the PCI Express capability requires root
+    pub fn get_pcie_payload_settings(&self) -> DCMIResult<PCIEPayloadSettings> {
+        pci_config::read_payload_settings(&self.sysfs_pci_dir()?.join("config"))
+            .map_err(sysfs_error)
+    }
+
+    /// Set the PCIe max read request size (MRRS) of the chip
+    ///
+    /// # Parameters
+    /// - size: 128, 256, 512, 1024, 2048 or 4096, unit: byte
+    ///
+    /// # Warning
+    /// Requires root, the setting is lost when the device is reset
+    pub fn set_pcie_max_read_request_size(&self, size: u32) -> DCMIResult<()> {
+        pci_config::write_size(
+            &self.sysfs_pci_dir()?.join("config"),
+            SizeField::MaxReadRequest,
+            size,
+        )
+        .map_err(sysfs_error)
+    }
+
+    /// Set the PCIe max payload size (MPS) of the chip
+    ///
+    /// # Parameters
+    /// - size: 128 up to `max_payload_size_supported`, power of two, unit: byte
+    ///
+    /// # Warning
+    /// Requires root, the setting is lost when the device is reset. The MPS must not exceed the
+    /// MPS of the upstream port, otherwise the link reports malformed TLPs.
+    pub fn set_pcie_max_payload_size(&self, size: u32) -> DCMIResult<()> {
+        if size > self.get_pcie_payload_settings()?.max_payload_size_supported {
+            return Err(DCMIError::InvalidParameter);
+        }
+        pci_config::write_size(
+            &self.sysfs_pci_dir()?.join("config"),
+            SizeField::MaxPayload,
+            size,
+        )
+        .map_err(sysfs_error)
+    }
+
+    /// List the processes using the chip and their device memory
+    pub fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>> {
+        // dcmi_get_device_resource_info does not take the buffer length, DCMI reports at most
+        // 32 processes per chip
+        let mut proc_info = [dcmi_proc_mem_info {
+            proc_id: 0,
+            proc_mem_usage: 0,
+        }; 64];
+        let mut proc_num = 0;
+        call_dcmi_function!(
+            dcmi_get_device_resource_info,
+            self.card_id as i32,
+            self.id as i32,
+            proc_info.as_mut_ptr(),
+            &mut proc_num
+        )?;
+        proc_info[..proc_num.clamp(0, proc_info.len() as i32) as usize]
+            .iter()
+            .map(|&info| convert(info))
+            .collect()
+    }
+
+    /// Check whether the chip can be handed to a VM through PCI passthrough
+    ///
+    /// The host driver is only unbound if `unbind_host_driver` is set and no host process uses
+    /// the chip. Binding the chip to `vfio-pci` is left to the caller or the hypervisor.
+    ///
+    /// # Warning
+    /// Unbinding requires root. Once the host driver is unbound the chip disappears from DCMI,
+    /// this handle must not be used afterwards.
+    pub fn prepare_for_passthrough(
+        &self,
+        unbind_host_driver: bool,
+    ) -> DCMIResult<PassthroughReadiness> {
+        let bdf = self.get_pcie_info()?.bdf();
+        let processes = self.get_processes()?;
+        passthrough::prepare(
+            &Path::new("/sys/bus/pci/devices").join(&bdf),
+            &bdf,
+            processes,
+            unbind_host_driver,
+        )
+        .map_err(sysfs_error)
+    }
+
+    fn sysfs_pci_dir(&self) -> DCMIResult<PathBuf> {
+        let bdf = self.get_pcie_info()?.bdf();
+        Ok(Path::new("/sys/bus/pci/devices").join(bdf))
+    }
+
+    /// Reset the chip through the given channel
+    ///
+    /// An out-of-band reset goes through the management MCU of the card and also works when the
+    /// chip no longer answers on PCIe.
+    ///
+    /// # Warning
+    /// Every process using the chip loses it, vNPUs are destroyed
+    pub fn reset(&self, channel: Channel) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_device_reset,
+            self.card_id as i32,
+            self.id as i32,
+            channel.into()
+        )
+    }
+
+    /// Query whether the out-of-band channel between the chip and the management MCU works
+    pub fn is_outband_channel_ok(&self) -> DCMIResult<bool> {
+        let mut state = 0;
+        call_dcmi_function!(
+            dcmi_get_device_outband_channel_state,
+            self.card_id as i32,
+            self.id as i32,
+            &mut state
+        )?;
+        Ok(state != 0)
+    }
+
+    /// Query the PCIe link error statistics of the chip
+    ///
+    /// PCIe replay and retry counters are not exposed by DCMI, see
+    /// [`crate::monitor::PcieLinkTracker`] for a link quality score built from these counters.
+    pub fn get_pcie_error_info(&self) -> DCMIResult<PCIEErrorInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut error_info: dcmi_chip_pcie_err_rate = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_get_device_pcie_error_cnt,
+            self.card_id as i32,
+            self.id as i32,
+            &mut error_info
+        )?;
+        convert(error_info)
+    }
+
+    /// Query whether peer-to-peer (device to device) transfers are enabled for the chip
+    ///
+    /// DCMI only reports the state per chip, it is configured by the driver and cannot be
+    /// changed through DCMI.
+    pub fn is_p2p_enabled(&self) -> DCMIResult<bool> {
+        let mut enable_flag = 0;
+        call_dcmi_function!(
+            dcmi_get_device_p2p_enable,
+            self.card_id as i32,
+            self.id as i32,
+            &mut enable_flag
+        )?;
+        Ok(enable_flag != 0)
+    }
+
+    /// Query how this chip is connected to `other`
+    pub fn get_topology_to(&self, other: &Chip) -> DCMIResult<TopologyType> {
+        let mut topology_type = 0;
+        call_dcmi_function!(
+            dcmi_get_topo_info_by_device_id,
+            self.card_id as i32,
+            self.id as i32,
+            other.card_id as i32,
+            other.id as i32,
+            &mut topology_type
+        )?;
+        convert(topology_type)
+    }
+
+    /// Query the CPUs closest to the chip, e.g. for pinning the threads feeding it
+    pub fn get_affinity_cpus(&self) -> DCMIResult<Vec<u32>> {
+        let mut affinity_cpu = [0; TOPO_INFO_MAX_LENTH as usize];
+        let mut length = affinity_cpu.len() as i32;
+        call_dcmi_function!(
+            dcmi_get_affinity_cpu_info_by_device_id,
+            self.card_id as i32,
+            self.id as i32,
+            affinity_cpu.as_mut_ptr(),
+            &mut length
+        )?;
+        numa::parse_cpu_list(&convert_string(&affinity_cpu)?).map_err(sysfs_error)
+    }
+
+    /// Query the NUMA node the chip is attached to, `None` if the machine does not report one
+    ///
+    /// The node is read from sysfs. If the kernel does not know it, e.g. in some VMs, it is the
+    /// node of the CPUs DCMI reports as closest to the chip.
+    pub fn get_numa_node(&self) -> DCMIResult<Option<u32>> {
+        let sysfs_node = self
+            .sysfs_pci_dir()
+            .and_then(|pci_dir| numa::read_numa_node(&pci_dir).map_err(sysfs_error));
+        if let Ok(Some(node)) = sysfs_node {
+            return Ok(Some(node));
+        }
+        let cpus = self.get_affinity_cpus()?;
+        numa::node_of_cpus(Path::new(numa::NODE_ROOT), &cpus).map_err(sysfs_error)
+    }
+
+    /// Query the id of one die of the chip
+    pub fn get_die_info(&self, die_type: DieType) -> DCMIResult<DieInfo> {
+        let mut die_id = dcmi_die_id { soc_die: [0; 5] };
+        call_dcmi_function!(
+            dcmi_get_device_die_v2,
+            self.card_id as i32,
+            self.id as i32,
+            die_type.into(),
+            &mut die_id
+        )?;
+        convert(die_id)
+    }
+
+    /// Query the dies of a multi-die package
+    ///
+    /// Die types the chip does not have are skipped, so single-die chips return at most one
+    /// die. DCMI reports telemetry per package, only die identity is die-scoped.
+    pub fn dies(&self) -> DCMIResult<Vec<Die<'a>>> {
+        let mut dies = Vec::new();
+        for die_type in [DieType::NDie, DieType::VDie] {
+            match self.get_die_info(die_type) {
+                Ok(info) => dies.push(Die {
+                    chip: *self,
+                    die_type,
+                    info,
+                }),
+                Err(e) if e.kind() == &DCMIError::NotSupport => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(dies)
+    }
+
+    /// Query the ECC state and error statistics of a memory type
+    ///
+    /// # Parameters
+    /// - device_type: memory type, only `DDR`, `SRAM`, `HBM` and `NPU` are meaningful here
+    pub fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo> {
+        let mut ecc_info = dcmi_ecc_info {
+            enable_flag: 0,
+            single_bit_error_cnt: 0,
+            double_bit_error_cnt: 0,
+            total_single_bit_error_cnt: 0,
+            total_double_bit_error_cnt: 0,
+            single_bit_isolated_pages_cnt: 0,
+            double_bit_isolated_pages_cnt: 0,
+        };
+        call_dcmi_function!(
+            dcmi_get_device_ecc_info,
+            self.card_id as i32,
+            self.id as i32,
+            device_type.into(),
+            &mut ecc_info
+        )?;
+        convert(ecc_info)
+    }
+
+    /// Query the DDR and HBM ECC statistics of the chip at once
+    ///
+    /// Memories the chip does not have (`NotSupport`) are left out of the summary.
+    pub fn get_ecc_summary(&self) -> DCMIResult<ECCSummary> {
+        let query = |device_type| match self.get_ecc_info(device_type) {
+            Ok(info) => Ok(Some(info)),
+            Err(e) if e.kind() == &DCMIError::NotSupport => Ok(None),
+            Err(e) => Err(e),
+        };
+        Ok(ECCSummary {
+            ddr: query(DeviceType::DDR)?,
+            hbm: query(DeviceType::HBM)?,
+        })
+    }
+
+    /// Query the HBM addresses at which ECC errors were recorded
+    ///
+    /// # Parameters
+    /// - device_type: [`DeviceType::HBMRecordedSingleAddr`] for single-bit error addresses or
+    ///   [`DeviceType::HBMRecordedMultiAddr`] for multi-bit error addresses
+    ///
+    /// # Returns
+    /// at most `MAX_RECORD_ECC_ADDR_COUNT` records, the number of recorded addresses is
+    /// reported by [`Chip::get_ecc_info`] with the same device type
+    pub fn get_hbm_error_addresses(
+        &self,
+        device_type: DeviceType,
+    ) -> DCMIResult<Vec<ECCAddressRecord>> {
+        let read_type = match device_type {
+            DeviceType::HBMRecordedSingleAddr => ECC_INFO_READ_SINGLE_ECC_INFO_READ,
+            DeviceType::HBMRecordedMultiAddr => ECC_INFO_READ_MULTI_ECC_INFO_READ,
+            _ => return Err(DCMIError::InvalidParameter),
+        };
+        let record_type = dcmi_ecc_record_type {
+            read_type,
+            module_type: DeviceType::HBM.into(),
+        };
+        let mut ecc_count = 0;
+        let mut records = [dcmi_ecc_common_data {
+            physical_addr: 0,
+            stack_pc_id: 0,
+            reg_addr_h: 0,
+            reg_addr_l: 0,
+            ecc_count: 0,
+            timestamp: 0,
+        }; MAX_RECORD_ECC_ADDR_COUNT as usize];
+        call_dcmi_function!(
+            dcmi_get_multi_ecc_record_info_v2,
+            self.card_id as i32,
+            self.id as i32,
+            record_type,
+            &mut ecc_count,
+            records.as_mut_ptr()
+        )?;
+        records[..(ecc_count as usize).min(records.len())]
+            .iter()
+            .map(|&record| convert(record))
+            .collect()
+    }
+
+    /// Reset the ECC error statistics and isolated page counters of the chip
+    ///
+    /// DCMI clears the statistics of every memory type of the chip at once, typically done after
+    /// memory repair or RMA triage.
+    ///
+    /// # Warning
+    /// Requires root privileges
+    pub fn clear_ecc_isolated_pages(&self) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_device_clear_ecc_statistics_info,
+            self.card_id as i32,
+            self.id as i32
+        )
+    }
+
+    /// Write a user configuration item of the chip
+    ///
+    /// User configuration is a persistent key-value store on the device, it is the only way to
+    /// reach some device settings (e.g. RoCE parameters).
+    ///
+    /// # Parameters
+    /// - key: name of the configuration item
+    /// - value: raw content of the item, at most [`USER_CONFIG_MAX_LEN`] bytes
+    ///
+    /// # Warning
+    /// Requires root privileges
+    pub fn set_user_config(&self, key: &str, value: &[u8]) -> DCMIResult<()> {
+        let key = CString::new(key).map_err(|_| DCMIError::InvalidParameter)?;
+        if value.is_empty() || value.len() > USER_CONFIG_MAX_LEN {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut buf = value.to_vec();
+        call_dcmi_function!(
+            dcmi_set_user_config,
+            self.card_id as i32,
+            self.id as i32,
+            key.as_ptr(),
+            buf.len() as u32,
+            buf.as_mut_ptr()
+        )
+    }
+
+    /// Read a user configuration item of the chip
+    ///
+    /// # Parameters
+    /// - key: name of the configuration item
+    ///
+    /// # Returns
+    /// raw content of the item, DCMI does not report the stored length so trailing zero bytes
+    /// are stripped
+    pub fn get_user_config(&self, key: &str) -> DCMIResult<Vec<u8>> {
+        let key = CString::new(key).map_err(|_| DCMIError::InvalidParameter)?;
+        let mut buf = vec![0u8; USER_CONFIG_MAX_LEN];
+        call_dcmi_function!(
+            dcmi_get_user_config,
+            self.card_id as i32,
+            self.id as i32,
+            key.as_ptr(),
+            buf.len() as u32,
+            buf.as_mut_ptr()
+        )?;
+        let len = buf.iter().rposition(|&b| b != 0).map_or(0, |pos| pos + 1);
+        buf.truncate(len);
+        Ok(buf)
+    }
+}
+
+impl fmt::Debug for Card<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Card").field("id", &self.id).finish()
+    }
+}
+
+impl fmt::Display for Card<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "card {}", self.id)
+    }
+}
+
+impl fmt::Debug for Chip<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chip")
+            .field("card_id", &self.card_id)
+            .field("id", &self.id)
+            .field("unit_type", &self.unit_type)
+            .finish()
+    }
+}
+
+impl fmt::Display for Chip<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{}",
+            self.unit_type.as_str(),
+            self.card_id,
+            self.id
+        )?;
+        if self.unit_type != UnitType::NPU {
+            return Ok(());
+        }
+        let details: Vec<String> = [
+            self.identity().ok().map(|identity| identity.info.model()),
+            self.get_pcie_info().ok().map(|pcie| pcie.bdf()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Chip<'_> {
+    /// Multi-line overview of the chip for operators, values that cannot be read are left out
+    ///
+    /// ```text
+    /// npu 0/1 (Ascend910B3, 0000:c1:00.0)
+    ///   health: normal
+    ///   temperature: 45 °C
+    ///   power: 72.5 W
+    ///   AI core: 30%
+    ///   memory: 8192 / 32768 MiB used (25%), 2666 MHz
+    ///   HBM: 1024 / 65536 MiB used, bandwidth 3%, 40 °C, 1600 MHz
+    ///   HBM ECC: 0 single-bit / 0 double-bit errors (total 0 / 0), 0 pages isolated
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut lines = vec![self.to_string()];
+        let mut line = |label: &str, value: Option<String>| {
+            if let Some(value) = value {
+                lines.push(format!("  {label}: {value}"));
+            }
+        };
+        line(
+            "health",
+            self.get_health().ok().map(|health| health.to_string()),
+        );
+        line(
+            "temperature",
+            self.get_temperature().ok().map(|temp| temp.to_string()),
+        );
+        line(
+            "power",
+            self.get_power_info()
+                .ok()
+                .map(|power| format!("{power:.1}")),
+        );
+        line(
+            "AI core",
+            self.get_utilization_rate(UtilizationType::AiCore)
+                .ok()
+                .map(|rate| format!("{rate}%")),
+        );
+        line(
+            "memory",
+            self.get_memory_info().ok().map(|info| info.to_string()),
+        );
+        line("HBM", self.get_hbm_info().ok().map(|info| info.to_string()));
+        if let Ok(ecc) = self.get_ecc_summary() {
+            line("DDR ECC", ecc.ddr.map(|info| info.to_string()));
+            line("HBM ECC", ecc.hbm.map(|info| info.to_string()));
+        }
+        lines.join("\n")
+    }
+}
+
+/// A die of a chip package
+#[derive(Debug, Clone, Copy)]
+pub struct Die<'a> {
+    chip: Chip<'a>,
+    die_type: DieType,
+    info: DieInfo,
+}
+
+impl<'a> Die<'a> {
+    /// Chip package the die belongs to
+    pub fn chip(&self) -> Chip<'a> {
+        self.chip
+    }
+
+    pub fn die_type(&self) -> DieType {
+        self.die_type
+    }
+
+    /// Unique id of the die
+    pub fn info(&self) -> &DieInfo {
+        &self.info
+    }
+}
+
+fn ip_addr_from_raw(addr: &dcmi_ip_addr) -> IpAddr {
+    // SAFETY: both union members are plain byte arrays
+    unsafe {
+        if addr.ip_type == dcmi_ip_addr_type_DCMI_IPADDR_TYPE_V6 {
+            IpAddr::from(addr.u_addr.ip6)
+        } else {
+            IpAddr::from(addr.u_addr.ip4)
+        }
+    }
+}
+
+/// Map an error of a sysfs access to the closest DCMI error
+pub(crate) fn sysfs_error(error: std::io::Error) -> DCMIError {
+    match error.kind() {
+        std::io::ErrorKind::InvalidInput => DCMIError::InvalidParameter,
+        std::io::ErrorKind::PermissionDenied => DCMIError::OperationNotPermitted,
+        std::io::ErrorKind::Unsupported => DCMIError::NotSupport,
+        _ => DCMIError::FileOperateFail,
+    }
+}
+
+/// Read the `virtfn*` links of a PCI physical function directory in sysfs
+fn read_virtual_functions(pf_dir: &Path) -> std::io::Result<Vec<VirtualFunction>> {
+    let file_name = |path: &Path| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    };
+    let mut vfs = Vec::new();
+    for entry in std::fs::read_dir(pf_dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(index) = name
+            .strip_prefix("virtfn")
+            .and_then(|index| index.parse().ok())
+        else {
+            continue;
+        };
+        let vf_dir = std::fs::read_link(entry.path())?;
+        let Some(bdf) = file_name(&vf_dir) else {
+            continue;
+        };
+        let driver = std::fs::read_link(entry.path().join("driver"))
+            .ok()
+            .and_then(|driver| file_name(&driver));
+        vfs.push(VirtualFunction { index, bdf, driver });
+    }
+    vfs.sort_by_key(|vf| vf.index);
+    Ok(vfs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn reads_virtual_functions_from_sysfs_layout() {
+        let root = std::env::temp_dir().join(format!("hw_dcmi_vf_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let pf = root.join("0000:c1:00.0");
+        let vf0 = root.join("0000:c1:00.1");
+        let vf1 = root.join("0000:c1:00.2");
+        let driver = root.join("drivers/vfio-pci");
+        for dir in [&pf, &vf0, &vf1, &driver] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        symlink(&vf1, pf.join("virtfn1")).unwrap();
+        symlink(&vf0, pf.join("virtfn0")).unwrap();
+        symlink(&driver, vf1.join("driver")).unwrap();
+        std::fs::write(pf.join("vendor"), "0x19e5").unwrap();
+
+        let vfs = read_virtual_functions(&pf).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            vfs,
+            vec![
+                VirtualFunction {
+                    index: 0,
+                    bdf: "0000:c1:00.1".to_string(),
+                    driver: None,
+                },
+                VirtualFunction {
+                    index: 1,
+                    bdf: "0000:c1:00.2".to_string(),
+                    driver: Some("vfio-pci".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_identity_without_dcmi() {
+        let dcmi = DCMI { _private: () };
+        let chip = Chip::new(&dcmi, 2, 1, UnitType::NPU);
+        assert_eq!(
+            format!("{chip:?}"),
+            "Chip { card_id: 2, id: 1, unit_type: NPU }"
+        );
+        // model and address are left out when DCMI cannot be queried
+        assert_eq!(chip.to_string(), "npu 2/1");
+        assert_eq!(chip.summary(), "npu 2/1");
+        assert_eq!(chip.card().to_string(), "card 2");
+    }
+}
@@ -0,0 +1,89 @@
+//! NUMA locality of a chip
+//!
+//! The kernel reports the NUMA node of a PCI device in `/sys/bus/pci/devices/<bdf>/numa_node`,
+//! which is `-1` on machines or VMs without NUMA information for the device. DCMI reports the
+//! CPUs close to a chip instead, as a list like `0-23,48-71`, which is matched against the CPU
+//! lists of the nodes in `/sys/devices/system/node`.
+
+use std::io;
+use std::path::Path;
+
+/// Where the kernel lists the NUMA nodes
+pub(super) const NODE_ROOT: &str = "/sys/devices/system/node";
+
+/// Read the NUMA node of a PCI device directory in sysfs, `None` if the kernel does not know it
+pub(super) fn read_numa_node(pci_dir: &Path) -> io::Result<Option<u32>> {
+    let attr = std::fs::read_to_string(pci_dir.join("numa_node"))?;
+    let node: i32 = attr.trim().parse().map_err(|_| invalid_list(&attr))?;
+    Ok(u32::try_from(node).ok())
+}
+
+/// Parse a CPU list such as `0-23,48-71`
+pub(super) fn parse_cpu_list(list: &str) -> io::Result<Vec<u32>> {
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        let parse = |cpu: &str| cpu.trim().parse::<u32>().map_err(|_| invalid_list(list));
+        match range.split_once('-') {
+            Some((first, last)) => cpus.extend(parse(first)?..=parse(last)?),
+            None => cpus.push(parse(range)?),
+        }
+    }
+    Ok(cpus)
+}
+
+/// Node under `node_root` whose CPUs include the first of `cpus`, `None` on kernels without NUMA
+/// support
+pub(super) fn node_of_cpus(node_root: &Path, cpus: &[u32]) -> io::Result<Option<u32>> {
+    let Some(cpu) = cpus.first() else {
+        return Ok(None);
+    };
+    let entries = match std::fs::read_dir(node_root) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(node) = name.strip_prefix("node").and_then(|node| node.parse().ok()) else {
+            continue;
+        };
+        let cpu_list = std::fs::read_to_string(entry.path().join("cpulist"))?;
+        if parse_cpu_list(&cpu_list)?.contains(cpu) {
+            return Ok(Some(node));
+        }
+    }
+    Ok(None)
+}
+
+fn invalid_list(list: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected CPU or node attribute {:?}", list.trim()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_node_of_affinity_cpus() {
+        assert_eq!(parse_cpu_list("0-2,8\n").unwrap(), [0, 1, 2, 8]);
+        assert!(parse_cpu_list("").unwrap().is_empty());
+        assert!(parse_cpu_list("0-x").is_err());
+
+        let root = std::env::temp_dir().join(format!("hw_dcmi_numa_{}", std::process::id()));
+        for (node, cpu_list) in [("node0", "0-23,48-71\n"), ("node1", "24-47,72-95\n")] {
+            std::fs::create_dir_all(root.join(node)).unwrap();
+            std::fs::write(root.join(node).join("cpulist"), cpu_list).unwrap();
+        }
+        std::fs::write(root.join("numa_node"), "-1\n").unwrap();
+
+        let found = node_of_cpus(&root, &parse_cpu_list("24-47").unwrap());
+        let unknown = read_numa_node(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(found.unwrap(), Some(1));
+        assert_eq!(unknown.unwrap(), None);
+    }
+}
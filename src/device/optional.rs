@@ -0,0 +1,76 @@
+//! `try_get_*` variants of queries which some chips legitimately lack, e.g. HBM on the Ascend 310
+//! series, returning `Ok(None)` instead of an error
+
+use super::Chip;
+use crate::enums::{DeviceType, DieType, FrequencyType, HealthState, UtilizationType};
+use crate::error::DCMIResult;
+use crate::structs::{
+    BoardInfo, DieInfo, ECCInfo, ELabelInfo, HBMInfo, MemoryInfo, PCIEInfo, PCIELinkStatus,
+    TemperatureThresholds, ThrottleStatus,
+};
+use crate::units::{Celsius, MegaHertz, Millivolts, Watts};
+
+/// Map unsupported errors of a query to `None`
+fn optional<T>(result: DCMIResult<T>) -> DCMIResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.is_unsupported() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Define `try_<query>` methods of [`Chip`] mapping unsupported errors of `<query>` to `None`
+macro_rules! try_queries {
+    ($($try_name:ident => $name:ident($($arg:ident: $arg_ty:ty),*) -> $ty:ty;)*) => {
+        impl Chip<'_> {
+            $(
+                #[doc = concat!(
+                    "[`Chip::", stringify!($name), "`], `None` if the chip does not support the ",
+                    "query, see [`DCMIError::is_unsupported`](crate::error::DCMIError::is_unsupported)"
+                )]
+                pub fn $try_name(&self $(, $arg: $arg_ty)*) -> DCMIResult<Option<$ty>> {
+                    optional(self.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+try_queries! {
+    try_get_health => get_health() -> HealthState;
+    try_get_elabel_info => get_elabel_info() -> ELabelInfo;
+    try_get_board_info => get_board_info() -> BoardInfo;
+    try_get_temperature => get_temperature() -> Celsius;
+    try_get_temperature_thresholds => get_temperature_thresholds() -> TemperatureThresholds;
+    try_get_voltage => get_voltage() -> Millivolts;
+    try_get_power_info => get_power_info() -> Watts;
+    try_get_utilization_rate => get_utilization_rate(utilization_type: UtilizationType) -> u32;
+    try_get_frequency => get_frequency(frequency_type: FrequencyType) -> MegaHertz;
+    try_get_memory_info => get_memory_info() -> MemoryInfo;
+    try_get_hbm_info => get_hbm_info() -> HBMInfo;
+    try_get_pcie_info => get_pcie_info() -> PCIEInfo;
+    try_get_pcie_link_status => get_pcie_link_status() -> PCIELinkStatus;
+    try_get_die_info => get_die_info(die_type: DieType) -> DieInfo;
+    try_get_ecc_info => get_ecc_info(device_type: DeviceType) -> ECCInfo;
+    try_get_throttle_status => get_throttle_status() -> ThrottleStatus;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DCMIError;
+
+    #[test]
+    fn unsupported_queries_are_none() {
+        assert_eq!(optional(Ok(Celsius(45))), Ok(Some(Celsius(45))));
+        assert_eq!(optional::<Celsius>(Err(DCMIError::NotSupport)), Ok(None));
+        assert_eq!(
+            optional::<Celsius>(Err(DCMIError::DeviceNotExist)),
+            Ok(None)
+        );
+        assert_eq!(
+            optional::<Celsius>(Err(DCMIError::CodeTimeOut)),
+            Err(DCMIError::CodeTimeOut)
+        );
+    }
+}
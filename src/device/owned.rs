@@ -0,0 +1,140 @@
+//! Card and chip handles owning a shared [`DCMI`] instead of borrowing it
+//!
+//! [`Card`] and [`Chip`] borrow the `DCMI` they were created from, which ties them to a scope.
+//! The owned handles hold an `Arc<DCMI>`, so they can be stored in long-lived structs and moved
+//! to other threads. Queries go through the borrowed handle returned by `card()` / `chip()`.
+//!
+//! ```no_run
+//! # use hw_dcmi::DCMI;
+//! # use std::sync::Arc;
+//! let dcmi = Arc::new(DCMI::init().unwrap());
+//! let chips: Vec<_> = dcmi
+//!     .owned_cards()
+//!     .unwrap()
+//!     .iter()
+//!     .flat_map(|card| card.get_chips().unwrap())
+//!     .collect();
+//! std::thread::spawn(move || {
+//!     for chip in &chips {
+//!         println!("{}", chip.chip().get_temperature().unwrap());
+//!     }
+//! });
+//! ```
+
+use super::{Card, Chip};
+use crate::enums::UnitType;
+use crate::error::DCMIResult;
+use crate::DCMI;
+use std::sync::Arc;
+
+/// Card handle keeping the `DCMI` instance alive
+#[derive(Debug, Clone)]
+pub struct OwnedCard {
+    dcmi: Arc<DCMI>,
+    id: u32,
+}
+
+impl OwnedCard {
+    /// Card id used by DCMI
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Borrowed handle to query the card
+    pub fn card(&self) -> Card<'_> {
+        Card::new(&self.dcmi, self.id)
+    }
+
+    /// Query all chips on the card, in the order of [`Card::get_chips`]
+    pub fn get_chips(&self) -> DCMIResult<Vec<OwnedChip>> {
+        Ok(self
+            .card()
+            .get_chips()?
+            .into_iter()
+            .map(|chip| OwnedChip {
+                dcmi: self.dcmi.clone(),
+                card_id: chip.card_id(),
+                id: chip.id(),
+                unit_type: chip.unit_type(),
+            })
+            .collect())
+    }
+}
+
+/// Chip handle keeping the `DCMI` instance alive
+#[derive(Debug, Clone)]
+pub struct OwnedChip {
+    dcmi: Arc<DCMI>,
+    card_id: u32,
+    id: u32,
+    unit_type: UnitType,
+}
+
+impl OwnedChip {
+    /// Id of the card this chip belongs to
+    pub fn card_id(&self) -> u32 {
+        self.card_id
+    }
+
+    /// Chip id inside its card
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Kind of the chip
+    pub fn unit_type(&self) -> UnitType {
+        self.unit_type
+    }
+
+    /// Card this chip belongs to
+    pub fn card(&self) -> OwnedCard {
+        OwnedCard {
+            dcmi: self.dcmi.clone(),
+            id: self.card_id,
+        }
+    }
+
+    /// Borrowed handle to query the chip
+    pub fn chip(&self) -> Chip<'_> {
+        Chip::new(&self.dcmi, self.card_id, self.id, self.unit_type)
+    }
+}
+
+impl DCMI {
+    /// Query all cards managed by DCMI as owned handles
+    pub fn owned_cards(self: &Arc<Self>) -> DCMIResult<Vec<OwnedCard>> {
+        Ok(self
+            .get_card_list()?
+            .into_iter()
+            .map(|card| OwnedCard {
+                dcmi: self.clone(),
+                id: card.id(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_handles_are_send_and_static() {
+        fn assert_send_static<T: Send + Sync + 'static>(_: &T) {}
+        let card = OwnedCard {
+            dcmi: Arc::new(DCMI { _private: () }),
+            id: 3,
+        };
+        assert_send_static(&card);
+        let chip = OwnedChip {
+            dcmi: card.dcmi.clone(),
+            card_id: card.id(),
+            id: 1,
+            unit_type: UnitType::NPU,
+        };
+        let borrowed = std::thread::spawn(move || (chip.chip().card_id(), chip.card().id()))
+            .join()
+            .unwrap();
+        assert_eq!(borrowed, (3, 3));
+    }
+}
@@ -0,0 +1,95 @@
+//! Preparation of a chip for PCI passthrough to a virtual machine
+
+use crate::structs::{PassthroughReadiness, ProcessMemoryInfo};
+use std::io;
+use std::path::Path;
+
+/// Driver a device must be bound to, or left unbound, to be handed to a VM
+pub(crate) const PASSTHROUGH_DRIVER: &str = "vfio-pci";
+
+/// Check a device and unbind its host driver if requested and no process holds it
+///
+/// # Parameters
+/// - dev_dir: `/sys/bus/pci/devices/<bdf>`
+pub(super) fn prepare(
+    dev_dir: &Path,
+    bdf: &str,
+    processes: Vec<ProcessMemoryInfo>,
+    unbind_host_driver: bool,
+) -> io::Result<PassthroughReadiness> {
+    let mut unbound_driver = None;
+    if unbind_host_driver && processes.is_empty() {
+        if let Some(driver) = driver_name(dev_dir)?.filter(|driver| driver != PASSTHROUGH_DRIVER) {
+            std::fs::write(dev_dir.join("driver/unbind"), bdf)?;
+            unbound_driver = Some(driver);
+        }
+    }
+    // kernels older than 5.15 have no reset_method, `reset` alone means a reset is possible
+    let reset_methods = match std::fs::read_to_string(dev_dir.join("reset_method")) {
+        Ok(methods) => methods.split_whitespace().map(str::to_string).collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if dev_dir.join("reset").exists() {
+                vec!["unknown".to_string()]
+            } else {
+                Vec::new()
+            }
+        }
+        Err(e) => return Err(e),
+    };
+    Ok(PassthroughReadiness {
+        bdf: bdf.to_string(),
+        processes,
+        driver: driver_name(dev_dir)?,
+        unbound_driver,
+        reset_methods,
+    })
+}
+
+fn driver_name(dev_dir: &Path) -> io::Result<Option<String>> {
+    match std::fs::read_link(dev_dir.join("driver")) {
+        Ok(driver) => Ok(driver
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Mebibytes;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn unbinds_idle_device_only() {
+        let root = std::env::temp_dir().join(format!("hw_dcmi_pt_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let dev = root.join("0000:c1:00.0");
+        let driver = root.join("drivers/devdrv_device_driver");
+        std::fs::create_dir_all(&dev).unwrap();
+        std::fs::create_dir_all(&driver).unwrap();
+        symlink(&driver, dev.join("driver")).unwrap();
+        std::fs::write(dev.join("reset_method"), "flr bus\n").unwrap();
+        std::fs::write(driver.join("unbind"), "").unwrap();
+
+        let busy = vec![ProcessMemoryInfo {
+            pid: 42,
+            memory_usage: Mebibytes(1024),
+        }];
+        let readiness = prepare(&dev, "0000:c1:00.0", busy, true).unwrap();
+        assert!(!readiness.is_ready());
+        assert_eq!(readiness.unbound_driver, None);
+        assert!(readiness.supports_hot_reset());
+
+        let readiness = prepare(&dev, "0000:c1:00.0", Vec::new(), true).unwrap();
+        let unbind = std::fs::read_to_string(driver.join("unbind")).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(unbind, "0000:c1:00.0");
+        assert_eq!(
+            readiness.unbound_driver.as_deref(),
+            Some("devdrv_device_driver")
+        );
+        assert_eq!(readiness.reset_methods, ["flr", "bus"]);
+    }
+}
@@ -0,0 +1,190 @@
+//! Access to the PCI configuration space through sysfs
+//!
+//! DCMI does not expose the PCIe device control settings, they are read from and written to
+//! `/sys/bus/pci/devices/<bdf>/config` like `setpci` does. The negotiated link speed and width
+//! are read from the link attributes next to it, which unlike the extended config space are
+//! readable without root.
+
+use crate::structs::{PCIELinkStatus, PCIEPayloadSettings};
+use std::fs::OpenOptions;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::Path;
+
+const CAPABILITY_POINTER: usize = 0x34;
+const CAPABILITY_ID_PCIE: u8 = 0x10;
+const DEVICE_CAPABILITIES: usize = 0x04;
+const DEVICE_CONTROL: usize = 0x08;
+/// Size of the config header readable without `CAP_SYS_ADMIN`
+const UNPRIVILEGED_CONFIG_LEN: usize = 64;
+
+/// Largest payload/read request size encoding defined by the PCIe spec (4096 bytes)
+const MAX_SIZE_ENCODING: u16 = 5;
+const MPS_SHIFT: u16 = 5;
+const MRRS_SHIFT: u16 = 12;
+
+/// Field of the device control register holding a payload or read request size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SizeField {
+    MaxPayload,
+    MaxReadRequest,
+}
+
+impl SizeField {
+    fn shift(self) -> u16 {
+        match self {
+            SizeField::MaxPayload => MPS_SHIFT,
+            SizeField::MaxReadRequest => MRRS_SHIFT,
+        }
+    }
+}
+
+pub(super) fn read_payload_settings(config_path: &Path) -> io::Result<PCIEPayloadSettings> {
+    let config = std::fs::read(config_path)?;
+    let cap = find_pcie_capability(&config)?;
+    let capabilities = read_u16(&config, cap + DEVICE_CAPABILITIES)?;
+    let control = read_u16(&config, cap + DEVICE_CONTROL)?;
+    Ok(PCIEPayloadSettings {
+        max_payload_size: decode_size(control >> MPS_SHIFT),
+        max_payload_size_supported: decode_size(capabilities),
+        max_read_request_size: decode_size(control >> MRRS_SHIFT),
+    })
+}
+
+/// Set a size field of the device control register, `size` must be a valid encoding size
+pub(super) fn write_size(config_path: &Path, field: SizeField, size: u32) -> io::Result<()> {
+    let encoded = encode_size(size).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, format!("invalid size {size}"))
+    })?;
+    let config = std::fs::read(config_path)?;
+    let offset = find_pcie_capability(&config)? + DEVICE_CONTROL;
+    let control = read_u16(&config, offset)?;
+    let control = control & !(0x7 << field.shift()) | encoded << field.shift();
+
+    let mut file = OpenOptions::new().write(true).open(config_path)?;
+    file.seek(SeekFrom::Start(offset as u64))?;
+    file.write_all(&control.to_le_bytes())
+}
+
+pub(super) fn read_link_status(pci_dir: &Path) -> io::Result<PCIELinkStatus> {
+    let read = |name: &str| std::fs::read_to_string(pci_dir.join(name));
+    Ok(PCIELinkStatus {
+        speed: parse_link_speed(&read("current_link_speed")?)?,
+        max_speed: parse_link_speed(&read("max_link_speed")?)?,
+        width: parse_link_width(&read("current_link_width")?)?,
+        max_width: parse_link_width(&read("max_link_width")?)?,
+    })
+}
+
+/// Parse a link speed attribute such as `16.0 GT/s PCIe` or `8 GT/s`, unit: GT/s
+fn parse_link_speed(attr: &str) -> io::Result<f32> {
+    attr.split_whitespace()
+        .next()
+        .and_then(|speed| speed.parse().ok())
+        .ok_or_else(|| invalid_attribute(attr))
+}
+
+fn parse_link_width(attr: &str) -> io::Result<u32> {
+    attr.trim().parse().map_err(|_| invalid_attribute(attr))
+}
+
+fn invalid_attribute(attr: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected link attribute {:?}", attr.trim()),
+    )
+}
+
+fn find_pcie_capability(config: &[u8]) -> io::Result<usize> {
+    let mut offset = *config
+        .get(CAPABILITY_POINTER)
+        .ok_or_else(|| truncated(config))? as usize;
+    // the list lives in the 256 byte legacy config space, at most 48 entries fit
+    for _ in 0..48 {
+        if offset == 0 {
+            break;
+        }
+        let id = *config.get(offset).ok_or_else(|| truncated(config))?;
+        if id == CAPABILITY_ID_PCIE {
+            return Ok(offset);
+        }
+        offset = *config.get(offset + 1).ok_or_else(|| truncated(config))? as usize & !0x3;
+    }
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "no PCI Express capability",
+    ))
+}
+
+fn read_u16(config: &[u8], offset: usize) -> io::Result<u16> {
+    config
+        .get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| truncated(config))
+}
+
+fn truncated(config: &[u8]) -> io::Error {
+    if config.len() <= UNPRIVILEGED_CONFIG_LEN {
+        io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "extended config space is only readable by root",
+        )
+    } else {
+        io::Error::new(io::ErrorKind::InvalidData, "truncated config space")
+    }
+}
+
+fn decode_size(field: u16) -> u32 {
+    128 << (field & 0x7).min(MAX_SIZE_ENCODING)
+}
+
+fn encode_size(size: u32) -> Option<u16> {
+    (0..=MAX_SIZE_ENCODING).find(|&encoded| 128 << encoded == size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_and_writes_device_control() {
+        let mut config = vec![0u8; 256];
+        config[CAPABILITY_POINTER] = 0x40;
+        // power management capability, then PCI Express at 0x70
+        config[0x40] = 0x01;
+        config[0x41] = 0x70;
+        config[0x70] = CAPABILITY_ID_PCIE;
+        config[0x74] = 0x02; // 512 bytes supported
+        config[0x78..0x7a].copy_from_slice(&(1u16 << MPS_SHIFT | 2 << MRRS_SHIFT).to_le_bytes());
+        let path = std::env::temp_dir().join(format!("hw_dcmi_config_{}", std::process::id()));
+        std::fs::write(&path, &config).unwrap();
+
+        let settings = read_payload_settings(&path).unwrap();
+        assert_eq!(
+            settings,
+            PCIEPayloadSettings {
+                max_payload_size: 256,
+                max_payload_size_supported: 512,
+                max_read_request_size: 512,
+            }
+        );
+        write_size(&path, SizeField::MaxReadRequest, 4096).unwrap();
+        assert!(write_size(&path, SizeField::MaxReadRequest, 1000).is_err());
+        let settings = read_payload_settings(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(settings.max_read_request_size, 4096);
+        assert_eq!(settings.max_payload_size, 256);
+
+        assert_eq!(
+            find_pcie_capability(&config[..64]).unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn parses_link_attributes() {
+        assert_eq!(parse_link_speed("16.0 GT/s PCIe\n").unwrap(), 16.0);
+        assert_eq!(parse_link_speed("8 GT/s\n").unwrap(), 8.0);
+        assert!(parse_link_speed("Unknown\n").is_err());
+        assert_eq!(parse_link_width("16\n").unwrap(), 16);
+    }
+}
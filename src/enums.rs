@@ -0,0 +1,368 @@
+use crate::hw_dcmi_sys::*;
+use crate::utils::impl_as_str;
+use std::str::FromStr;
+use strum::{Display, EnumIter, EnumString, IntoStaticStr, ParseError};
+
+/// Kind of management unit on a card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum UnitType {
+    /// Neural processing unit
+    NPU,
+    /// Micro controller unit
+    MCU,
+    /// Control CPU
+    CPU,
+}
+
+/// Kind of die inside a chip package
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum DieType {
+    /// IO die (Nimbus)
+    NDie,
+    /// Compute die (Virtuvian)
+    VDie,
+}
+
+impl From<DieType> for dcmi_die_type {
+    fn from(value: DieType) -> Self {
+        match value {
+            DieType::NDie => dcmi_die_type_NDIE,
+            DieType::VDie => dcmi_die_type_VDIE,
+        }
+    }
+}
+
+/// Memory or component type used by ECC and related queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum DeviceType {
+    DDR,
+    SRAM,
+    HBM,
+    NPU,
+    /// Addresses of HBM single-bit errors recorded by the driver
+    HBMRecordedSingleAddr,
+    /// Addresses of HBM multi-bit errors recorded by the driver
+    HBMRecordedMultiAddr,
+    None,
+}
+
+impl From<DeviceType> for dcmi_device_type {
+    fn from(value: DeviceType) -> Self {
+        match value {
+            DeviceType::DDR => dcmi_device_type_DCMI_DEVICE_TYPE_DDR,
+            DeviceType::SRAM => dcmi_device_type_DCMI_DEVICE_TYPE_SRAM,
+            DeviceType::HBM => dcmi_device_type_DCMI_DEVICE_TYPE_HBM,
+            DeviceType::NPU => dcmi_device_type_DCMI_DEVICE_TYPE_NPU,
+            DeviceType::HBMRecordedSingleAddr => dcmi_device_type_DCMI_HBM_RECORDED_SINGLE_ADDR,
+            DeviceType::HBMRecordedMultiAddr => dcmi_device_type_DCMI_HBM_RECORDED_MULTI_ADDR,
+            DeviceType::None => dcmi_device_type_DCMI_DEVICE_TYPE_NONE,
+        }
+    }
+}
+
+/// Health state of a chip, ordered from healthy to most severe
+///
+/// Parsing and iteration only cover the documented states, undocumented values are all
+/// formatted as `unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HealthState {
+    Normal,
+    MinorAlarm,
+    MajorAlarm,
+    CriticalAlarm,
+    /// The device does not exist or has not started
+    NotExist,
+    /// Value not documented by DCMI
+    Unknown(u32),
+}
+
+impl HealthState {
+    /// All documented states
+    pub fn iter() -> impl Iterator<Item = HealthState> {
+        [
+            HealthState::Normal,
+            HealthState::MinorAlarm,
+            HealthState::MajorAlarm,
+            HealthState::CriticalAlarm,
+            HealthState::NotExist,
+        ]
+        .into_iter()
+    }
+}
+
+impl FromStr for HealthState {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HealthState::iter()
+            .find(|state| state.as_str() == s)
+            .ok_or(ParseError::VariantNotFound)
+    }
+}
+
+impl From<u32> for HealthState {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => HealthState::Normal,
+            1 => HealthState::MinorAlarm,
+            2 => HealthState::MajorAlarm,
+            3 => HealthState::CriticalAlarm,
+            0xFFFFFFFF => HealthState::NotExist,
+            value => HealthState::Unknown(value),
+        }
+    }
+}
+
+/// Interconnect between two chips, from the closest to the most distant
+///
+/// Parsing and iteration only cover the documented types, undocumented values are all formatted
+/// as `unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TopologyType {
+    /// Both ids refer to the same chip
+    SameChip,
+    /// SIO link between the dies of one package
+    Sio,
+    /// Direct HCCS link
+    Hccs,
+    /// HCCS through an HCCS switch
+    HccsSwitch,
+    /// PCIe through a single PCIe switch
+    PcieSwitch,
+    /// PCIe through multiple PCIe switches, without crossing a host bridge
+    MultiplePcieSwitches,
+    /// PCIe through a PCIe host bridge
+    HostBridge,
+    /// PCIe across the interconnect between NUMA nodes
+    System,
+    /// Value not documented by DCMI
+    Unknown(i32),
+}
+
+impl TopologyType {
+    /// All documented types
+    pub fn iter() -> impl Iterator<Item = TopologyType> {
+        [
+            TopologyType::SameChip,
+            TopologyType::Sio,
+            TopologyType::Hccs,
+            TopologyType::HccsSwitch,
+            TopologyType::PcieSwitch,
+            TopologyType::MultiplePcieSwitches,
+            TopologyType::HostBridge,
+            TopologyType::System,
+        ]
+        .into_iter()
+    }
+}
+
+impl FromStr for TopologyType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TopologyType::iter()
+            .find(|topology| topology.as_str() == s)
+            .ok_or(ParseError::VariantNotFound)
+    }
+}
+
+impl From<i32> for TopologyType {
+    fn from(value: i32) -> Self {
+        match value as _bindgen_ty_1 {
+            DCMI_TOPO_TYPE_SELF => TopologyType::SameChip,
+            DCMI_TOPO_TYPE_SIO => TopologyType::Sio,
+            DCMI_TOPO_TYPE_HCCS => TopologyType::Hccs,
+            DCMI_TOPO_TYPE_HCCS_SW => TopologyType::HccsSwitch,
+            DCMI_TOPO_TYPE_PIX => TopologyType::PcieSwitch,
+            DCMI_TOPO_TYPE_PXB => TopologyType::MultiplePcieSwitches,
+            DCMI_TOPO_TYPE_PHB => TopologyType::HostBridge,
+            DCMI_TOPO_TYPE_SYS => TopologyType::System,
+            _ => TopologyType::Unknown(value),
+        }
+    }
+}
+
+/// Component whose utilization is queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum UtilizationType {
+    /// DDR memory
+    Memory,
+    AiCore,
+    AiCpu,
+    CtrlCpu,
+    /// DDR memory bandwidth
+    MemoryBandwidth,
+    Hbm,
+    HbmBandwidth,
+    VectorCore,
+    Npu,
+}
+
+impl From<UtilizationType> for i32 {
+    fn from(value: UtilizationType) -> Self {
+        (match value {
+            UtilizationType::Memory => DCMI_UTILIZATION_RATE_DDR,
+            UtilizationType::AiCore => DCMI_UTILIZATION_RATE_AICORE,
+            UtilizationType::AiCpu => DCMI_UTILIZATION_RATE_AICPU,
+            UtilizationType::CtrlCpu => DCMI_UTILIZATION_RATE_CTRLCPU,
+            UtilizationType::MemoryBandwidth => DCMI_UTILIZATION_RATE_DDR_BANDWIDTH,
+            UtilizationType::Hbm => DCMI_UTILIZATION_RATE_HBM,
+            UtilizationType::HbmBandwidth => DCMI_UTILIZATION_RATE_HBM_BANDWIDTH,
+            UtilizationType::VectorCore => DCMI_UTILIZATION_RATE_VECTORCORE,
+            UtilizationType::Npu => DCMI_UTILIZATION_RATE_NPU,
+        }) as i32
+    }
+}
+
+/// Clock whose frequency is queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum FrequencyType {
+    Ddr,
+    CtrlCpu,
+    Hbm,
+    /// Current AI core frequency, lowered when the chip throttles
+    AiCoreCurrent,
+    /// Rated AI core frequency
+    AiCoreMax,
+    VectorCoreCurrent,
+}
+
+impl From<FrequencyType> for dcmi_freq_type {
+    fn from(value: FrequencyType) -> Self {
+        match value {
+            FrequencyType::Ddr => dcmi_freq_type_DCMI_FREQ_DDR,
+            FrequencyType::CtrlCpu => dcmi_freq_type_DCMI_FREQ_CTRLCPU,
+            FrequencyType::Hbm => dcmi_freq_type_DCMI_FREQ_HBM,
+            FrequencyType::AiCoreCurrent => dcmi_freq_type_DCMI_FREQ_AICORE_CURRENT_,
+            FrequencyType::AiCoreMax => dcmi_freq_type_DCMI_FREQ_AICORE_MAX,
+            FrequencyType::VectorCoreCurrent => dcmi_freq_type_DCMI_FREQ_VECTORCORE_CURRENT,
+        }
+    }
+}
+
+/// Voltage rail reported by the power management firmware
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum VoltageRail {
+    AiCore,
+    /// Shared rail of the control CPU and peripherals
+    Hybrid,
+    /// TaiShan (AI CPU / control CPU) cores
+    #[strum(serialize = "taishan")]
+    TaiShan,
+    Ddr,
+}
+
+impl From<VoltageRail> for DCMI_LP_SUB_CMD {
+    fn from(value: VoltageRail) -> Self {
+        match value {
+            VoltageRail::AiCore => DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_AICORE_VOLTAGE_CURRENT,
+            VoltageRail::Hybrid => DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_HYBIRD_VOLTAGE_CURRENT,
+            VoltageRail::TaiShan => DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_TAISHAN_VOLTAGE_CURRENT,
+            VoltageRail::Ddr => DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_DDR_VOLTAGE_CURRENT,
+        }
+    }
+}
+
+/// Management channel through which a chip is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Channel {
+    /// PCIe, through the host driver
+    InBand,
+    /// The management MCU of the card, the path used by the BMC
+    OutOfBand,
+}
+
+impl From<Channel> for dcmi_reset_channel {
+    fn from(value: Channel) -> Self {
+        match value {
+            Channel::InBand => dcmi_reset_channel_INBAND_CHANNEL,
+            Channel::OutOfBand => dcmi_reset_channel_OUTBAND_CHANNEL,
+        }
+    }
+}
+
+/// Network port of a chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum PortType {
+    /// Virtual NIC towards the host
+    Vnic,
+    /// RoCE NIC used for collective communication
+    Roce,
+}
+
+impl From<PortType> for dcmi_port_type {
+    fn from(value: PortType) -> Self {
+        match value {
+            PortType::Vnic => dcmi_port_type_DCMI_VNIC_PORT,
+            PortType::Roce => dcmi_port_type_DCMI_ROCE_PORT,
+        }
+    }
+}
+
+/// Power mode of an idle chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PowerMode {
+    /// The chip stays at full readiness when idle
+    Normal,
+    /// The chip enters its idle power state when no task runs on it, the first task after a
+    /// pause waits for it to wake up
+    LowPower,
+}
+
+impl_as_str!(
+    UnitType,
+    DieType,
+    DeviceType,
+    HealthState,
+    TopologyType,
+    UtilizationType,
+    FrequencyType,
+    VoltageRail,
+    Channel,
+    PortType,
+    PowerMode
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn identifiers_roundtrip() {
+        for utilization_type in UtilizationType::iter() {
+            let id = utilization_type.as_str();
+            assert_eq!(id.parse::<UtilizationType>(), Ok(utilization_type));
+        }
+        assert_eq!(UtilizationType::HbmBandwidth.as_str(), "hbm_bandwidth");
+        assert_eq!(
+            DeviceType::HBMRecordedSingleAddr.to_string(),
+            "hbm_recorded_single_addr"
+        );
+        assert_eq!(VoltageRail::TaiShan.as_str(), "taishan");
+
+        assert_eq!(HealthState::iter().count(), 5);
+        assert_eq!(HealthState::Unknown(7).as_str(), "unknown");
+        assert_eq!("not_exist".parse(), Ok(HealthState::NotExist));
+        assert!("unknown".parse::<HealthState>().is_err());
+    }
+}
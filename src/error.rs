@@ -0,0 +1,538 @@
+use crate::hw_dcmi_sys::*;
+use crate::utils::{string_from_c_chars, take_malformed};
+use std::ffi::c_char;
+use std::fmt;
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Result type of all DCMI calls
+pub type DCMIResult<T> = Result<T, DCMIError>;
+
+/// Errors returned by the DCMI library
+///
+/// Every variant except the last five maps to a `DCMI_ERR_CODE_*` value defined in
+/// `dcmi_interface_api.h`, codes which are not known to this crate are kept in
+/// [`DCMIError::UnknownErrorCode`].
+///
+/// Errors of DCMI calls are wrapped in [`DCMIError::Context`], which names the failing function,
+/// its raw return code and the card and chip it was called for. Match on [`DCMIError::kind`] to
+/// handle an error regardless of its context:
+///
+/// ```
+/// use hw_dcmi::error::{DCMIError, ErrorContext};
+///
+/// let error = DCMIError::NotSupport.with_context(ErrorContext {
+///     function: "dcmi_get_device_temperature",
+///     code: -8255,
+///     card_id: Some(1),
+///     chip_id: Some(0),
+/// });
+/// assert_eq!(error.kind(), &DCMIError::NotSupport);
+/// assert_eq!(
+///     error.to_string(),
+///     "dcmi_get_device_temperature returned -8255 for card 1 chip 0: Not support"
+/// );
+/// ```
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DCMIError {
+    #[error("Invalid parameter")]
+    InvalidParameter,
+    #[error("Operation not permitted")]
+    OperationNotPermitted,
+    #[error("Memory operation failed")]
+    MemoryOperateFail,
+    #[error("Secure function failed")]
+    SecureFunctionFail,
+    #[error("Inner error")]
+    InnerError,
+    #[error("Timeout")]
+    CodeTimeOut,
+    #[error("Invalid device id")]
+    InvalidDeviceId,
+    #[error("Device not exist")]
+    DeviceNotExist,
+    #[error("Ioctl failed")]
+    IoctlFail,
+    #[error("Send message failed")]
+    SendMessageFail,
+    #[error("Receive message failed")]
+    ReceiveMessageFail,
+    #[error("Not ready")]
+    NotReady,
+    #[error("Not support in container")]
+    NotSupportInContainer,
+    #[error("File operation failed")]
+    FileOperateFail,
+    #[error("Reset failed")]
+    ResetFail,
+    #[error("Operation aborted")]
+    AbortOperate,
+    #[error("Device is upgrading")]
+    IsUpgrading,
+    #[error("Resource occupied")]
+    ResourceOccupied,
+    #[error("Partition not right")]
+    PartitionNotRight,
+    #[error("Config info not exist")]
+    ConfigInfoNotExist,
+    #[error("Not support")]
+    NotSupport,
+    #[error("Unknown error, error code: {0}")]
+    UnknownErrorCode(i32),
+    /// libdcmi could not be loaded, only with the `dynamic-loading` feature
+    #[error("Loading libdcmi failed: {0}")]
+    LibraryLoad(String),
+    /// [`DCMIBuilder::library_path`](crate::loader::DCMIBuilder::library_path) names another
+    /// library than the one loaded already, only with the `dynamic-loading` feature
+    #[error("libdcmi is loaded from {}, cannot load {}", loaded.display(), requested.display())]
+    LibraryAlreadyLoaded {
+        loaded: std::path::PathBuf,
+        requested: std::path::PathBuf,
+    },
+    /// A string returned by DCMI is not valid UTF-8, only with [`StringConversion::Strict`]
+    #[error("DCMI returned a string which is not valid UTF-8 for {0}")]
+    MalformedString(&'static str),
+    /// Parameters of a vNPU rejected before calling DCMI, see
+    /// [`VChipRes::validate`](crate::structs::VChipRes::validate)
+    #[error("Invalid vNPU parameters: {0}")]
+    InvalidVChipRes(VChipResError),
+    #[error("{context}: {source}")]
+    Context {
+        context: ErrorContext,
+        source: Box<DCMIError>,
+    },
+}
+
+/// DCMI call an error was returned by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Name of the C function, e.g. `dcmi_get_device_temperature`
+    pub function: &'static str,
+    /// Raw return code of the function
+    pub code: i32,
+    /// Card the function was called for, `None` for calls not bound to a card
+    pub card_id: Option<u32>,
+    /// Chip the function was called for, `None` for calls not bound to a chip
+    pub chip_id: Option<u32>,
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} returned {}", self.function, self.code)?;
+        match (self.card_id, self.chip_id) {
+            (Some(card_id), Some(chip_id)) => write!(f, " for card {card_id} chip {chip_id}"),
+            (Some(card_id), None) => write!(f, " for card {card_id}"),
+            (None, _) => Ok(()),
+        }
+    }
+}
+
+/// Invalid parameters of a vNPU, see [`VChipRes::validate`](crate::structs::VChipRes::validate)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VChipResError {
+    #[error("vNPU id {0} is out of range, ids must be below 65535")]
+    InvalidVChipId(u32),
+    #[error("virtual function group id {0} is out of range, ids must be below 32")]
+    InvalidVfgId(u32),
+    #[error("template name is empty")]
+    EmptyTemplateName,
+    #[error("template name is {0} bytes long, at most 31 bytes are allowed")]
+    TemplateNameTooLong(usize),
+    #[error("template name contains a NUL byte")]
+    TemplateNameContainsNul,
+}
+
+impl From<VChipResError> for DCMIError {
+    fn from(error: VChipResError) -> Self {
+        DCMIError::InvalidVChipRes(error)
+    }
+}
+
+impl DCMIError {
+    /// Convert a return code of a DCMI function into a result
+    pub fn check(code: i32) -> DCMIResult<()> {
+        match code {
+            0 => Ok(()),
+            code => Err(DCMIError::from(code)),
+        }
+    }
+
+    /// Convert the return code of a DCMI function into a result, errors carry the call context
+    pub(crate) fn check_call(
+        code: i32,
+        function: &'static str,
+        card_id: Option<u32>,
+        chip_id: Option<u32>,
+    ) -> DCMIResult<()> {
+        DCMIError::check(code).map_err(|e| {
+            e.with_context(ErrorContext {
+                function,
+                code,
+                card_id,
+                chip_id,
+            })
+        })
+    }
+
+    /// Wrap the error with the context of the call which returned it
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        DCMIError::Context {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    /// The error without its context
+    pub fn kind(&self) -> &DCMIError {
+        match self {
+            DCMIError::Context { source, .. } => source.kind(),
+            kind => kind,
+        }
+    }
+
+    /// Context of the DCMI call which returned the error, `None` for errors raised by this crate
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            DCMIError::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Whether the error means the queried component or function does not exist on the chip
+    /// (`NotSupport` or `DeviceNotExist`) rather than that the query failed
+    pub fn is_unsupported(&self) -> bool {
+        matches!(
+            self.kind(),
+            DCMIError::NotSupport | DCMIError::DeviceNotExist
+        )
+    }
+
+    /// Whether the call may succeed when repeated later (`NotReady`, `CodeTimeOut` or
+    /// `IsUpgrading`), e.g. while the device boots, see [`RetryPolicy`](crate::retry::RetryPolicy)
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self.kind(),
+            DCMIError::NotReady | DCMIError::CodeTimeOut | DCMIError::IsUpgrading
+        )
+    }
+
+    /// Stable, low-cardinality identifier of the error kind
+    ///
+    /// Intended for metric labels, unknown error codes all share the `unknown` label so that raw
+    /// numeric codes never end up in label values.
+    pub fn metric_label(&self) -> &'static str {
+        match self {
+            DCMIError::InvalidParameter => "invalid_parameter",
+            DCMIError::OperationNotPermitted => "operation_not_permitted",
+            DCMIError::MemoryOperateFail => "memory_operate_fail",
+            DCMIError::SecureFunctionFail => "secure_function_fail",
+            DCMIError::InnerError => "inner_error",
+            DCMIError::CodeTimeOut => "timeout",
+            DCMIError::InvalidDeviceId => "invalid_device_id",
+            DCMIError::DeviceNotExist => "device_not_exist",
+            DCMIError::IoctlFail => "ioctl_fail",
+            DCMIError::SendMessageFail => "send_message_fail",
+            DCMIError::ReceiveMessageFail => "receive_message_fail",
+            DCMIError::NotReady => "not_ready",
+            DCMIError::NotSupportInContainer => "not_support_in_container",
+            DCMIError::FileOperateFail => "file_operate_fail",
+            DCMIError::ResetFail => "reset_fail",
+            DCMIError::AbortOperate => "abort_operate",
+            DCMIError::IsUpgrading => "is_upgrading",
+            DCMIError::ResourceOccupied => "resource_occupied",
+            DCMIError::PartitionNotRight => "partition_not_right",
+            DCMIError::ConfigInfoNotExist => "config_info_not_exist",
+            DCMIError::NotSupport => "not_support",
+            DCMIError::UnknownErrorCode(_) => "unknown",
+            DCMIError::LibraryLoad(_) => "library_load",
+            DCMIError::LibraryAlreadyLoaded { .. } => "library_already_loaded",
+            DCMIError::MalformedString(_) => "malformed_string",
+            DCMIError::InvalidVChipRes(_) => "invalid_vchip_res",
+            DCMIError::Context { source, .. } => source.metric_label(),
+        }
+    }
+}
+
+impl From<i32> for DCMIError {
+    fn from(code: i32) -> Self {
+        match code {
+            DCMI_ERR_CODE_INVALID_PARAMETER => DCMIError::InvalidParameter,
+            DCMI_ERR_CODE_OPER_NOT_PERMITTED => DCMIError::OperationNotPermitted,
+            DCMI_ERR_CODE_MEM_OPERATE_FAIL => DCMIError::MemoryOperateFail,
+            DCMI_ERR_CODE_SECURE_FUN_FAIL => DCMIError::SecureFunctionFail,
+            DCMI_ERR_CODE_INNER_ERR => DCMIError::InnerError,
+            DCMI_ERR_CODE_TIME_OUT => DCMIError::CodeTimeOut,
+            DCMI_ERR_CODE_INVALID_DEVICE_ID => DCMIError::InvalidDeviceId,
+            DCMI_ERR_CODE_DEVICE_NOT_EXIST => DCMIError::DeviceNotExist,
+            DCMI_ERR_CODE_IOCTL_FAIL => DCMIError::IoctlFail,
+            DCMI_ERR_CODE_SEND_MSG_FAIL => DCMIError::SendMessageFail,
+            DCMI_ERR_CODE_RECV_MSG_FAIL => DCMIError::ReceiveMessageFail,
+            DCMI_ERR_CODE_NOT_REDAY => DCMIError::NotReady,
+            DCMI_ERR_CODE_NOT_SUPPORT_IN_CONTAINER => DCMIError::NotSupportInContainer,
+            DCMI_ERR_CODE_FILE_OPERATE_FAIL => DCMIError::FileOperateFail,
+            DCMI_ERR_CODE_RESET_FAIL => DCMIError::ResetFail,
+            DCMI_ERR_CODE_ABORT_OPERATE => DCMIError::AbortOperate,
+            DCMI_ERR_CODE_IS_UPGRADING => DCMIError::IsUpgrading,
+            DCMI_ERR_CODE_RESOURCE_OCCUPIED => DCMIError::ResourceOccupied,
+            DCMI_ERR_CODE_PARTITION_NOT_RIGHT => DCMIError::PartitionNotRight,
+            DCMI_ERR_CODE_CONFIG_INFO_NOT_EXIST => DCMIError::ConfigInfoNotExist,
+            DCMI_ERR_CODE_NOT_SUPPORT => DCMIError::NotSupport,
+            code => DCMIError::UnknownErrorCode(code),
+        }
+    }
+}
+
+/// Call a function of `hw_dcmi_sys` and convert its return code into a [`DCMIResult<()>`]
+///
+/// Transient errors of read-only queries are retried according to the process-wide
+/// [`RetryPolicy`](crate::retry::RetryPolicy), see [`retry::policy_for`](crate::retry::policy_for).
+///
+/// Errors carry the name of the function and its return code. Calls which pass the ids the
+/// usual way, `self.card_id as i32, self.id as i32` first in chip methods or `self.id as i32`
+/// first in card methods, also carry the card and chip id.
+macro_rules! call_dcmi_function {
+    ($func:ident, $chip:ident.card_id as i32, $chip2:ident.id as i32 $(, $arg:expr)* $(,)?) => {
+        $crate::retry::policy_for(stringify!($func)).run(|| $crate::error::DCMIError::check_call(
+            $crate::error::dcmi_sys_call!($func, $chip.card_id as i32, $chip2.id as i32 $(, $arg)*),
+            stringify!($func),
+            Some($chip.card_id),
+            Some($chip2.id),
+        ))
+    };
+    ($func:ident, $chip:ident.card_id() as i32, $chip2:ident.id() as i32 $(, $arg:expr)* $(,)?) => {
+        $crate::retry::policy_for(stringify!($func)).run(|| $crate::error::DCMIError::check_call(
+            $crate::error::dcmi_sys_call!($func, $chip.card_id() as i32, $chip2.id() as i32 $(, $arg)*),
+            stringify!($func),
+            Some($chip.card_id()),
+            Some($chip2.id()),
+        ))
+    };
+    ($func:ident, $card:ident.id as i32 $(, $arg:expr)* $(,)?) => {
+        $crate::retry::policy_for(stringify!($func)).run(|| $crate::error::DCMIError::check_call(
+            $crate::error::dcmi_sys_call!($func, $card.id as i32 $(, $arg)*),
+            stringify!($func),
+            Some($card.id),
+            None,
+        ))
+    };
+    ($func:ident $(, $arg:expr)* $(,)?) => {
+        $crate::retry::policy_for(stringify!($func)).run(|| $crate::error::DCMIError::check_call(
+            $crate::error::dcmi_sys_call!($func $(, $arg)*),
+            stringify!($func),
+            None,
+            None,
+        ))
+    };
+}
+
+pub(crate) use call_dcmi_function;
+
+/// Call a function of `hw_dcmi_sys`, returning its raw return code
+#[cfg(not(feature = "dynamic-loading"))]
+macro_rules! dcmi_sys_call {
+    ($func:ident $(, $arg:expr)*) => {
+        unsafe { $crate::hw_dcmi_sys::$func($($arg),*) }
+    };
+}
+
+/// Call a function of the loaded library, returning its raw return code
+///
+/// Functions missing from the library return `DCMI_ERR_CODE_NOT_SUPPORT`, calls before the library
+/// is loaded `DCMI_ERR_CODE_NOT_REDAY`.
+#[cfg(feature = "dynamic-loading")]
+macro_rules! dcmi_sys_call {
+    ($func:ident $(, $arg:expr)*) => {
+        match $crate::loader::library() {
+            Some(library) => match &library.$func {
+                Ok(function) => unsafe { function($($arg),*) },
+                Err(_) => $crate::hw_dcmi_sys::DCMI_ERR_CODE_NOT_SUPPORT,
+            },
+            None => $crate::hw_dcmi_sys::DCMI_ERR_CODE_NOT_REDAY,
+        }
+    };
+}
+
+pub(crate) use dcmi_sys_call;
+
+/// How strings returned by DCMI which are not valid UTF-8 are converted, e.g. electronic label
+/// fields of boards with garbage in their EEPROM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StringConversion {
+    /// Replace invalid sequences with `U+FFFD`
+    #[default]
+    Lossy,
+    /// Fail the query with [`DCMIError::MalformedString`]
+    Strict,
+}
+
+/// Conversion of strings set by [`DCMI::set_string_conversion`](crate::DCMI::set_string_conversion)
+static STRING_CONVERSION: Mutex<StringConversion> = Mutex::new(StringConversion::Lossy);
+
+pub(crate) fn set_string_conversion(conversion: StringConversion) {
+    *STRING_CONVERSION.lock().unwrap_or_else(|e| e.into_inner()) = conversion;
+}
+
+pub(crate) fn string_conversion() -> StringConversion {
+    *STRING_CONVERSION.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Fail with [`DCMIError::MalformedString`] if strings converted since the last check were not
+/// valid UTF-8 and `conversion` is strict
+fn check_strings(target: &'static str, conversion: StringConversion) -> DCMIResult<()> {
+    if take_malformed() && conversion == StringConversion::Strict {
+        return Err(DCMIError::MalformedString(target));
+    }
+    Ok(())
+}
+
+/// Convert a NUL-terminated string returned by DCMI, see [`StringConversion`]
+pub(crate) fn convert_string(chars: &[c_char]) -> DCMIResult<String> {
+    convert_string_with(chars, string_conversion())
+}
+
+/// [`convert_string`] with `conversion` instead of the conversion set for the process
+fn convert_string_with(chars: &[c_char], conversion: StringConversion) -> DCMIResult<String> {
+    take_malformed();
+    let string = string_from_c_chars(chars);
+    check_strings("String", conversion)?;
+    Ok(string)
+}
+
+/// Convert a value returned by DCMI into its safe representation
+///
+/// Strings which are not valid UTF-8 are handled as set by [`StringConversion`]. With the
+/// `defensive` feature a panic during the conversion, e.g. caused by malformed firmware data, is
+/// caught and reported as [`DCMIError::InnerError`], its message is kept for
+/// [`last_conversion_failure`]. Without the feature the panic propagates.
+pub(crate) fn convert<T, U: From<T>>(raw: T) -> DCMIResult<U> {
+    take_malformed();
+    let converted = convert_unchecked(raw)?;
+    check_strings(std::any::type_name::<U>(), string_conversion())?;
+    Ok(converted)
+}
+
+fn convert_unchecked<T, U: From<T>>(raw: T) -> DCMIResult<U> {
+    #[cfg(feature = "defensive")]
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| U::from(raw))).map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            *LAST_CONVERSION_FAILURE
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = Some(ConversionFailure {
+                source: std::any::type_name::<T>(),
+                target: std::any::type_name::<U>(),
+                message,
+            });
+            DCMIError::InnerError
+        })
+    }
+    #[cfg(not(feature = "defensive"))]
+    Ok(U::from(raw))
+}
+
+/// Conversion which panicked and was turned into [`DCMIError::InnerError`]
+#[cfg(feature = "defensive")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionFailure {
+    /// Type of the raw value returned by DCMI
+    pub source: &'static str,
+    /// Type the value was converted into
+    pub target: &'static str,
+    /// Panic message
+    pub message: String,
+}
+
+#[cfg(feature = "defensive")]
+static LAST_CONVERSION_FAILURE: std::sync::Mutex<Option<ConversionFailure>> =
+    std::sync::Mutex::new(None);
+
+/// Most recent conversion of this process which panicked, for diagnostics
+#[cfg(feature = "defensive")]
+pub fn last_conversion_failure() -> Option<ConversionFailure> {
+    LAST_CONVERSION_FAILURE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_maps_error_codes() {
+        assert_eq!(DCMIError::check(0), Ok(()));
+        assert_eq!(DCMIError::check(-8005), Err(DCMIError::InnerError));
+        assert_eq!(DCMIError::check(-8255), Err(DCMIError::NotSupport));
+        assert_eq!(DCMIError::check(-1), Err(DCMIError::UnknownErrorCode(-1)));
+    }
+
+    #[cfg(feature = "defensive")]
+    #[test]
+    fn convert_catches_panics() {
+        struct Malformed;
+        #[derive(Debug)]
+        struct Converted;
+        impl From<Malformed> for Converted {
+            fn from(_: Malformed) -> Self {
+                panic!("malformed firmware string")
+            }
+        }
+        assert_eq!(convert::<u8, u32>(7), Ok(7));
+        assert!(matches!(
+            convert::<_, Converted>(Malformed),
+            Err(DCMIError::InnerError)
+        ));
+        let failure = last_conversion_failure().unwrap();
+        assert_eq!(failure.message, "malformed firmware string");
+        assert!(failure.target.ends_with("Converted"));
+    }
+
+    #[test]
+    fn strict_conversion_rejects_malformed_strings() {
+        let garbage = [b'S' as c_char, 0xff_u8 as c_char, 0];
+        assert_eq!(
+            convert_string_with(&garbage, StringConversion::Lossy).unwrap(),
+            "S\u{fffd}"
+        );
+        assert_eq!(
+            convert_string_with(&garbage, StringConversion::Strict),
+            Err(DCMIError::MalformedString("String"))
+        );
+        let valid = [b'S' as c_char, 0];
+        assert_eq!(
+            convert_string_with(&valid, StringConversion::Strict).unwrap(),
+            "S"
+        );
+    }
+
+    #[test]
+    fn context_names_the_failed_call() {
+        let error = DCMIError::check_call(-8005, "dcmi_get_device_temperature", Some(1), Some(0))
+            .unwrap_err();
+        assert_eq!(error.kind(), &DCMIError::InnerError);
+        assert_eq!(error.metric_label(), "inner_error");
+        let context = error.context().unwrap();
+        assert_eq!(context.function, "dcmi_get_device_temperature");
+        assert_eq!(context.code, -8005);
+        assert_eq!(
+            error.to_string(),
+            "dcmi_get_device_temperature returned -8005 for card 1 chip 0: Inner error"
+        );
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(DCMIError::check_call(0, "dcmi_init", None, None), Ok(()));
+        assert_eq!(DCMIError::NotSupport.kind(), &DCMIError::NotSupport);
+        assert_eq!(DCMIError::NotSupport.context(), None);
+    }
+
+    #[test]
+    fn metric_label_hides_raw_codes() {
+        assert_eq!(DCMIError::NotSupport.metric_label(), "not_support");
+        assert_eq!(DCMIError::UnknownErrorCode(-9000).metric_label(), "unknown");
+        assert_eq!(DCMIError::UnknownErrorCode(-1).metric_label(), "unknown");
+    }
+}
@@ -0,0 +1,635 @@
+use crate::error::{call_dcmi_function, convert, DCMIError, DCMIResult};
+use crate::hw_dcmi_sys::*;
+use crate::monitor::{Alert, AlertState};
+use crate::utils::{impl_as_str, string_from_c_chars};
+use crate::DCMI;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Severity of a fault event, ordered from the least to the most severe
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Severity {
+    Notice,
+    Minor,
+    Major,
+    Critical,
+}
+
+impl From<u8> for Severity {
+    /// Values above 3 are treated as critical
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Severity::Notice,
+            1 => Severity::Minor,
+            2 => Severity::Major,
+            _ => Severity::Critical,
+        }
+    }
+}
+
+impl From<Severity> for u8 {
+    fn from(value: Severity) -> Self {
+        value as u8
+    }
+}
+
+/// Whether a fault event reports a fault occurring or recovering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Assertion {
+    /// The fault has recovered
+    Recovery,
+    /// The fault occurred
+    Occur,
+    /// One-off notification without a recovery counterpart
+    Notice,
+}
+
+impl From<u8> for Assertion {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Assertion::Recovery,
+            1 => Assertion::Occur,
+            _ => Assertion::Notice,
+        }
+    }
+}
+
+/// Fault event reported by the device management service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaultEvent {
+    pub event_id: u32,
+    /// Id of the device that raised the event
+    pub device_id: u16,
+    pub node_type: u8,
+    pub node_id: u8,
+    pub sub_node_type: u8,
+    pub sub_node_id: u8,
+    pub severity: Severity,
+    pub assertion: Assertion,
+    pub event_serial_num: i32,
+    pub notify_serial_num: i32,
+    /// Time at which the alarm was raised, as reported by the driver
+    pub alarm_raised_time: u64,
+    pub event_name: String,
+    pub additional_info: String,
+    pub os_id: u8,
+}
+
+impl From<dcmi_dms_fault_event> for FaultEvent {
+    fn from(event: dcmi_dms_fault_event) -> Self {
+        FaultEvent {
+            event_id: event.event_id,
+            device_id: event.deviceid,
+            node_type: event.node_type,
+            node_id: event.node_id,
+            sub_node_type: event.sub_node_type,
+            sub_node_id: event.sub_node_id,
+            severity: event.severity.into(),
+            assertion: event.assertion.into(),
+            event_serial_num: event.event_serial_num,
+            notify_serial_num: event.notify_serial_num,
+            alarm_raised_time: event.alarm_raised_time,
+            event_name: string_from_c_chars(&event.event_name),
+            additional_info: string_from_c_chars(&event.additional_info),
+            os_id: event.os_id,
+        }
+    }
+}
+
+/// Event id of the fault events published for the alerts of a
+/// [`HealthMonitor`](crate::monitor::HealthMonitor)
+pub const ALERT_EVENT_ID: u32 = 0;
+
+impl FaultEvent {
+    /// Event standing for a monitor alert on the chip with logic id `device_id`
+    ///
+    /// The rule name is the event name, the metric and its value are the additional info. A
+    /// resolved alert is a recovery, alerts are minor faults.
+    pub(crate) fn from_alert(alert: &Alert, device_id: u16) -> Self {
+        FaultEvent {
+            event_id: ALERT_EVENT_ID,
+            device_id,
+            node_type: 0,
+            node_id: 0,
+            sub_node_type: 0,
+            sub_node_id: 0,
+            severity: Severity::Minor,
+            assertion: match alert.state {
+                AlertState::Firing => Assertion::Occur,
+                AlertState::Resolved => Assertion::Recovery,
+            },
+            event_serial_num: 0,
+            notify_serial_num: 0,
+            alarm_raised_time: 0,
+            event_name: alert.rule.clone(),
+            additional_info: format!("{}={}", alert.metric, alert.value),
+            os_id: 0,
+        }
+    }
+
+    /// Whether the event was published for a monitor alert instead of raised by the driver
+    pub fn is_alert(&self) -> bool {
+        self.event_id == ALERT_EVENT_ID
+    }
+}
+
+/// Fault currently active on a chip, see [`Chip::get_errors`]
+///
+/// [`Chip::get_errors`]: crate::device::Chip::get_errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorRecord {
+    /// Error code, the same as the id of the fault events of the fault
+    pub code: u32,
+    /// Description reported by the driver, `None` if it has none for the code
+    pub description: Option<String>,
+    /// Severity of the last fault event with this id the process has seen, `None` if it has not
+    /// seen one
+    pub severity: Option<Severity>,
+}
+
+impl fmt::Display for ErrorRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.code)?;
+        if let Some(severity) = self.severity {
+            write!(f, " ({severity})")?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, ": {description}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Severity of the last fault event seen per event id
+static SEVERITIES: Mutex<BTreeMap<u32, Severity>> = Mutex::new(BTreeMap::new());
+
+fn record_severity(event: &FaultEvent) {
+    SEVERITIES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(event.event_id, event.severity);
+}
+
+/// Severity of the fault events with id `event_id` seen so far
+pub(crate) fn known_severity(event_id: u32) -> Option<Severity> {
+    SEVERITIES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&event_id)
+        .copied()
+}
+
+/// Filter applied to fault events
+///
+/// All conditions are optional, events must match every condition which is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    pub event_id: Option<u32>,
+    /// Only report events at least this severe
+    pub min_severity: Option<Severity>,
+    pub node_type: Option<u8>,
+    /// Only report events of the chip `(card_id, chip_id)`
+    pub device: Option<(u32, u32)>,
+}
+
+impl EventFilter {
+    /// Filter which accepts every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn event_id(mut self, event_id: u32) -> Self {
+        self.event_id = Some(event_id);
+        self
+    }
+
+    pub fn min_severity(mut self, severity: Severity) -> Self {
+        self.min_severity = Some(severity);
+        self
+    }
+
+    pub fn node_type(mut self, node_type: u8) -> Self {
+        self.node_type = Some(node_type);
+        self
+    }
+
+    pub fn device(mut self, card_id: u32, chip_id: u32) -> Self {
+        self.device = Some((card_id, chip_id));
+        self
+    }
+
+    /// Whether the event passes the conditions which can be checked on the event itself
+    pub fn matches(&self, event: &FaultEvent) -> bool {
+        self.event_id.is_none_or(|id| id == event.event_id)
+            && self.min_severity.is_none_or(|s| event.severity >= s)
+            && self.node_type.is_none_or(|t| t == event.node_type)
+    }
+
+    fn to_raw(self) -> dcmi_event_filter {
+        let mut filter = dcmi_event_filter {
+            filter_flag: 0,
+            event_id: 0,
+            severity: 0,
+            node_type: 0,
+            resv: [0; 32],
+        };
+        if let Some(event_id) = self.event_id {
+            filter.filter_flag |= DCMI_EVENT_FILTER_FLAG_EVENT_ID as u64;
+            filter.event_id = event_id;
+        }
+        if let Some(severity) = self.min_severity {
+            filter.filter_flag |= DCMI_EVENT_FILTER_FLAG_SERVERITY as u64;
+            filter.severity = severity.into();
+        }
+        if let Some(node_type) = self.node_type {
+            filter.filter_flag |= DCMI_EVENT_FILTER_FLAG_NODE_TYPE as u64;
+            filter.node_type = node_type;
+        }
+        filter
+    }
+}
+
+/// Blocking iterator over fault events
+///
+/// Each call to `next` waits at most `timeout` for an event, a wait that runs out yields an error
+/// of kind [`DCMIError::CodeTimeOut`] so the caller can decide whether to keep waiting. The
+/// iterator never ends on its own.
+///
+/// Streams created by [`DCMI::fault_events`] poll the driver with `dcmi_get_fault_event`,
+/// streams created by [`DCMI::subscribe_fault_events`] are fed by the driver's event
+/// subscription and receive events as soon as they are raised. Subscribed streams also receive
+/// the alerts of every [`HealthMonitor`](crate::monitor::HealthMonitor) of the process, see
+/// [`FaultEvent::is_alert`].
+#[derive(Debug)]
+pub struct EventStream<'a> {
+    _dcmi: &'a DCMI,
+    filter: EventFilter,
+    timeout: Duration,
+    source: EventSource,
+}
+
+#[derive(Debug)]
+enum EventSource {
+    Polling,
+    Subscribed {
+        events: Receiver<FaultEvent>,
+        /// Logic id of the chip selected by the filter, events carry logic ids
+        logic_id: Option<u32>,
+    },
+}
+
+/// End of the wait of one call to `next`, `None` if the timeout reaches beyond what `Instant`
+/// represents
+#[derive(Debug, Clone, Copy)]
+struct Deadline(Option<Instant>);
+
+impl Deadline {
+    fn after(timeout: Duration) -> Self {
+        Deadline(Instant::now().checked_add(timeout))
+    }
+
+    /// Time left, zero once the deadline has passed
+    fn remaining(self) -> Duration {
+        self.0.map_or(Duration::MAX, |deadline| {
+            deadline.saturating_duration_since(Instant::now())
+        })
+    }
+}
+
+/// Senders of all subscribed event streams, disconnected ones are dropped on the next event
+static SUBSCRIBERS: Mutex<Vec<Sender<FaultEvent>>> = Mutex::new(Vec::new());
+/// Whether the process-wide driver subscription has been registered
+static SUBSCRIBED: Mutex<bool> = Mutex::new(false);
+
+unsafe extern "C" fn dispatch_fault_event(event: *mut dcmi_event) {
+    let Some(event) = (unsafe { event.as_ref() }) else {
+        return;
+    };
+    if event.type_ != dcmi_event_type_DCMI_DMS_FAULT_EVENT {
+        return;
+    }
+    // with the `defensive` feature a malformed event is dropped instead of aborting the process
+    let Ok(event) = convert::<_, FaultEvent>(unsafe { event.event_t.dms_event }) else {
+        return;
+    };
+    record_severity(&event);
+    publish(event);
+}
+
+/// Pass an event to every subscribed stream
+pub(crate) fn publish(event: FaultEvent) {
+    SUBSCRIBERS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|sender| sender.send(event.clone()).is_ok());
+}
+
+/// Logic id of a chip, fault events identify chips by it
+pub(crate) fn logic_id(card_id: u32, chip_id: u32) -> DCMIResult<u32> {
+    let mut logic_id = 0;
+    call_dcmi_function!(
+        dcmi_get_device_logic_id,
+        &mut logic_id,
+        card_id as i32,
+        chip_id as i32
+    )?;
+    Ok(logic_id as u32)
+}
+
+/// Register the process-wide subscription to the fault events of all devices
+///
+/// DCMI has no way to unsubscribe and the callback carries no context, so a single
+/// subscription is shared by every stream and events are dispatched to them by
+/// [`dispatch_fault_event`].
+fn ensure_subscribed() -> DCMIResult<()> {
+    let mut subscribed = SUBSCRIBED.lock().unwrap_or_else(|e| e.into_inner());
+    if !*subscribed {
+        call_dcmi_function!(
+            dcmi_subscribe_fault_event,
+            -1,
+            -1,
+            EventFilter::new().to_raw(),
+            Some(dispatch_fault_event)
+        )?;
+        *subscribed = true;
+    }
+    Ok(())
+}
+
+impl<'a> EventStream<'a> {
+    pub(crate) fn new(dcmi: &'a DCMI, filter: EventFilter, timeout: Duration) -> Self {
+        EventStream {
+            _dcmi: dcmi,
+            filter,
+            timeout,
+            source: EventSource::Polling,
+        }
+    }
+
+    pub(crate) fn subscribed(
+        dcmi: &'a DCMI,
+        filter: EventFilter,
+        timeout: Duration,
+    ) -> DCMIResult<Self> {
+        let logic_id = match filter.device {
+            Some((card_id, chip_id)) => Some(logic_id(card_id, chip_id)?),
+            None => None,
+        };
+        ensure_subscribed()?;
+        let (sender, events) = mpsc::channel();
+        SUBSCRIBERS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(sender);
+        Ok(EventStream {
+            _dcmi: dcmi,
+            filter,
+            timeout,
+            source: EventSource::Subscribed { events, logic_id },
+        })
+    }
+
+    /// Whether events are pushed by the driver instead of being polled
+    pub fn is_event_driven(&self) -> bool {
+        matches!(self.source, EventSource::Subscribed { .. })
+    }
+
+    /// Wait at most `timeout` for the next event matching the filter
+    pub fn next_event(&mut self) -> DCMIResult<FaultEvent> {
+        match &self.source {
+            EventSource::Polling => self.poll_event(),
+            EventSource::Subscribed { events, logic_id } => {
+                let deadline = Deadline::after(self.timeout);
+                loop {
+                    let event = events
+                        .recv_timeout(deadline.remaining())
+                        .map_err(|e| match e {
+                            RecvTimeoutError::Timeout => DCMIError::CodeTimeOut,
+                            RecvTimeoutError::Disconnected => DCMIError::InnerError,
+                        })?;
+                    if self.filter.matches(&event)
+                        && logic_id.is_none_or(|id| id == event.device_id as u32)
+                    {
+                        return Ok(event);
+                    }
+                }
+            }
+        }
+    }
+
+    fn poll_event(&self) -> DCMIResult<FaultEvent> {
+        let (card_id, device_id) = self.filter.device.map_or((-1, -1), |(card_id, chip_id)| {
+            (card_id as i32, chip_id as i32)
+        });
+        // events the filter rejects must not restart the wait
+        let deadline = Deadline::after(self.timeout);
+        loop {
+            let timeout = deadline.remaining().as_millis().min(i32::MAX as u128) as i32;
+            // SAFETY: dcmi_event is a plain C struct, all-zero is a valid value
+            let mut event: dcmi_event = unsafe { std::mem::zeroed() };
+            call_dcmi_function!(
+                dcmi_get_fault_event,
+                card_id,
+                device_id,
+                timeout,
+                self.filter.to_raw(),
+                &mut event
+            )?;
+            if event.type_ == dcmi_event_type_DCMI_DMS_FAULT_EVENT {
+                let event: FaultEvent = convert(unsafe { event.event_t.dms_event })?;
+                record_severity(&event);
+                if self.filter.matches(&event) {
+                    return Ok(event);
+                }
+            }
+            if deadline.remaining().is_zero() {
+                return Err(DCMIError::CodeTimeOut);
+            }
+        }
+    }
+}
+
+impl Iterator for EventStream<'_> {
+    type Item = DCMIResult<FaultEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_event())
+    }
+}
+
+impl DCMI {
+    /// Blocking iterator over the fault events of all devices (or of the device selected in the
+    /// filter)
+    ///
+    /// # Parameters
+    /// - filter: conditions the reported events must match
+    /// - timeout: maximum time a single call to `next` waits for an event
+    pub fn fault_events(&self, filter: EventFilter, timeout: Duration) -> EventStream<'_> {
+        EventStream::new(self, filter, timeout)
+    }
+
+    /// Event-driven variant of [`DCMI::fault_events`]
+    ///
+    /// Events are pushed by the driver's fault event subscription, which brings fault detection
+    /// latency down from the polling interval to milliseconds. Falls back to a polling stream if
+    /// the driver does not support subscriptions.
+    ///
+    /// # Parameters
+    /// - filter: conditions the reported events must match
+    /// - timeout: maximum time a single call to `next` waits for an event
+    pub fn subscribe_fault_events(
+        &self,
+        filter: EventFilter,
+        timeout: Duration,
+    ) -> DCMIResult<EventStream<'_>> {
+        match EventStream::subscribed(self, filter, timeout) {
+            Err(e) if e.kind() == &DCMIError::NotSupport => {
+                Ok(EventStream::new(self, filter, timeout))
+            }
+            result => result,
+        }
+    }
+}
+
+impl_as_str!(Severity, Assertion);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_checks_min_severity() {
+        let mut raw: dcmi_dms_fault_event = unsafe { std::mem::zeroed() };
+        raw.event_id = 0x80e01801;
+        raw.severity = 2;
+        raw.assertion = 1;
+        let event = FaultEvent::from(raw);
+        assert_eq!(event.severity, Severity::Major);
+        assert_eq!(event.assertion, Assertion::Occur);
+
+        assert!(EventFilter::new().matches(&event));
+        assert!(EventFilter::new()
+            .min_severity(Severity::Minor)
+            .matches(&event));
+        assert!(!EventFilter::new()
+            .min_severity(Severity::Critical)
+            .matches(&event));
+        assert!(!EventFilter::new().event_id(1).matches(&event));
+    }
+
+    #[test]
+    fn alerts_are_published_to_subscribers() {
+        let (sender, events) = mpsc::channel();
+        SUBSCRIBERS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(sender);
+        let alert = Alert {
+            rule: "chip_overheat".to_string(),
+            metric: crate::monitor::Metric::Temperature,
+            card_id: 0,
+            chip_id: 0,
+            value: 92.0,
+            state: AlertState::Resolved,
+            at: Instant::now(),
+        };
+        publish(FaultEvent::from_alert(&alert, 3));
+        let event = events
+            .iter()
+            .find(|event| event.event_name == "chip_overheat")
+            .unwrap();
+        assert!(event.is_alert());
+        assert_eq!(event.device_id, 3);
+        assert_eq!(event.assertion, Assertion::Recovery);
+        assert_eq!(event.additional_info, "temperature=92");
+    }
+
+    #[test]
+    fn deadline_runs_out() {
+        assert!(Deadline::after(Duration::ZERO).remaining().is_zero());
+        assert!(Deadline::after(Duration::from_secs(60)).remaining() > Duration::from_secs(59));
+        assert_eq!(Deadline::after(Duration::MAX).remaining(), Duration::MAX);
+    }
+
+    #[test]
+    fn rejected_events_do_not_extend_the_wait() {
+        let dcmi = DCMI { _private: () };
+        let (sender, events) = mpsc::channel();
+        let mut stream = EventStream {
+            _dcmi: &dcmi,
+            filter: EventFilter::new().event_id(1),
+            timeout: Duration::from_millis(50),
+            source: EventSource::Subscribed {
+                events,
+                logic_id: None,
+            },
+        };
+        let mut raw: dcmi_dms_fault_event = unsafe { std::mem::zeroed() };
+        raw.event_id = 2;
+        let rejected = FaultEvent::from(raw);
+        let feeder = std::thread::spawn(move || {
+            while sender.send(rejected.clone()).is_ok() {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        });
+        let start = Instant::now();
+        assert_eq!(stream.next_event(), Err(DCMIError::CodeTimeOut));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        drop(stream);
+        feeder.join().unwrap();
+    }
+
+    #[test]
+    fn filter_sets_raw_flags() {
+        let raw = EventFilter::new()
+            .min_severity(Severity::Major)
+            .node_type(3)
+            .to_raw();
+        assert_eq!(
+            raw.filter_flag,
+            (DCMI_EVENT_FILTER_FLAG_SERVERITY | DCMI_EVENT_FILTER_FLAG_NODE_TYPE) as u64
+        );
+        assert_eq!(raw.severity, 2);
+        assert_eq!(raw.node_type, 3);
+    }
+
+    #[test]
+    fn dispatch_reaches_subscribers() {
+        let (sender, events) = mpsc::channel();
+        SUBSCRIBERS.lock().unwrap().push(sender);
+        let mut raw: dcmi_event = unsafe { std::mem::zeroed() };
+        raw.type_ = dcmi_event_type_DCMI_DMS_FAULT_EVENT;
+        raw.event_t.dms_event.event_id = 42;
+        unsafe { dispatch_fault_event(&mut raw) };
+        unsafe { dispatch_fault_event(std::ptr::null_mut()) };
+        assert_eq!(events.try_recv().unwrap().event_id, 42);
+        assert!(events.try_recv().is_err());
+        assert_eq!(known_severity(42), Some(Severity::Notice));
+
+        let record = ErrorRecord {
+            code: 42,
+            description: Some("HBM ECC error".to_string()),
+            severity: known_severity(42),
+        };
+        assert_eq!(record.to_string(), "0x0000002a (notice): HBM ECC error");
+    }
+}
@@ -0,0 +1,304 @@
+//! Health report of the NPUs of a node
+//!
+//! The health state reported by the driver only covers faults. A chip can be healthy by that
+//! measure and still be slow, so the report also flags chips whose AI cores are throttled or
+//! which run on a downtrained PCIe link. A lower AI core frequency alone is not flagged, idle
+//! chips clock down.
+
+use crate::device::{Card, Chip};
+use crate::enums::{HealthState, UnitType};
+use crate::error::DCMIResult;
+use crate::structs::{PCIELinkStatus, TemperatureThresholds};
+use crate::units::Celsius;
+use crate::utils::impl_as_str;
+use crate::DCMI;
+use std::fmt;
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Overall status of a chip or node, ordered from the best to the worst
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    /// Working, but with a minor alarm or reduced performance
+    Degraded,
+    /// Faulty or missing
+    Unhealthy,
+}
+
+/// Why a chip is not fully healthy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthReason {
+    /// Health state other than [`HealthState::Normal`] reported by the driver
+    Alarm(HealthState),
+    /// AI cores are throttled while the chip is at or above its alarm temperature
+    ThermalThrottling,
+    /// AI cores are throttled for another reason, with the cause reported by the driver
+    ///
+    /// The driver does not document its causes, a power cap cannot be told apart from other
+    /// limits.
+    Throttled(u32),
+    /// PCIe link trained below the speed or width supported by the chip
+    PcieDowntrained(PCIELinkStatus),
+}
+
+impl HealthReason {
+    /// Status a chip has at best with this reason
+    pub fn status(&self) -> HealthStatus {
+        match self {
+            HealthReason::Alarm(HealthState::MinorAlarm) => HealthStatus::Degraded,
+            HealthReason::Alarm(_) => HealthStatus::Unhealthy,
+            HealthReason::ThermalThrottling
+            | HealthReason::Throttled(_)
+            | HealthReason::PcieDowntrained(_) => HealthStatus::Degraded,
+        }
+    }
+}
+
+impl fmt::Display for HealthReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthReason::Alarm(state) => write!(f, "health {state}"),
+            HealthReason::ThermalThrottling => f.write_str("AI cores throttled by temperature"),
+            HealthReason::Throttled(cause) => write!(f, "AI cores throttled, cause {cause:#x}"),
+            HealthReason::PcieDowntrained(link) => write!(
+                f,
+                "PCIe downtrained to {} GT/s x{} (supported {} GT/s x{})",
+                link.speed, link.width, link.max_speed, link.max_width
+            ),
+        }
+    }
+}
+
+/// Health of a single chip
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChipHealth {
+    pub card_id: u32,
+    pub chip_id: u32,
+    pub reasons: Vec<HealthReason>,
+}
+
+impl ChipHealth {
+    pub fn status(&self) -> HealthStatus {
+        self.reasons
+            .iter()
+            .map(HealthReason::status)
+            .max()
+            .unwrap_or(HealthStatus::Healthy)
+    }
+}
+
+/// Health of every NPU of the node
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeHealthReport {
+    pub chips: Vec<ChipHealth>,
+}
+
+impl NodeHealthReport {
+    /// Worst status of all chips
+    pub fn status(&self) -> HealthStatus {
+        self.chips
+            .iter()
+            .map(ChipHealth::status)
+            .max()
+            .unwrap_or(HealthStatus::Healthy)
+    }
+
+    /// Chips which are not healthy
+    pub fn affected(&self) -> impl Iterator<Item = &ChipHealth> {
+        self.chips
+            .iter()
+            .filter(|chip| chip.status() != HealthStatus::Healthy)
+    }
+}
+
+/// Health states of the NPUs and the MCU of a card
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardHealth {
+    pub card_id: u32,
+    /// Health state of each NPU, with the chip id
+    pub chips: Vec<(u32, HealthState)>,
+    /// Health state of the MCU, `None` if the card has none or it does not report its health
+    pub mcu: Option<HealthState>,
+}
+
+impl CardHealth {
+    /// Worst health state of the NPUs and the MCU
+    pub fn state(&self) -> HealthState {
+        self.chips
+            .iter()
+            .map(|&(_, state)| state)
+            .chain(self.mcu)
+            .max()
+            .unwrap_or(HealthState::Normal)
+    }
+
+    /// Status of the card as a whole, degraded on a minor alarm like a chip
+    pub fn status(&self) -> HealthStatus {
+        match self.state() {
+            HealthState::Normal => HealthStatus::Healthy,
+            state => HealthReason::Alarm(state).status(),
+        }
+    }
+}
+
+/// Health reason of a chip throttled with `cause`, thermal if the chip has reached its alarm
+/// temperature
+fn throttle_reason(
+    cause: u32,
+    temperature: Option<Celsius>,
+    thresholds: Option<TemperatureThresholds>,
+) -> HealthReason {
+    match (temperature, thresholds) {
+        (Some(temperature), Some(thresholds)) if temperature >= thresholds.alarm => {
+            HealthReason::ThermalThrottling
+        }
+        _ => HealthReason::Throttled(cause),
+    }
+}
+
+impl Chip<'_> {
+    /// Check the health state, AI core throttling and PCIe link of the chip
+    ///
+    /// Throttling and link checks which the chip or environment does not support are skipped.
+    pub fn check_health(&self) -> DCMIResult<ChipHealth> {
+        let mut reasons = Vec::new();
+        let health = self.get_health()?;
+        if health != HealthState::Normal {
+            reasons.push(HealthReason::Alarm(health));
+        }
+        if let Some(throttle) = self.try_get_throttle_status()? {
+            if throttle.is_throttled() {
+                reasons.push(throttle_reason(
+                    throttle.cause,
+                    self.try_get_temperature()?,
+                    self.try_get_temperature_thresholds()?,
+                ));
+            }
+        }
+        if let Some(link) = self.try_get_pcie_link_status()? {
+            if link.is_downtrained() {
+                reasons.push(HealthReason::PcieDowntrained(link));
+            }
+        }
+        Ok(ChipHealth {
+            card_id: self.card_id(),
+            chip_id: self.id(),
+            reasons,
+        })
+    }
+}
+
+impl Card<'_> {
+    /// Query the health states of the NPUs and the MCU of the card
+    ///
+    /// The control CPU is not included, see [`CardHealth::state`] for the worst of them.
+    pub fn get_health(&self) -> DCMIResult<CardHealth> {
+        let mut chips = Vec::new();
+        let mut mcu = None;
+        for chip in self.get_chips()? {
+            match chip.unit_type() {
+                UnitType::NPU => chips.push((chip.id(), chip.get_health()?)),
+                UnitType::MCU => mcu = chip.try_get_health()?,
+                _ => {}
+            }
+        }
+        Ok(CardHealth {
+            card_id: self.id(),
+            chips,
+            mcu,
+        })
+    }
+}
+
+impl DCMI {
+    /// Check the health of every NPU of the node
+    pub fn health_report(&self) -> DCMIResult<NodeHealthReport> {
+        let chips = self
+            .all_chips()?
+            .iter()
+            .map(Chip::check_health)
+            .collect::<DCMIResult<_>>()?;
+        Ok(NodeHealthReport { chips })
+    }
+}
+
+impl_as_str!(HealthStatus);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_is_worst_reason() {
+        let mut chip = ChipHealth {
+            card_id: 0,
+            chip_id: 0,
+            reasons: Vec::new(),
+        };
+        assert_eq!(chip.status(), HealthStatus::Healthy);
+        chip.reasons.push(HealthReason::ThermalThrottling);
+        assert_eq!(chip.status(), HealthStatus::Degraded);
+        let mut report = NodeHealthReport {
+            chips: vec![chip.clone()],
+        };
+        chip.reasons
+            .push(HealthReason::Alarm(HealthState::CriticalAlarm));
+        report.chips.push(chip);
+        assert_eq!(report.status(), HealthStatus::Unhealthy);
+        assert_eq!(report.affected().count(), 2);
+        assert_eq!(
+            report.chips[0].reasons[0].to_string(),
+            "AI cores throttled by temperature"
+        );
+    }
+
+    #[test]
+    fn throttling_is_thermal_at_the_alarm_temperature() {
+        let thresholds = TemperatureThresholds {
+            alarm: Celsius(85),
+            shutdown: Celsius(105),
+        };
+        assert_eq!(
+            throttle_reason(1, Some(Celsius(85)), Some(thresholds)),
+            HealthReason::ThermalThrottling
+        );
+        assert_eq!(
+            throttle_reason(1, Some(Celsius(60)), Some(thresholds)),
+            HealthReason::Throttled(1)
+        );
+        assert_eq!(
+            throttle_reason(4, Some(Celsius(95)), None),
+            HealthReason::Throttled(4)
+        );
+    }
+
+    #[test]
+    fn card_health_is_worst_unit() {
+        let mut card = CardHealth {
+            card_id: 0,
+            chips: vec![(0, HealthState::Normal), (1, HealthState::MinorAlarm)],
+            mcu: None,
+        };
+        assert_eq!(card.state(), HealthState::MinorAlarm);
+        assert_eq!(card.status(), HealthStatus::Degraded);
+        card.mcu = Some(HealthState::MajorAlarm);
+        assert_eq!(card.status(), HealthStatus::Unhealthy);
+        card.chips.clear();
+        card.mcu = None;
+        assert_eq!(card.status(), HealthStatus::Healthy);
+    }
+}
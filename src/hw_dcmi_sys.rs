@@ -30,9 +30,6 @@ impl<T> ::std::fmt::Debug for __IncompleteArrayField<T> {
         fmt.write_str("__IncompleteArrayField")
     }
 }
-pub const __bool_true_false_are_defined: u32 = 1;
-pub const true_: u32 = 1;
-pub const false_: u32 = 0;
 pub const MAX_VER_LEN: u32 = 255;
 pub const MAX_CARD_NUM: u32 = 64;
 pub const MAX_CHIP_NAME_LEN: u32 = 32;
@@ -0,0 +1,155 @@
+//! Cached identity of the chips
+//!
+//! Exporters attach model, serial number and versions to every scrape. These never change while
+//! a driver is loaded, so [`Chip::identity`] reads them once per chip and serves them from a
+//! process-wide cache afterwards. The cache is dropped when [`DCMI::driver_generation`] moves on
+//! after a driver upgrade, or explicitly with [`DCMI::clear_identity_cache`].
+//!
+//! [`DCMI::chip_by_serial`] and [`DCMI::chip_by_die_id`] find a chip by identity, for
+//! configuration which has to follow a device across reboots.
+
+use crate::device::Chip;
+use crate::error::DCMIResult;
+use crate::structs::{BoardInfo, ChipInfo, DieInfo, ELabelInfo};
+use crate::DCMI;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Static identity of a chip
+///
+/// Only the chip information is required, the other parts are `None` if the chip or
+/// environment does not report them (e.g. the electronic label inside a container).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipIdentity {
+    pub card_id: u32,
+    pub chip_id: u32,
+    pub info: ChipInfo,
+    pub elabel: Option<ELabelInfo>,
+    pub board: Option<BoardInfo>,
+    pub firmware_version: Option<String>,
+    pub driver_version: Option<String>,
+}
+
+/// Identities read so far, keyed by `(card_id, chip_id)`, with the driver generation they were
+/// read in
+#[derive(Debug, Default)]
+struct IdentityCache {
+    generation: u64,
+    chips: HashMap<(u32, u32), ChipIdentity>,
+}
+
+static CACHE: Mutex<Option<IdentityCache>> = Mutex::new(None);
+
+impl IdentityCache {
+    /// Cached identity of a chip, entries of an older driver generation are dropped first
+    fn get(&mut self, generation: u64, key: (u32, u32)) -> Option<ChipIdentity> {
+        if self.generation != generation {
+            self.chips.clear();
+            self.generation = generation;
+        }
+        self.chips.get(&key).cloned()
+    }
+}
+
+impl Chip<'_> {
+    /// Identity of the chip, read from the driver on the first call and cached afterwards
+    pub fn identity(&self) -> DCMIResult<ChipIdentity> {
+        let generation = self.dcmi.driver_generation();
+        let key = (self.card_id(), self.id());
+        if let Some(identity) = lock_cache()
+            .get_or_insert_with(Default::default)
+            .get(generation, key)
+        {
+            return Ok(identity);
+        }
+        // read without holding the lock, a concurrent reader of the same chip does the same work
+        let identity = self.read_identity()?;
+        let mut cache = lock_cache();
+        let cache = cache.get_or_insert_with(Default::default);
+        if cache.generation == generation {
+            cache.chips.insert(key, identity.clone());
+        }
+        Ok(identity)
+    }
+
+    fn read_identity(&self) -> DCMIResult<ChipIdentity> {
+        Ok(ChipIdentity {
+            card_id: self.card_id(),
+            chip_id: self.id(),
+            info: self.get_chip_info()?,
+            elabel: self.get_elabel_info().ok(),
+            board: self.get_board_info().ok(),
+            firmware_version: self.get_firmware_version().ok(),
+            driver_version: self.dcmi.get_driver_version().ok(),
+        })
+    }
+}
+
+impl DCMI {
+    /// Drop the cached chip identities, the next [`Chip::identity`] reads them again
+    pub fn clear_identity_cache(&self) {
+        *lock_cache() = None;
+    }
+
+    /// Find the NPU whose electronic label carries `serial_number`
+    ///
+    /// Unlike card and chip ids, the serial number survives reboots and changes of the
+    /// enumeration order. The chips of a multi-chip card report the serial number of the card,
+    /// the first of them is returned then, use [`DCMI::chip_by_die_id`] to tell them apart.
+    /// Chips without an electronic label are skipped.
+    pub fn chip_by_serial(&self, serial_number: &str) -> DCMIResult<Option<Chip<'_>>> {
+        for chip in self.all_chips()? {
+            let identity = chip.identity()?;
+            if identity
+                .elabel
+                .is_some_and(|elabel| elabel.serial_number == serial_number)
+            {
+                return Ok(Some(chip));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the NPU one of whose dies has the id `die_id`, see [`Chip::dies`]
+    pub fn chip_by_die_id(&self, die_id: &DieInfo) -> DCMIResult<Option<Chip<'_>>> {
+        for chip in self.all_chips()? {
+            if chip.dies()?.iter().any(|die| die.info() == die_id) {
+                return Ok(Some(chip));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn lock_cache() -> std::sync::MutexGuard<'static, Option<IdentityCache>> {
+    CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_is_dropped_on_new_generation() {
+        let identity = ChipIdentity {
+            card_id: 1,
+            chip_id: 0,
+            info: ChipInfo {
+                chip_type: "Ascend".to_string(),
+                chip_name: "910B3".to_string(),
+                chip_version: "V1".to_string(),
+                aicore_count: 20,
+            },
+            elabel: None,
+            board: None,
+            firmware_version: None,
+            driver_version: Some("24.1.rc2".to_string()),
+        };
+        let mut cache = IdentityCache::default();
+        cache.chips.insert((1, 0), identity.clone());
+        assert_eq!(cache.get(0, (1, 0)), Some(identity));
+        assert_eq!(cache.get(0, (1, 1)), None);
+        assert_eq!(cache.get(1, (1, 0)), None);
+        assert!(cache.chips.is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! Descriptors for Kubernetes device plugins
+//!
+//! Maps chips and vNPUs to the devices a device plugin advertises to the kubelet, named like the
+//! Ascend device plugin does: the resource of an Ascend 910 is `huawei.com/Ascend910`, its chip
+//! with physical id 3 is the device `Ascend910-3`, and a vNPU with 4 AI cores created on it is a
+//! device `Ascend910-4c-<vNPU id>-3` of the resource `huawei.com/Ascend910-4c`.
+//!
+//! ```no_run
+//! # use hw_dcmi::DCMI;
+//! use hw_dcmi::k8s;
+//!
+//! let dcmi = DCMI::init().unwrap();
+//! let devices = dcmi.device_descriptors().unwrap();
+//! for device in &devices {
+//!     println!("{} {} {} {:?}", device.resource_name, device.id, device.health, device.numa_nodes);
+//! }
+//! // annotations of a pod allocated all devices
+//! let annotations = k8s::annotations(&devices);
+//! ```
+
+use crate::device::Chip;
+use crate::error::{DCMIError, DCMIResult};
+use crate::health::HealthStatus;
+use crate::structs::ChipInfo;
+use crate::utils::impl_as_str;
+use crate::vnpu::VirtualChip;
+use crate::DCMI;
+use std::collections::BTreeMap;
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Vendor domain of the extended resources
+pub const RESOURCE_DOMAIN: &str = "huawei.com";
+
+/// Health of a device as reported to the kubelet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+pub enum DeviceHealth {
+    Healthy,
+    Unhealthy,
+}
+
+impl From<HealthStatus> for DeviceHealth {
+    /// Degraded chips still run workloads, only unhealthy ones are withdrawn
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Healthy | HealthStatus::Degraded => DeviceHealth::Healthy,
+            HealthStatus::Unhealthy => DeviceHealth::Unhealthy,
+        }
+    }
+}
+
+/// Device advertised by a device plugin, a whole chip or a vNPU
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceDescriptor {
+    /// Device id, e.g. `Ascend910-3`
+    pub id: String,
+    /// Extended resource the device belongs to, e.g. `huawei.com/Ascend910`
+    pub resource_name: String,
+    pub health: DeviceHealth,
+    /// NUMA nodes the device is attached to, the topology hint of the device, empty if unknown
+    pub numa_nodes: Vec<u32>,
+    pub card_id: u32,
+    pub chip_id: u32,
+    pub phy_id: u32,
+    /// Id of the vNPU, `None` for a whole chip
+    pub vchip_id: Option<u32>,
+}
+
+/// Device class of a chip model, e.g. `Ascend910` for an Ascend 910B3 and `Ascend310P` for an
+/// Ascend 310P3
+pub fn device_class(chip_info: &ChipInfo) -> String {
+    let digits = chip_info
+        .chip_name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(chip_info.chip_name.len());
+    let series = match chip_info.chip_name[digits..].chars().next() {
+        Some('P') => &chip_info.chip_name[..digits + 1],
+        _ => &chip_info.chip_name[..digits],
+    };
+    format!("{}{series}", chip_info.chip_type)
+}
+
+/// Suffix of the vNPU resource for a template name, e.g. `4c` for `vir04` and `2c.1cpu` for
+/// `vir02_1c`
+///
+/// Returns `None` for names not following the `vir<AI cores>[_<AI CPUs>c]...` scheme.
+pub fn vnpu_suffix(template_name: &str) -> Option<String> {
+    let mut parts = template_name.strip_prefix("vir")?.split('_');
+    let aicore: u32 = parts.next()?.parse().ok()?;
+    let mut suffix = format!("{aicore}c");
+    for part in parts {
+        suffix.push('.');
+        match part.strip_suffix('c') {
+            Some(aicpu) if aicpu.parse::<u32>().is_ok() => {
+                suffix.push_str(aicpu);
+                suffix.push_str("cpu");
+            }
+            _ => suffix.push_str(part),
+        }
+    }
+    Some(suffix)
+}
+
+/// Pod annotations listing the devices per resource, e.g.
+/// `huawei.com/Ascend910: Ascend910-0,Ascend910-1`
+pub fn annotations(devices: &[DeviceDescriptor]) -> BTreeMap<String, String> {
+    let mut annotations: BTreeMap<String, String> = BTreeMap::new();
+    for device in devices {
+        let ids = annotations.entry(device.resource_name.clone()).or_default();
+        if !ids.is_empty() {
+            ids.push(',');
+        }
+        ids.push_str(&device.id);
+    }
+    annotations
+}
+
+impl Chip<'_> {
+    /// Describe the whole chip as a device plugin device
+    pub fn device_descriptor(&self) -> DCMIResult<DeviceDescriptor> {
+        let class = device_class(&self.get_chip_info()?);
+        let phy_id = self.get_phy_id()?;
+        Ok(DeviceDescriptor {
+            id: format!("{class}-{phy_id}"),
+            resource_name: format!("{RESOURCE_DOMAIN}/{class}"),
+            health: self.check_health()?.status().into(),
+            numa_nodes: self.get_numa_node()?.into_iter().collect(),
+            card_id: self.card_id,
+            chip_id: self.id,
+            phy_id,
+            vchip_id: None,
+        })
+    }
+}
+
+impl VirtualChip<'_> {
+    /// Describe the vNPU as a device plugin device, it shares the health and NUMA node of its
+    /// chip
+    ///
+    /// # Errors
+    /// [`DCMIError::InvalidParameter`] if the vNPU was created from a template whose name does
+    /// not follow the `vir<AI cores>` scheme
+    pub fn device_descriptor(&self) -> DCMIResult<DeviceDescriptor> {
+        let chip = self.chip().device_descriptor()?;
+        let suffix = vnpu_suffix(&self.info()?.name).ok_or(DCMIError::InvalidParameter)?;
+        let class = &chip.resource_name[RESOURCE_DOMAIN.len() + 1..];
+        Ok(DeviceDescriptor {
+            id: format!("{class}-{suffix}-{}-{}", self.id(), chip.phy_id),
+            resource_name: format!("{}-{suffix}", chip.resource_name),
+            vchip_id: Some(self.id()),
+            ..chip
+        })
+    }
+}
+
+impl DCMI {
+    /// Describe every NPU as a device plugin device, split chips as one device per vNPU
+    pub fn device_descriptors(&self) -> DCMIResult<Vec<DeviceDescriptor>> {
+        let mut devices = Vec::new();
+        for chip in self.all_chips()? {
+            let vchips = match chip.list_virtual_chips() {
+                Ok(vchips) => vchips,
+                Err(e) if e.is_unsupported() => Vec::new(),
+                Err(e) => return Err(e),
+            };
+            if vchips.is_empty() {
+                devices.push(chip.device_descriptor()?);
+            } else {
+                for vchip in vchips {
+                    devices.push(vchip.device_descriptor()?);
+                }
+            }
+        }
+        Ok(devices)
+    }
+}
+
+impl_as_str!(DeviceHealth);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_devices_like_the_ascend_device_plugin() {
+        let chip_info = |chip_name: &str| ChipInfo {
+            chip_type: "Ascend".to_string(),
+            chip_name: chip_name.to_string(),
+            chip_version: "V1".to_string(),
+            aicore_count: 30,
+        };
+        assert_eq!(device_class(&chip_info("910B3")), "Ascend910");
+        assert_eq!(device_class(&chip_info("310P3")), "Ascend310P");
+        assert_eq!(device_class(&chip_info("310")), "Ascend310");
+
+        assert_eq!(vnpu_suffix("vir04").as_deref(), Some("4c"));
+        assert_eq!(vnpu_suffix("vir02_1c").as_deref(), Some("2c.1cpu"));
+        assert_eq!(
+            vnpu_suffix("vir04_4c_dvpp").as_deref(),
+            Some("4c.4cpu.dvpp")
+        );
+        assert_eq!(vnpu_suffix("custom"), None);
+
+        let device = |id: &str, resource_name: &str| DeviceDescriptor {
+            id: id.to_string(),
+            resource_name: resource_name.to_string(),
+            health: DeviceHealth::Healthy,
+            numa_nodes: vec![0],
+            card_id: 0,
+            chip_id: 0,
+            phy_id: 0,
+            vchip_id: None,
+        };
+        let annotations = annotations(&[
+            device("Ascend910-0", "huawei.com/Ascend910"),
+            device("Ascend910-4c-100-1", "huawei.com/Ascend910-4c"),
+            device("Ascend910-2", "huawei.com/Ascend910"),
+        ]);
+        assert_eq!(
+            annotations["huawei.com/Ascend910"],
+            "Ascend910-0,Ascend910-2"
+        );
+        assert_eq!(annotations["huawei.com/Ascend910-4c"], "Ascend910-4c-100-1");
+    }
+}
@@ -1,16 +1,248 @@
+#[allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    non_snake_case,
+    dead_code,
+    clippy::all
+)]
 pub mod hw_dcmi_sys;
 
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+#[cfg(feature = "async")]
+pub mod aio;
+pub mod cache;
+pub mod capability;
+pub mod compat;
+pub mod container;
+pub mod coverage;
+#[cfg(feature = "json")]
+pub mod delta;
+pub mod device;
+pub mod enums;
+pub mod error;
+pub mod event;
+pub mod health;
+pub mod identity;
+#[cfg(feature = "k8s")]
+pub mod k8s;
+#[cfg(feature = "dynamic-loading")]
+pub mod loader;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod monitor;
+pub mod parallel;
+pub mod query;
+#[cfg(feature = "json")]
+pub mod ranktable;
+pub mod remote;
+pub mod retry;
+pub mod snapshot;
+pub mod structs;
+pub mod topology;
+pub mod units;
+pub mod upgrade;
+mod utils;
+pub mod vnpu;
+pub mod watchdog;
+
+use crate::capability::{Capabilities, Feature};
+use crate::device::{Card, Chip, ERROR_CODES_LIMIT, MAX_ERROR_CODES};
+use crate::enums::{HealthState, UnitType};
+use crate::error::{call_dcmi_function, convert, convert_string, DCMIResult, StringConversion};
+use crate::hw_dcmi_sys::{MAX_CARD_NUM, MAX_VER_LEN};
+use crate::retry::RetryPolicy;
+use crate::utils::query_list;
+use std::sync::Mutex;
+
+/// Whether `dcmi_init` succeeded in this process
+static INITIALIZED: Mutex<bool> = Mutex::new(false);
+
+/// Most cards queried by [`DCMI::get_card_list`], which starts with room for `MAX_CARD_NUM`
+const CARD_LIST_LIMIT: usize = 1024;
+
+/// Entry point of the safe DCMI bindings
+///
+/// `dcmi_init` is called once per process by the first [`DCMI::init`], every card and chip
+/// handle borrows the `DCMI` instance it was created from.
+#[derive(Debug)]
+pub struct DCMI {
+    _private: (),
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl DCMI {
+    /// Initialize the DCMI library
+    ///
+    /// Initializing the library a second time is undefined behavior with some driver versions,
+    /// so later calls (e.g. from another component of the same process) share the first
+    /// initialization. A failed initialization is retried by the next call.
+    ///
+    /// With the `dynamic-loading` feature the library is first loaded from the default
+    /// locations, see [`loader`].
+    pub fn init() -> DCMIResult<Self> {
+        #[cfg(feature = "dynamic-loading")]
+        loader::load(&loader::DCMIBuilder::default())?;
+        let mut initialized = INITIALIZED.lock().unwrap_or_else(|e| e.into_inner());
+        let dcmi = DCMI { _private: () };
+        if !*initialized {
+            call_dcmi_function!(dcmi_init)?;
+            *initialized = true;
+            capability::set(dcmi.driver_generation(), dcmi.detect_capabilities());
+        }
+        Ok(dcmi)
+    }
+
+    /// Library and driver versions, read at initialization and again after a driver upgrade
+    /// detected by [`DCMI::check_driver_version`]
+    pub fn capabilities(&self) -> Capabilities {
+        let generation = self.driver_generation();
+        capability::get(generation).unwrap_or_else(|| {
+            let capabilities = self.detect_capabilities();
+            capability::set(generation, capabilities);
+            capabilities
+        })
+    }
+
+    fn detect_capabilities(&self) -> Capabilities {
+        Capabilities {
+            dcmi_version: self.get_dcmi_version().ok().and_then(|v| v.parse().ok()),
+            driver_version: self.get_driver_version().ok().and_then(|v| v.parse().ok()),
+        }
+    }
+
+    /// Whether the installed library and driver provide `feature`, see [`Capabilities::supports`]
+    pub fn supports(&self, feature: Feature) -> bool {
+        feature.is_loaded()
+    }
+
+    /// Options for loading the library at runtime, see [`loader`]
+    #[cfg(feature = "dynamic-loading")]
+    pub fn builder() -> loader::DCMIBuilder {
+        loader::DCMIBuilder::default()
+    }
+
+    /// Retry transient errors of every read-only DCMI query of the process, see [`retry`]
+    ///
+    /// The policy is shared by all `DCMI` handles, like the initialization. By default calls are
+    /// not retried. Calls changing the device and fault event polling are never retried.
+    pub fn set_retry_policy(&self, policy: RetryPolicy) {
+        retry::set(policy);
+    }
+
+    /// Policy set by [`DCMI::set_retry_policy`]
+    pub fn retry_policy(&self) -> RetryPolicy {
+        retry::current()
+    }
+
+    /// Set how strings returned by DCMI which are not valid UTF-8 are converted, for every `DCMI`
+    /// handle of the process
+    ///
+    /// By default invalid sequences are replaced, [`StringConversion::Strict`] fails the queries
+    /// returning them with [`DCMIError::MalformedString`](error::DCMIError::MalformedString).
+    pub fn set_string_conversion(&self, conversion: StringConversion) {
+        error::set_string_conversion(conversion);
+    }
+
+    /// Conversion set by [`DCMI::set_string_conversion`]
+    pub fn string_conversion(&self) -> StringConversion {
+        error::string_conversion()
+    }
+
+    /// Whether the DCMI library has been initialized in this process
+    pub fn is_initialized() -> bool {
+        *INITIALIZED.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Query the version of the DCMI library
+    pub fn get_dcmi_version(&self) -> DCMIResult<String> {
+        let mut version = [0; MAX_VER_LEN as usize + 1];
+        call_dcmi_function!(
+            dcmi_get_dcmi_version,
+            version.as_mut_ptr(),
+            version.len() as u32
+        )?;
+        convert_string(&version)
+    }
+
+    /// Query the version of the NPU driver
+    pub fn get_driver_version(&self) -> DCMIResult<String> {
+        let mut version = [0; MAX_VER_LEN as usize + 1];
+        call_dcmi_function!(
+            dcmi_get_driver_version,
+            version.as_mut_ptr(),
+            version.len() as u32
+        )?;
+        convert_string(&version)
+    }
+
+    /// Query the health state of the NPU driver, covering faults not bound to a chip
+    pub fn get_driver_health(&self) -> DCMIResult<HealthState> {
+        let mut health = 0;
+        call_dcmi_function!(dcmi_get_driver_health, &mut health)?;
+        convert(health)
+    }
+
+    /// Query the error codes of the faults currently active in the NPU driver
+    ///
+    /// [`Chip::get_errors`] reports the faults of a chip.
+    pub fn get_driver_error_codes(&self) -> DCMIResult<Vec<u32>> {
+        query_list(
+            MAX_ERROR_CODES,
+            ERROR_CODES_LIMIT,
+            |error_codes: &mut [u32]| -> DCMIResult<usize> {
+                let mut error_count = 0;
+                call_dcmi_function!(
+                    dcmi_get_driver_errorcode,
+                    &mut error_count,
+                    error_codes.as_mut_ptr(),
+                    error_codes.len() as u32
+                )?;
+                Ok(usize::try_from(error_count).unwrap_or(0))
+            },
+        )
+    }
+
+    /// Query all cards managed by DCMI
+    ///
+    /// The list is queried again with a larger buffer while the cards fill it, so hosts with more
+    /// than `MAX_CARD_NUM` cards are listed completely.
+    pub fn get_card_list(&self) -> DCMIResult<Vec<Card<'_>>> {
+        let card_list = query_list(
+            MAX_CARD_NUM as usize,
+            CARD_LIST_LIMIT,
+            |card_list: &mut [i32]| -> DCMIResult<usize> {
+                let mut card_num = 0;
+                call_dcmi_function!(
+                    dcmi_get_card_list,
+                    &mut card_num,
+                    card_list.as_mut_ptr(),
+                    card_list.len() as i32
+                )?;
+                Ok(usize::try_from(card_num).unwrap_or(0))
+            },
+        )?;
+        Ok(card_list
+            .into_iter()
+            .map(|id| Card::new(self, id as u32))
+            .collect())
+    }
+
+    /// Query the NPU chips of all cards, in card order
+    pub fn all_chips(&self) -> DCMIResult<Vec<Chip<'_>>> {
+        self.all_chips_of(&[UnitType::NPU])
+    }
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    /// Query the chips of the given kinds of all cards, in card order
+    ///
+    /// # Parameters
+    /// - unit_types: kinds of chip to include, e.g. `&[UnitType::NPU, UnitType::MCU]`
+    pub fn all_chips_of(&self, unit_types: &[UnitType]) -> DCMIResult<Vec<Chip<'_>>> {
+        let mut chips = Vec::new();
+        for card in self.get_card_list()? {
+            chips.extend(
+                card.get_chips()?
+                    .into_iter()
+                    .filter(|chip| unit_types.contains(&chip.unit_type())),
+            );
+        }
+        Ok(chips)
     }
 }
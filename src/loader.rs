@@ -0,0 +1,290 @@
+//! Loading libdcmi at runtime
+//!
+//! With the `dynamic-loading` feature the crate does not link against libdcmi, [`DCMI::init`]
+//! opens it instead. The default search follows the build: `$HW_DCMI_PATH/libdcmi.so` if the
+//! variable is set at runtime, then `libdcmi.so` through the dynamic linker (`LD_LIBRARY_PATH`,
+//! the linker cache), then the locations driver installs use (`/usr/local/dcmi`,
+//! `/usr/local/Ascend/driver/lib64` and `/usr/lib64`). [`DCMIBuilder`] selects another path,
+//! e.g. where a container mounts the library. A libdcmi the process has loaded already, e.g.
+//! through another library linked against it, is reused instead of loading a second copy.
+//!
+//! The bindings follow the newest header, yet one binary serves fleets with several driver
+//! generations: a library only has to export the functions needed to initialize it and list the
+//! chips. Calls of functions an older library lacks return [`DCMIError::NotSupport`], like calls
+//! the driver rejects, and [`missing_symbols`] lists them.
+
+use crate::error::{DCMIError, DCMIResult};
+use crate::hw_dcmi_sys::{DcmiLibrary, DCMI_SYMBOLS};
+use crate::DCMI;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// File name of the library
+const LIBRARY_NAME: &str = "libdcmi.so";
+/// Where NPU driver installs put the library, keep in sync with `DEFAULT_DIRS` in `build.rs`
+const DEFAULT_DIRS: &[&str] = &[
+    "/usr/local/dcmi",
+    "/usr/local/Ascend/driver/lib64/driver",
+    "/usr/local/Ascend/driver/lib64",
+    "/usr/lib64",
+];
+
+/// Functions every supported driver generation exports, without them the library is unusable
+const REQUIRED_SYMBOLS: &[&str] = &[
+    "dcmi_init",
+    "dcmi_get_card_list",
+    "dcmi_get_device_num_in_card",
+    "dcmi_get_device_id_in_card",
+];
+
+/// Library loaded by the first successful [`load`]
+static LIBRARY: OnceLock<DcmiLibrary> = OnceLock::new();
+/// Path [`LIBRARY`] was loaded from
+static LIBRARY_PATH: OnceLock<PathBuf> = OnceLock::new();
+/// Functions of the bindings the loaded library lacks
+static MISSING: OnceLock<Vec<&'static str>> = OnceLock::new();
+/// Serializes [`load`], so the library is opened once
+static LOADING: Mutex<()> = Mutex::new(());
+
+/// Options of [`DCMI::init`]
+///
+/// ```no_run
+/// use hw_dcmi::DCMI;
+///
+/// let dcmi = DCMI::builder()
+///     .library_path("/opt/ascend/lib64/libdcmi.so")
+///     .allow_missing_symbols(false)
+///     .init()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DCMIBuilder {
+    library_path: Option<PathBuf>,
+    allow_missing_symbols: bool,
+}
+
+impl Default for DCMIBuilder {
+    fn default() -> Self {
+        DCMIBuilder {
+            library_path: None,
+            allow_missing_symbols: true,
+        }
+    }
+}
+
+impl DCMIBuilder {
+    /// Load the library from `path` instead of searching the default locations
+    pub fn library_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.library_path = Some(path.into());
+        self
+    }
+
+    /// Accept a library lacking some functions, calls of those return [`DCMIError::NotSupport`]
+    ///
+    /// Enabled by default, so libraries of older driver generations load. Disabled, a library
+    /// lacking any function of the bindings is rejected by [`DCMIBuilder::init`]. A library
+    /// lacking a function needed to initialize it or list the chips is always rejected.
+    pub fn allow_missing_symbols(mut self, allow: bool) -> Self {
+        self.allow_missing_symbols = allow;
+        self
+    }
+
+    /// Load the library and initialize it, see [`DCMI::init`]
+    ///
+    /// The library is loaded once per process. Once it is loaded, a call naming another
+    /// [`library_path`](DCMIBuilder::library_path) fails with
+    /// [`DCMIError::LibraryAlreadyLoaded`], the other options of later calls have no effect.
+    pub fn init(self) -> DCMIResult<DCMI> {
+        load(&self)?;
+        DCMI::init()
+    }
+}
+
+/// Library functions are called through, `None` before it is loaded
+pub(crate) fn library() -> Option<&'static DcmiLibrary> {
+    LIBRARY.get()
+}
+
+/// Functions of the bindings the loaded library lacks, calls of them return
+/// [`DCMIError::NotSupport`]
+///
+/// Empty before the library is loaded.
+pub fn missing_symbols() -> &'static [&'static str] {
+    MISSING.get().map_or(&[], Vec::as_slice)
+}
+
+/// Missing functions which make the library unusable, all of them unless `allow_missing`
+fn rejected<'a>(missing: &[&'a str], allow_missing: bool) -> Vec<&'a str> {
+    missing
+        .iter()
+        .copied()
+        .filter(|symbol| !allow_missing || REQUIRED_SYMBOLS.contains(symbol))
+        .collect()
+}
+
+/// Paths tried in order when no library path is configured
+fn default_paths(hw_dcmi_path: Option<PathBuf>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = hw_dcmi_path
+        .map(|dir| dir.join(LIBRARY_NAME))
+        .into_iter()
+        .collect();
+    paths.push(PathBuf::from(LIBRARY_NAME));
+    paths.extend(
+        DEFAULT_DIRS
+            .iter()
+            .map(|dir| Path::new(dir).join(LIBRARY_NAME)),
+    );
+    paths
+}
+
+/// Whether `a` and `b` name the same file
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b
+        || matches!(
+            (std::fs::canonicalize(a), std::fs::canonicalize(b)),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+/// Handle of the library at `path` if the process has loaded it already
+#[cfg(unix)]
+fn already_loaded(path: &Path) -> Option<libloading::Library> {
+    // SAFETY: with RTLD_NOLOAD nothing is loaded or initialized, only the handle of a loaded
+    // library is returned
+    unsafe { libloading::os::unix::Library::open(Some(path), libc::RTLD_NOLOAD | libc::RTLD_NOW) }
+        .ok()
+        .map(Into::into)
+}
+
+#[cfg(not(unix))]
+fn already_loaded(_path: &Path) -> Option<libloading::Library> {
+    None
+}
+
+/// Open the library as configured by `options` unless it is loaded already
+///
+/// A library the process has loaded from one of the paths is preferred over loading another
+/// copy from an earlier path, two copies would each have their own state.
+pub(crate) fn load(options: &DCMIBuilder) -> DCMIResult<()> {
+    let _loading = LOADING.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(loaded) = LIBRARY_PATH.get() {
+        return match &options.library_path {
+            Some(requested) if !same_file(requested, loaded) => {
+                Err(DCMIError::LibraryAlreadyLoaded {
+                    loaded: loaded.clone(),
+                    requested: requested.clone(),
+                })
+            }
+            _ => Ok(()),
+        };
+    }
+    let paths = match &options.library_path {
+        Some(path) => vec![path.clone()],
+        None => default_paths(std::env::var_os("HW_DCMI_PATH").map(PathBuf::from)),
+    };
+    if let Some((path, library)) = paths
+        .iter()
+        .find_map(|path| Some((path, already_loaded(path)?)))
+    {
+        return register(path, library, options);
+    }
+    let mut errors = Vec::new();
+    for path in &paths {
+        // SAFETY: libdcmi runs no initialization code with preconditions when it is opened
+        match unsafe { libloading::Library::new(path) } {
+            Ok(library) => return register(path, library, options),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    Err(DCMIError::LibraryLoad(errors.join("; ")))
+}
+
+/// Check the functions of the library opened from `path` and make it the one calls go through
+fn register(path: &Path, library: libloading::Library, options: &DCMIBuilder) -> DCMIResult<()> {
+    let missing: Vec<&'static str> = DCMI_SYMBOLS
+        .iter()
+        .copied()
+        // SAFETY: the symbol is only looked up, not used
+        .filter(|symbol| unsafe { library.get::<*const ()>(symbol.as_bytes()) }.is_err())
+        .collect();
+    let rejected = rejected(&missing, options.allow_missing_symbols);
+    if !rejected.is_empty() {
+        return Err(DCMIError::LibraryLoad(format!(
+            "{} lacks {}",
+            path.display(),
+            rejected.join(", ")
+        )));
+    }
+    // SAFETY: the functions are declared with the signatures of the header the bindings were
+    // generated from
+    let library = unsafe { DcmiLibrary::from_library(library) }
+        .map_err(|e| DCMIError::LibraryLoad(format!("{}: {e}", path.display())))?;
+    let _ = MISSING.set(missing);
+    let _ = LIBRARY_PATH.set(path.to_path_buf());
+    let _ = LIBRARY.set(library);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn searches_hw_dcmi_path_first() {
+        assert_eq!(
+            default_paths(Some(PathBuf::from("/mnt/dcmi"))),
+            [
+                PathBuf::from("/mnt/dcmi/libdcmi.so"),
+                PathBuf::from("libdcmi.so"),
+                PathBuf::from("/usr/local/dcmi/libdcmi.so"),
+                PathBuf::from("/usr/local/Ascend/driver/lib64/driver/libdcmi.so"),
+                PathBuf::from("/usr/local/Ascend/driver/lib64/libdcmi.so"),
+                PathBuf::from("/usr/lib64/libdcmi.so"),
+            ]
+        );
+        assert_eq!(default_paths(None).len(), 5);
+    }
+
+    #[test]
+    fn accepts_older_libraries_with_required_symbols() {
+        let older = [
+            "dcmi_get_device_memory_info_v3",
+            "dcmi_get_device_pcie_info_v2",
+        ];
+        assert!(rejected(&older, true).is_empty());
+        assert_eq!(rejected(&older, false), older);
+        assert_eq!(
+            rejected(&["dcmi_init", "dcmi_get_device_pcie_info_v2"], true),
+            ["dcmi_init"]
+        );
+    }
+
+    #[test]
+    fn compares_library_paths_by_file() {
+        let dir = std::env::temp_dir();
+        assert!(same_file(
+            Path::new("/opt/libdcmi.so"),
+            Path::new("/opt/libdcmi.so")
+        ));
+        assert!(same_file(&dir, &dir.join(".")));
+        assert!(!same_file(
+            Path::new("/opt/a/libdcmi.so"),
+            Path::new("/opt/b/libdcmi.so")
+        ));
+    }
+
+    #[test]
+    fn unloaded_library_is_not_found_loaded() {
+        assert!(already_loaded(Path::new("/nonexistent/libdcmi.so")).is_none());
+    }
+
+    #[test]
+    #[ignore = "needs libdcmi, run with HW_DCMI_PATH set to its directory"]
+    fn init_loads_library_from_hw_dcmi_path() {
+        let dir = std::env::var_os("HW_DCMI_PATH").expect("HW_DCMI_PATH is not set");
+        assert!(Path::new(&dir).join(LIBRARY_NAME).exists());
+        // dcmi_init may still fail on a host without NPUs, the library is loaded before
+        let _ = DCMI::init();
+        assert!(library().is_some());
+    }
+}
@@ -0,0 +1,374 @@
+//! Hardware-free backend for tests of code using this crate
+//!
+//! [`MockDcmi`] and [`MockChip`] implement [`DcmiApi`] and [`ChipQuery`] from plain data, so
+//! collectors written against the traits can be tested without Ascend hardware or the DCMI
+//! library. Every value can be changed between reads through the public fields, and errors can be
+//! injected per query.
+//!
+//! ```
+//! use hw_dcmi::error::DCMIError;
+//! use hw_dcmi::mock::{MockChip, MockDcmi, MockQuery};
+//! use hw_dcmi::monitor::Metric;
+//! use hw_dcmi::query::DcmiApi;
+//! use hw_dcmi::units::{Celsius, Watts};
+//!
+//! let mut dcmi = MockDcmi::new()
+//!     .with_chip(MockChip::new(0, 0).temperature(Celsius(52)).power(Watts(180.0)))
+//!     .with_chip(MockChip::new(1, 0).fail(MockQuery::Power, DCMIError::DeviceNotExist));
+//!
+//! let chips = dcmi.all_chips().unwrap();
+//! assert_eq!(Metric::Temperature.read(&chips[0]), Ok(52.0));
+//! assert_eq!(Metric::Power.read(&chips[1]), Err(DCMIError::DeviceNotExist));
+//!
+//! dcmi.chip_mut(0, 0).unwrap().temperature = Celsius(95);
+//! assert_eq!(Metric::Temperature.read(&dcmi.all_chips().unwrap()[0]), Ok(95.0));
+//! ```
+
+use crate::enums::{DeviceType, FrequencyType, HealthState, UtilizationType};
+use crate::error::{DCMIError, DCMIResult};
+use crate::query::{CardQuery, ChipQuery, DcmiApi};
+use crate::structs::{ChipInfo, ECCInfo, HBMInfo, MemoryInfo, PCIEInfo, ProcessMemoryInfo};
+use crate::units::{Celsius, Mebibytes, MegaHertz, Watts};
+use std::collections::HashMap;
+
+/// Query of a [`MockChip`] an error can be injected into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MockQuery {
+    ChipInfo,
+    Health,
+    Temperature,
+    Power,
+    Utilization,
+    Frequency,
+    MemoryInfo,
+    HbmInfo,
+    PcieInfo,
+    EccInfo,
+    Processes,
+}
+
+/// Fake chip
+///
+/// Values which are `None` or missing from a map read as [`DCMIError::NotSupport`], like queries
+/// a real chip does not implement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockChip {
+    pub card_id: u32,
+    pub id: u32,
+    pub chip_info: ChipInfo,
+    pub health: HealthState,
+    pub temperature: Celsius,
+    pub power: Watts,
+    pub utilization: HashMap<UtilizationType, u32>,
+    pub frequency: HashMap<FrequencyType, MegaHertz>,
+    pub memory: Option<MemoryInfo>,
+    pub hbm: Option<HBMInfo>,
+    pub pcie: PCIEInfo,
+    pub ecc: HashMap<DeviceType, ECCInfo>,
+    pub processes: Vec<ProcessMemoryInfo>,
+    /// Errors returned instead of the values above
+    pub faults: HashMap<MockQuery, DCMIError>,
+}
+
+impl MockChip {
+    /// Idle, healthy Ascend 910B3 with 64 GiB of HBM
+    pub fn new(card_id: u32, id: u32) -> Self {
+        MockChip {
+            card_id,
+            id,
+            chip_info: ChipInfo {
+                chip_type: "Ascend".to_string(),
+                chip_name: "910B3".to_string(),
+                chip_version: "V1".to_string(),
+                aicore_count: 20,
+            },
+            health: HealthState::Normal,
+            temperature: Celsius(40),
+            power: Watts(90.0),
+            utilization: HashMap::from([(UtilizationType::AiCore, 0)]),
+            frequency: HashMap::from([
+                (FrequencyType::AiCoreCurrent, MegaHertz(1800)),
+                (FrequencyType::AiCoreMax, MegaHertz(1800)),
+            ]),
+            memory: None,
+            hbm: Some(HBMInfo {
+                memory_size: Mebibytes(65536),
+                freq: MegaHertz(1600),
+                memory_usage: Mebibytes(0),
+                temp: Celsius(40),
+                bandwidth_util_rate: 0,
+            }),
+            pcie: PCIEInfo {
+                vender_id: 0x19e5,
+                subvender_id: 0x0200,
+                device_id: 0xd802,
+                subdevice_id: 0x3000,
+                domain: 0,
+                bdf_bus_id: card_id + 1,
+                bdf_device_id: 0,
+                bdf_func_id: id,
+            },
+            ecc: HashMap::from([(
+                DeviceType::HBM,
+                ECCInfo {
+                    enable: true,
+                    single_bit_error_cnt: 0,
+                    double_bit_error_cnt: 0,
+                    total_single_bit_error_cnt: 0,
+                    total_double_bit_error_cnt: 0,
+                    single_bit_isolated_pages_cnt: 0,
+                    double_bit_isolated_pages_cnt: 0,
+                },
+            )]),
+            processes: Vec::new(),
+            faults: HashMap::new(),
+        }
+    }
+
+    pub fn chip_info(mut self, chip_info: ChipInfo) -> Self {
+        self.chip_info = chip_info;
+        self
+    }
+
+    pub fn health(mut self, health: HealthState) -> Self {
+        self.health = health;
+        self
+    }
+
+    pub fn temperature(mut self, temperature: Celsius) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn power(mut self, power: Watts) -> Self {
+        self.power = power;
+        self
+    }
+
+    pub fn utilization(mut self, utilization_type: UtilizationType, rate: u32) -> Self {
+        self.utilization.insert(utilization_type, rate);
+        self
+    }
+
+    pub fn frequency(mut self, frequency_type: FrequencyType, frequency: MegaHertz) -> Self {
+        self.frequency.insert(frequency_type, frequency);
+        self
+    }
+
+    pub fn memory(mut self, memory: Option<MemoryInfo>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    pub fn hbm(mut self, hbm: Option<HBMInfo>) -> Self {
+        self.hbm = hbm;
+        self
+    }
+
+    pub fn ecc(mut self, device_type: DeviceType, ecc: ECCInfo) -> Self {
+        self.ecc.insert(device_type, ecc);
+        self
+    }
+
+    pub fn process(mut self, process: ProcessMemoryInfo) -> Self {
+        self.processes.push(process);
+        self
+    }
+
+    /// Make `query` return `error` until the fault is removed from [`MockChip::faults`]
+    pub fn fail(mut self, query: MockQuery, error: DCMIError) -> Self {
+        self.faults.insert(query, error);
+        self
+    }
+
+    fn read<T>(&self, query: MockQuery, value: Option<T>) -> DCMIResult<T> {
+        match self.faults.get(&query) {
+            Some(error) => Err(error.clone()),
+            None => value.ok_or(DCMIError::NotSupport),
+        }
+    }
+}
+
+impl ChipQuery for MockChip {
+    fn card_id(&self) -> u32 {
+        self.card_id
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn get_chip_info(&self) -> DCMIResult<ChipInfo> {
+        self.read(MockQuery::ChipInfo, Some(self.chip_info.clone()))
+    }
+
+    fn get_health(&self) -> DCMIResult<HealthState> {
+        self.read(MockQuery::Health, Some(self.health))
+    }
+
+    fn get_temperature(&self) -> DCMIResult<Celsius> {
+        self.read(MockQuery::Temperature, Some(self.temperature))
+    }
+
+    fn get_power_info(&self) -> DCMIResult<Watts> {
+        self.read(MockQuery::Power, Some(self.power))
+    }
+
+    fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32> {
+        let rate = self.utilization.get(&utilization_type).copied();
+        self.read(MockQuery::Utilization, rate)
+    }
+
+    fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz> {
+        let frequency = self.frequency.get(&frequency_type).copied();
+        self.read(MockQuery::Frequency, frequency)
+    }
+
+    fn get_memory_info(&self) -> DCMIResult<MemoryInfo> {
+        self.read(MockQuery::MemoryInfo, self.memory)
+    }
+
+    fn get_hbm_info(&self) -> DCMIResult<HBMInfo> {
+        self.read(MockQuery::HbmInfo, self.hbm)
+    }
+
+    fn get_pcie_info(&self) -> DCMIResult<PCIEInfo> {
+        self.read(MockQuery::PcieInfo, Some(self.pcie))
+    }
+
+    fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo> {
+        self.read(MockQuery::EccInfo, self.ecc.get(&device_type).copied())
+    }
+
+    fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>> {
+        self.read(MockQuery::Processes, Some(self.processes.clone()))
+    }
+}
+
+/// Card of a [`MockDcmi`], made up of the chips with its card id
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCard<'a> {
+    id: u32,
+    chips: Vec<&'a MockChip>,
+}
+
+impl<'a> CardQuery for MockCard<'a> {
+    type Chip = &'a MockChip;
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn get_chip_num(&self) -> DCMIResult<u32> {
+        Ok(self.chips.len() as u32)
+    }
+
+    fn get_chips(&self) -> DCMIResult<Vec<&'a MockChip>> {
+        Ok(self.chips.clone())
+    }
+}
+
+/// Fake node
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockDcmi {
+    pub dcmi_version: String,
+    pub driver_version: String,
+    /// NPUs of all cards, in the order [`DcmiApi::all_chips`] returns them
+    pub chips: Vec<MockChip>,
+}
+
+impl Default for MockDcmi {
+    fn default() -> Self {
+        MockDcmi {
+            dcmi_version: "24.1.rc2".to_string(),
+            driver_version: "24.1.rc2".to_string(),
+            chips: Vec::new(),
+        }
+    }
+}
+
+impl MockDcmi {
+    /// Node without chips
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_chip(mut self, chip: MockChip) -> Self {
+        self.chips.push(chip);
+        self
+    }
+
+    /// Chip to change between reads
+    pub fn chip_mut(&mut self, card_id: u32, chip_id: u32) -> Option<&mut MockChip> {
+        self.chips
+            .iter_mut()
+            .find(|chip| chip.card_id == card_id && chip.id == chip_id)
+    }
+}
+
+impl DcmiApi for MockDcmi {
+    type Card<'a> = MockCard<'a>;
+    type Chip<'a> = &'a MockChip;
+
+    fn get_dcmi_version(&self) -> DCMIResult<String> {
+        Ok(self.dcmi_version.clone())
+    }
+
+    fn get_driver_version(&self) -> DCMIResult<String> {
+        Ok(self.driver_version.clone())
+    }
+
+    /// Cards in ascending order of their ids
+    fn get_card_list(&self) -> DCMIResult<Vec<MockCard<'_>>> {
+        let mut ids: Vec<u32> = self.chips.iter().map(|chip| chip.card_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        Ok(ids
+            .into_iter()
+            .map(|id| MockCard {
+                id,
+                chips: self
+                    .chips
+                    .iter()
+                    .filter(|chip| chip.card_id == id)
+                    .collect(),
+            })
+            .collect())
+    }
+
+    fn all_chips(&self) -> DCMIResult<Vec<&MockChip>> {
+        Ok(self.chips.iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::Metric;
+
+    #[test]
+    fn unset_values_are_not_supported() {
+        let chip = MockChip::new(0, 1)
+            .hbm(None)
+            .utilization(UtilizationType::AiCore, 75)
+            .fail(MockQuery::Temperature, DCMIError::CodeTimeOut);
+        assert_eq!(chip.get_hbm_info(), Err(DCMIError::NotSupport));
+        assert_eq!(Metric::HbmUsage.read(&chip), Err(DCMIError::NotSupport));
+        assert_eq!(Metric::AiCoreUtilization.read(&chip), Ok(75.0));
+        assert_eq!(chip.get_temperature(), Err(DCMIError::CodeTimeOut));
+        assert_eq!(chip.get_pcie_info().unwrap().bdf(), "0000:01:00.1");
+
+        let dcmi = MockDcmi::new()
+            .with_chip(MockChip::new(1, 0))
+            .with_chip(chip)
+            .with_chip(MockChip::new(0, 0));
+        assert_eq!(dcmi.all_chips().unwrap().len(), 3);
+        let cards = dcmi.get_card_list().unwrap();
+        assert_eq!(
+            cards.iter().map(|card| card.id()).collect::<Vec<_>>(),
+            [0, 1]
+        );
+        assert_eq!(cards[0].get_chip_num(), Ok(2));
+        assert_eq!(dcmi.get_driver_version().unwrap(), "24.1.rc2");
+    }
+}
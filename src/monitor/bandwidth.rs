@@ -0,0 +1,174 @@
+use crate::enums::UtilizationType;
+use crate::error::DCMIResult;
+use crate::query::ChipQuery;
+use crate::units::MegaHertz;
+use crate::utils::impl_as_str;
+use std::time::{Duration, Instant};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Memory whose bandwidth is analyzed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum MemoryKind {
+    Ddr,
+    Hbm,
+}
+
+/// Bandwidth utilization and memory frequency read at the same time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandwidthSample {
+    pub at: Instant,
+    /// unit: %
+    pub utilization: u32,
+    pub freq: MegaHertz,
+}
+
+impl BandwidthSample {
+    /// Read the bandwidth utilization and frequency of a memory of the chip
+    pub fn read(chip: &impl ChipQuery, memory: MemoryKind) -> DCMIResult<Self> {
+        let (utilization, freq) = match memory {
+            MemoryKind::Ddr => (
+                chip.get_utilization_rate(UtilizationType::MemoryBandwidth)?,
+                chip.get_memory_info()?.freq,
+            ),
+            MemoryKind::Hbm => {
+                let hbm = chip.get_hbm_info()?;
+                (hbm.bandwidth_util_rate, hbm.freq)
+            }
+        };
+        Ok(BandwidthSample {
+            at: Instant::now(),
+            utilization,
+            freq,
+        })
+    }
+}
+
+/// Bandwidth estimated from a [`BandwidthSample`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandwidthEstimate {
+    /// unit: GB/s
+    pub bandwidth: f64,
+    /// Peak bandwidth at the sampled frequency, unit: GB/s
+    pub peak_bandwidth: f64,
+    /// unit: %
+    pub utilization: u32,
+    /// Start of the saturation window if utilization stayed above the threshold for at least the
+    /// configured duration
+    pub saturated_since: Option<Instant>,
+}
+
+/// Turns bandwidth utilization into absolute bandwidth and flags sustained saturation
+///
+/// DCMI reports bandwidth as a utilization percentage only. The absolute bandwidth is estimated
+/// as `utilization × frequency × bytes per cycle`, where bytes per cycle depend on the memory
+/// bus of the product and must be provided by the caller.
+///
+/// ```no_run
+/// # use hw_dcmi::DCMI;
+/// # use hw_dcmi::monitor::{BandwidthAnalyzer, BandwidthSample, MemoryKind};
+/// # use std::time::Duration;
+/// # let dcmi = DCMI::init().unwrap();
+/// # let chip = dcmi.all_chips().unwrap()[0];
+/// // 4 HBM stacks with a 1024 bit bus at double data rate
+/// let mut analyzer = BandwidthAnalyzer::new(4.0 * 128.0 * 2.0)
+///     .saturation_threshold(90)
+///     .sustained_for(Duration::from_secs(30));
+/// loop {
+///     let estimate = analyzer.update(BandwidthSample::read(&chip, MemoryKind::Hbm).unwrap());
+///     if estimate.saturated_since.is_some() {
+///         println!("HBM saturated at {:.0} GB/s", estimate.bandwidth);
+///     }
+///     std::thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BandwidthAnalyzer {
+    bytes_per_cycle: f64,
+    saturation_threshold: u32,
+    sustained_for: Duration,
+    above_since: Option<Instant>,
+}
+
+impl BandwidthAnalyzer {
+    /// # Parameters
+    /// - bytes_per_cycle: bytes transferred per memory clock cycle over all channels
+    pub fn new(bytes_per_cycle: f64) -> Self {
+        BandwidthAnalyzer {
+            bytes_per_cycle,
+            saturation_threshold: 90,
+            sustained_for: Duration::from_secs(10),
+            above_since: None,
+        }
+    }
+
+    /// Utilization from which the memory counts as saturated, unit: %, default 90
+    pub fn saturation_threshold(mut self, threshold: u32) -> Self {
+        self.saturation_threshold = threshold;
+        self
+    }
+
+    /// How long the utilization must stay above the threshold to be flagged, default 10s
+    pub fn sustained_for(mut self, duration: Duration) -> Self {
+        self.sustained_for = duration;
+        self
+    }
+
+    /// Record a sample
+    pub fn update(&mut self, sample: BandwidthSample) -> BandwidthEstimate {
+        if sample.utilization >= self.saturation_threshold {
+            self.above_since.get_or_insert(sample.at);
+        } else {
+            self.above_since = None;
+        }
+        let saturated_since = self.above_since.filter(|&since| {
+            sample
+                .at
+                .checked_duration_since(since)
+                .is_some_and(|elapsed| elapsed >= self.sustained_for)
+        });
+        // MHz × bytes per cycle = MB/s
+        let peak_bandwidth = f64::from(sample.freq) * self.bytes_per_cycle / 1000.0;
+        BandwidthEstimate {
+            bandwidth: peak_bandwidth * sample.utilization as f64 / 100.0,
+            peak_bandwidth,
+            utilization: sample.utilization,
+            saturated_since,
+        }
+    }
+}
+
+impl_as_str!(MemoryKind);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_sustained_saturation_only() {
+        let start = Instant::now();
+        let sample = |secs, utilization| BandwidthSample {
+            at: start + Duration::from_secs(secs),
+            utilization,
+            freq: MegaHertz(1600),
+        };
+        let mut analyzer = BandwidthAnalyzer::new(1024.0)
+            .saturation_threshold(90)
+            .sustained_for(Duration::from_secs(20));
+
+        let estimate = analyzer.update(sample(0, 50));
+        assert!((estimate.peak_bandwidth - 1638.4).abs() < 1e-9);
+        assert!((estimate.bandwidth - 819.2).abs() < 1e-9);
+        assert!(estimate.saturated_since.is_none());
+
+        assert!(analyzer.update(sample(10, 95)).saturated_since.is_none());
+        assert!(analyzer.update(sample(20, 92)).saturated_since.is_none());
+        assert_eq!(
+            analyzer.update(sample(30, 99)).saturated_since,
+            Some(start + Duration::from_secs(10))
+        );
+        // a dip below the threshold ends the window
+        assert!(analyzer.update(sample(40, 80)).saturated_since.is_none());
+        assert!(analyzer.update(sample(50, 95)).saturated_since.is_none());
+    }
+}
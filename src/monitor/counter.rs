@@ -0,0 +1,143 @@
+//! Increases and rates of counters read periodically, e.g. error counts or transferred bytes,
+//! which roll over or are reset between readings
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// Meaning of a counter reading lower than the previous one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rollover {
+    /// The counter overflowed its range of `bits` bits and continued from 0, e.g. 32 for a `u32`
+    /// hardware counter
+    ///
+    /// A reading outside the range cannot come from such a counter, it is taken as a reset.
+    Wrap { bits: u32 },
+    /// The statistics were cleared and the counter restarted at 0, e.g. ECC and PCIe error counts
+    Reset,
+}
+
+impl Rollover {
+    /// Increase of a counter from `last` to `current`
+    ///
+    /// A wrapped counter counts up to its maximum and on from 0, a reset counter counts from 0.
+    pub fn delta(self, last: u64, current: u64) -> u64 {
+        match self {
+            Rollover::Wrap { bits } => {
+                let max = u64::MAX.checked_shr(64 - bits.min(64)).unwrap_or(0);
+                if last > max || current > max {
+                    return Rollover::Reset.delta(last, current);
+                }
+                current.wrapping_sub(last) & max
+            }
+            Rollover::Reset if current >= last => current - last,
+            Rollover::Reset => current,
+        }
+    }
+}
+
+/// Reading of a counter-type metric, e.g. an error count or transferred bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterSample {
+    pub at: Instant,
+    pub value: u64,
+}
+
+impl CounterSample {
+    /// Counter read now
+    pub fn now(value: impl Into<u64>) -> Self {
+        CounterSample {
+            at: Instant::now(),
+            value: value.into(),
+        }
+    }
+
+    /// Increase since `previous`
+    pub fn delta(&self, previous: &CounterSample, rollover: Rollover) -> u64 {
+        rollover.delta(previous.value, self.value)
+    }
+
+    /// Increase per second since `previous`, `None` if no time elapsed
+    pub fn rate(&self, previous: &CounterSample, rollover: Rollover) -> Option<f64> {
+        let secs = self.at.checked_duration_since(previous.at)?.as_secs_f64();
+        (secs > 0.0).then(|| self.delta(previous, rollover) as f64 / secs)
+    }
+}
+
+/// Rates of many counters, each compared with its previous reading
+///
+/// Counters are identified by a key, e.g. `(card, chip, "ecc_double_bit")`.
+///
+/// ```
+/// use hw_dcmi::monitor::{CounterRates, CounterSample, Rollover};
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// let mut rates = CounterRates::new(Rollover::Wrap { bits: 32 });
+/// let sample = |secs, value| CounterSample { at: start + Duration::from_secs(secs), value };
+/// assert_eq!(rates.update("rx_bytes", sample(0, 4_294_967_000)), None);
+/// assert_eq!(rates.update("rx_bytes", sample(2, 200)), Some(248.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CounterRates<K> {
+    rollover: Rollover,
+    last: HashMap<K, CounterSample>,
+}
+
+impl<K: Eq + Hash> CounterRates<K> {
+    /// Track counters which roll over as `rollover`
+    pub fn new(rollover: Rollover) -> Self {
+        CounterRates {
+            rollover,
+            last: HashMap::new(),
+        }
+    }
+
+    /// Record a reading of the counter `key`
+    ///
+    /// # Returns
+    /// increase per second since the previous reading of the counter, `None` for its first
+    /// reading or if no time elapsed
+    pub fn update(&mut self, key: K, sample: CounterSample) -> Option<f64> {
+        let previous = self.last.insert(key, sample)?;
+        sample.rate(&previous, self.rollover)
+    }
+
+    /// Forget a counter, e.g. of a removed chip
+    pub fn remove(&mut self, key: &K) {
+        self.last.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn handles_wrap_and_reset() {
+        assert_eq!(Rollover::Reset.delta(10, 15), 5);
+        assert_eq!(Rollover::Reset.delta(10, 3), 3);
+        assert_eq!(Rollover::Wrap { bits: 16 }.delta(65530, 4), 10);
+        assert_eq!(Rollover::Wrap { bits: 64 }.delta(u64::MAX, 0), 1);
+        assert_eq!(Rollover::Wrap { bits: 64 }.delta(3, 10), 7);
+        // readings outside the range of the counter are resets
+        assert_eq!(Rollover::Wrap { bits: 16 }.delta(1 << 16, 4), 4);
+        assert_eq!(Rollover::Wrap { bits: 16 }.delta(4, 1 << 20), (1 << 20) - 4);
+        assert_eq!(Rollover::Wrap { bits: 0 }.delta(0, 0), 0);
+        assert_eq!(Rollover::Wrap { bits: 0 }.delta(5, 2), 2);
+
+        let start = Instant::now();
+        let first = CounterSample {
+            at: start,
+            value: 7,
+        };
+        let later = CounterSample {
+            at: start + Duration::from_millis(500),
+            value: 8,
+        };
+        assert_eq!(later.rate(&first, Rollover::Reset), Some(2.0));
+        assert_eq!(first.rate(&first, Rollover::Reset), None);
+        assert_eq!(first.rate(&later, Rollover::Reset), None);
+    }
+}
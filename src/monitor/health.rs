@@ -0,0 +1,246 @@
+use super::sampler::{next_round, push_bounded, wait_for_round};
+use super::{Alert, Metric, Rule, RuleEngine};
+use crate::device::OwnedChip;
+use crate::event::{self, FaultEvent};
+use crate::query::ChipQuery;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+type Callback = Box<dyn FnMut(&Alert) + Send>;
+
+/// Settings of a [`HealthMonitor`]
+pub struct HealthMonitorConfig {
+    interval: Duration,
+    rules: Vec<Rule>,
+    callbacks: Vec<Callback>,
+    capacity: usize,
+}
+
+impl fmt::Debug for HealthMonitorConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HealthMonitorConfig")
+            .field("interval", &self.interval)
+            .field("rules", &self.rules)
+            .field("callbacks", &self.callbacks.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl HealthMonitorConfig {
+    /// Evaluate the rules every `interval`, by default without rules and with room for 1024
+    /// alerts
+    pub fn new(interval: Duration) -> Self {
+        HealthMonitorConfig {
+            interval,
+            rules: Vec::new(),
+            callbacks: Vec::new(),
+            capacity: 1024,
+        }
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add the rules `chip_overheat` (above 90 °C for a minute), `ecc_double_bit_error` (any new
+    /// double-bit ECC error) and `chip_unhealthy` (any health state but normal)
+    pub fn default_rules(self) -> Self {
+        self.rule(
+            Rule::metric(Metric::Temperature)
+                .above(90)
+                .for_duration(Duration::from_secs(60))
+                .named("chip_overheat"),
+        )
+        .rule(
+            Rule::metric(Metric::EccDoubleBitErrors)
+                .increase_above(0)
+                .named("ecc_double_bit_error"),
+        )
+        .rule(
+            Rule::metric(Metric::Health)
+                .above(0)
+                .named("chip_unhealthy"),
+        )
+    }
+
+    /// Call `callback` with every alert, on the monitoring thread
+    pub fn on_alert(mut self, callback: impl FnMut(&Alert) + Send + 'static) -> Self {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Maximum number of alerts kept for [`HealthMonitor::take_alerts`], the oldest are dropped
+    /// first
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+}
+
+/// Background thread evaluating alert rules against the chips
+///
+/// Alerts are passed to the callbacks, queued for [`HealthMonitor::take_alerts`] and published
+/// as fault events to the streams of [`DCMI::subscribe_fault_events`](crate::DCMI::subscribe_fault_events).
+/// Metrics which cannot be read in a round are skipped. The thread stops when this value is dropped.
+///
+/// ```no_run
+/// # use hw_dcmi::DCMI;
+/// # use hw_dcmi::monitor::{HealthMonitor, HealthMonitorConfig, Metric, Rule};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// let dcmi = Arc::new(DCMI::init().unwrap());
+/// let mut chips = Vec::new();
+/// for card in dcmi.owned_cards().unwrap() {
+///     chips.extend(card.get_chips().unwrap());
+/// }
+/// let config = HealthMonitorConfig::new(Duration::from_secs(10))
+///     .default_rules()
+///     .rule(Rule::metric(Metric::Power).above(350).named("power_high"))
+///     .on_alert(|alert| eprintln!("{} {} on {}/{}", alert.rule, alert.state, alert.card_id, alert.chip_id));
+/// let monitor = HealthMonitor::spawn(chips, config).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct HealthMonitor {
+    alerts: Arc<Mutex<VecDeque<Alert>>>,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl HealthMonitor {
+    /// Start monitoring the chips
+    pub fn spawn(chips: Vec<OwnedChip>, config: HealthMonitorConfig) -> io::Result<Self> {
+        let HealthMonitorConfig {
+            interval,
+            rules,
+            mut callbacks,
+            capacity,
+        } = config;
+        let mut engine = RuleEngine::new(rules);
+        let alerts = Arc::new(Mutex::new(VecDeque::new()));
+        let thread_alerts = alerts.clone();
+        // alerts of chips whose logic id cannot be read are published with device id u16::MAX
+        let logic_ids: Vec<_> = chips
+            .iter()
+            .map(|chip| {
+                let chip = chip.chip();
+                let logic_id = event::logic_id(chip.card_id(), chip.id());
+                (
+                    (chip.card_id(), chip.id()),
+                    logic_id.map_or(u16::MAX, |id| id as u16),
+                )
+            })
+            .collect();
+        let (stop, stop_rx) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("dcmi-health".to_string())
+            .spawn(move || {
+                let start = Instant::now();
+                let mut round = 0u32;
+                loop {
+                    let chips: Vec<_> = chips.iter().map(OwnedChip::chip).collect();
+                    let new_alerts = evaluate(&chips, &mut engine);
+                    for alert in &new_alerts {
+                        for callback in &mut callbacks {
+                            callback(alert);
+                        }
+                        let device_id = logic_ids
+                            .iter()
+                            .find(|(chip, _)| *chip == (alert.card_id, alert.chip_id))
+                            .map_or(u16::MAX, |&(_, logic_id)| logic_id);
+                        event::publish(FaultEvent::from_alert(alert, device_id));
+                    }
+                    push_bounded(
+                        &mut thread_alerts.lock().unwrap_or_else(|e| e.into_inner()),
+                        new_alerts,
+                        capacity,
+                    );
+
+                    round = next_round(start, interval, round, Instant::now());
+                    if wait_for_round(&stop_rx, start, interval, round) {
+                        break;
+                    }
+                }
+            })?;
+        Ok(HealthMonitor {
+            alerts,
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+
+    /// Remove and return the queued alerts, oldest first
+    pub fn take_alerts(&self) -> Vec<Alert> {
+        let mut alerts = self.alerts.lock().unwrap_or_else(|e| e.into_inner());
+        alerts.drain(..).collect()
+    }
+}
+
+impl Drop for HealthMonitor {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Read the metrics of the rules from every chip and evaluate them
+fn evaluate(chips: &[impl ChipQuery], engine: &mut RuleEngine) -> Vec<Alert> {
+    let metrics = engine.metrics();
+    let mut alerts = Vec::new();
+    for chip in chips {
+        for &metric in &metrics {
+            if let Ok(value) = metric.read(chip) {
+                let at = Instant::now();
+                alerts.extend(engine.evaluate(chip.card_id(), chip.id(), metric, value, at));
+            }
+        }
+    }
+    alerts
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::enums::{DeviceType, HealthState};
+    use crate::error::DCMIError;
+    use crate::mock::{MockChip, MockQuery};
+    use crate::monitor::AlertState;
+
+    #[test]
+    fn default_rules_alert_on_health_and_ecc() {
+        let config = HealthMonitorConfig::new(Duration::from_secs(1)).default_rules();
+        let mut engine = RuleEngine::new(config.rules);
+        let mut chips = vec![
+            MockChip::new(0, 0),
+            MockChip::new(1, 0).fail(MockQuery::Temperature, DCMIError::CodeTimeOut),
+        ];
+        assert!(evaluate(&chips, &mut engine).is_empty());
+
+        chips[0].health = HealthState::MajorAlarm;
+        chips[1]
+            .ecc
+            .get_mut(&DeviceType::HBM)
+            .unwrap()
+            .double_bit_error_cnt = 1;
+        let alerts = evaluate(&chips, &mut engine);
+        let fired: Vec<_> = alerts
+            .iter()
+            .map(|alert| (alert.rule.as_str(), alert.card_id, alert.state))
+            .collect();
+        assert_eq!(
+            fired,
+            [
+                ("chip_unhealthy", 0, AlertState::Firing),
+                ("ecc_double_bit_error", 1, AlertState::Firing)
+            ]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Chip metrics emitted through the [`metrics`](::metrics) facade
+
+use super::Metric;
+use crate::error::DCMIResult;
+use crate::query::{ChipQuery, DcmiApi};
+use std::io;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use strum::IntoEnumIterator;
+
+/// Prefix of the names of all emitted metrics
+const METRIC_PREFIX: &str = "hw_dcmi_chip_";
+/// Counter of failed metric reads, labelled with the metric and the error kind
+const READ_ERRORS: &str = "hw_dcmi_read_errors_total";
+
+/// Record every [`Metric`] of every NPU once
+///
+/// Values are set as gauges named `hw_dcmi_chip_<metric>` (e.g. `hw_dcmi_chip_temperature`)
+/// with `card` and `chip` labels. A failed read does not stop the others, it increments
+/// `hw_dcmi_read_errors_total` labelled with the metric and [`DCMIError::metric_label`].
+///
+/// [`DCMIError::metric_label`]: crate::error::DCMIError::metric_label
+pub fn record(dcmi: &impl DcmiApi) -> DCMIResult<()> {
+    for chip in dcmi.all_chips()? {
+        let card_id = chip.card_id().to_string();
+        let chip_id = chip.id().to_string();
+        for metric in Metric::iter() {
+            match metric.read(&chip) {
+                Ok(value) => ::metrics::gauge!(
+                    format!("{METRIC_PREFIX}{metric}"),
+                    "card" => card_id.clone(),
+                    "chip" => chip_id.clone()
+                )
+                .set(value),
+                Err(e) => ::metrics::counter!(
+                    READ_ERRORS,
+                    "metric" => metric.as_str(),
+                    "error" => e.metric_label()
+                )
+                .increment(1),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Background thread calling [`record`] at a fixed interval
+///
+/// Metrics go to whichever recorder is installed in the `metrics` facade. The thread stops when
+/// this value is dropped.
+///
+/// ```no_run
+/// # use hw_dcmi::DCMI;
+/// # use hw_dcmi::monitor::MetricsReporter;
+/// # use std::time::Duration;
+/// // install a recorder first, e.g. metrics_exporter_prometheus::PrometheusBuilder
+/// let reporter = MetricsReporter::install(DCMI::init().unwrap(), Duration::from_secs(15)).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MetricsReporter {
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MetricsReporter {
+    /// Start recording the metrics of all chips every `interval`
+    pub fn install(dcmi: impl DcmiApi + Send + 'static, interval: Duration) -> io::Result<Self> {
+        let (stop, stop_rx) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("dcmi-metrics".to_string())
+            .spawn(move || loop {
+                // listing the cards fails while the driver restarts, retry on the next round
+                let _ = record(&dcmi);
+                if stop_rx.recv_timeout(interval) != Err(RecvTimeoutError::Timeout) {
+                    break;
+                }
+            })?;
+        Ok(MetricsReporter {
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+}
+
+impl Drop for MetricsReporter {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
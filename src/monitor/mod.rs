@@ -0,0 +1,23 @@
+//! Helpers for long-running monitoring of chips
+
+mod bandwidth;
+mod counter;
+mod health;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod pcie;
+mod rules;
+mod sampler;
+#[cfg(all(unix, feature = "thread-tuning"))]
+mod thread;
+
+pub use bandwidth::{BandwidthAnalyzer, BandwidthEstimate, BandwidthSample, MemoryKind};
+pub use counter::{CounterRates, CounterSample, Rollover};
+pub use health::{HealthMonitor, HealthMonitorConfig};
+#[cfg(feature = "metrics")]
+pub use metrics::{record as record_metrics, MetricsReporter};
+pub use pcie::{PcieErrorRates, PcieLinkTracker};
+pub use rules::{Alert, AlertState, Condition, Metric, Rule, RuleEngine};
+pub use sampler::{Sample, Sampler, SamplerConfig};
+#[cfg(all(unix, feature = "thread-tuning"))]
+pub use thread::ThreadTuning;
@@ -0,0 +1,118 @@
+use super::Rollover;
+use crate::structs::PCIEErrorInfo;
+use std::time::Instant;
+
+/// Per-second rates of the PCIe error counters between two samples
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PcieErrorRates {
+    pub lcrc_per_sec: f64,
+    pub dcrc_per_sec: f64,
+    pub pcs_rx_per_sec: f64,
+    pub phy_lane_per_sec: f64,
+    pub symbol_unlock_per_sec: f64,
+}
+
+impl PcieErrorRates {
+    /// Total errors per minute over all counters
+    pub fn errors_per_minute(&self) -> f64 {
+        (self.lcrc_per_sec
+            + self.dcrc_per_sec
+            + self.pcs_rx_per_sec
+            + self.phy_lane_per_sec
+            + self.symbol_unlock_per_sec)
+            * 60.0
+    }
+
+    /// Link quality score in `(0, 100]`
+    ///
+    /// `100 / (1 + errors per minute)`: 100 for a clean link, 50 at one error per minute, close
+    /// to 0 for a link producing errors continuously. DCMI does not expose PCIe replay or retry
+    /// counters, so the score is built from the LCRC/DCRC, PCS, PHY lane and symbol unlock
+    /// counters.
+    pub fn link_quality_score(&self) -> f64 {
+        100.0 / (1.0 + self.errors_per_minute())
+    }
+}
+
+/// Tracks the PCIe error counters of a chip and turns them into rates
+///
+/// ```no_run
+/// # use hw_dcmi::DCMI;
+/// # use hw_dcmi::monitor::PcieLinkTracker;
+/// # use std::time::{Duration, Instant};
+/// # let dcmi = DCMI::init().unwrap();
+/// # let chip = dcmi.all_chips().unwrap()[0];
+/// let mut tracker = PcieLinkTracker::new();
+/// loop {
+///     let info = chip.get_pcie_error_info().unwrap();
+///     if let Some(rates) = tracker.update(Instant::now(), info) {
+///         println!("link quality: {:.1}", rates.link_quality_score());
+///     }
+///     std::thread::sleep(Duration::from_secs(10));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PcieLinkTracker {
+    last: Option<(Instant, PCIEErrorInfo)>,
+}
+
+impl PcieLinkTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a sample
+    ///
+    /// # Returns
+    /// rates since the previous sample, `None` for the first sample or if no time elapsed
+    pub fn update(&mut self, at: Instant, info: PCIEErrorInfo) -> Option<PcieErrorRates> {
+        let previous = self.last.replace((at, info));
+        let (last_at, last) = previous?;
+        let secs = at.checked_duration_since(last_at)?.as_secs_f64();
+        if secs <= 0.0 {
+            return None;
+        }
+        // a counter going backwards means the statistics were cleared
+        let rate = |current: u32, last: u32| {
+            Rollover::Reset.delta(last.into(), current.into()) as f64 / secs
+        };
+        Some(PcieErrorRates {
+            lcrc_per_sec: rate(info.dl_lcrc_err_num, last.dl_lcrc_err_num),
+            dcrc_per_sec: rate(info.dl_dcrc_err_num, last.dl_dcrc_err_num),
+            pcs_rx_per_sec: rate(info.pcs_rx_err_cnt, last.pcs_rx_err_cnt),
+            phy_lane_per_sec: rate(info.phy_lane_err_counter, last.phy_lane_err_counter),
+            symbol_unlock_per_sec: rate(info.symbol_unlock_counter, last.symbol_unlock_counter),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn tracker_computes_rates_and_score() {
+        let start = Instant::now();
+        let mut tracker = PcieLinkTracker::new();
+        let mut info = PCIEErrorInfo {
+            dl_lcrc_err_num: 10,
+            ..Default::default()
+        };
+        assert!(tracker.update(start, info).is_none());
+
+        info.dl_lcrc_err_num = 11;
+        let rates = tracker
+            .update(start + Duration::from_secs(60), info)
+            .unwrap();
+        assert!((rates.errors_per_minute() - 1.0).abs() < 1e-9);
+        assert!((rates.link_quality_score() - 50.0).abs() < 1e-9);
+
+        // counters were cleared
+        info.dl_lcrc_err_num = 0;
+        let rates = tracker
+            .update(start + Duration::from_secs(120), info)
+            .unwrap();
+        assert_eq!(rates.link_quality_score(), 100.0);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::enums::{DeviceType, HealthState, UtilizationType};
+use crate::error::{DCMIError, DCMIResult};
+use crate::query::ChipQuery;
+use crate::utils::impl_as_str;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Chip metric that rules can be written against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Metric {
+    /// Chip temperature, unit: °C
+    Temperature,
+    /// HBM temperature, unit: °C
+    HbmTemperature,
+    /// Power consumption, unit: W
+    Power,
+    /// AI core utilization, unit: %
+    AiCoreUtilization,
+    /// DDR memory utilization, unit: %
+    MemoryUtilization,
+    /// Used share of the HBM, unit: %
+    HbmUsage,
+    /// Health level: 0 normal, 1 minor, 2 major, 3 critical alarm, 4 not existing or unknown
+    Health,
+    /// Double-bit ECC errors of the DDR and HBM since the last statistics reset
+    EccDoubleBitErrors,
+}
+
+impl Metric {
+    /// Read the current value of the metric from a chip
+    pub fn read(&self, chip: &impl ChipQuery) -> DCMIResult<f64> {
+        Ok(match self {
+            Metric::Temperature => chip.get_temperature()?.into(),
+            Metric::HbmTemperature => chip.get_hbm_info()?.temp.into(),
+            Metric::Power => chip.get_power_info()?.into(),
+            Metric::AiCoreUtilization => chip.get_utilization_rate(UtilizationType::AiCore)? as f64,
+            Metric::MemoryUtilization => chip.get_memory_info()?.utilization as f64,
+            Metric::HbmUsage => {
+                let hbm = chip.get_hbm_info()?;
+                hbm.memory_usage.percent_of(hbm.memory_size)
+            }
+            Metric::Health => match chip.get_health()? {
+                HealthState::Normal => 0.0,
+                HealthState::MinorAlarm => 1.0,
+                HealthState::MajorAlarm => 2.0,
+                HealthState::CriticalAlarm => 3.0,
+                HealthState::NotExist | HealthState::Unknown(_) => 4.0,
+            },
+            Metric::EccDoubleBitErrors => {
+                // a chip has DDR or HBM, or both
+                let mut errors = None;
+                for device_type in [DeviceType::DDR, DeviceType::HBM] {
+                    match chip.get_ecc_info(device_type) {
+                        Ok(ecc) => {
+                            *errors.get_or_insert(0.0) += f64::from(ecc.double_bit_error_cnt)
+                        }
+                        Err(e) if e.kind() == &DCMIError::NotSupport => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                errors.ok_or(DCMIError::NotSupport)?
+            }
+        })
+    }
+}
+
+/// Condition a metric value is checked against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Above(f64),
+    Below(f64),
+    /// The value rose by more than the threshold since the previous sample, e.g. for error
+    /// counters
+    IncreaseAbove(f64),
+}
+
+impl Condition {
+    pub fn holds(&self, value: f64) -> bool {
+        self.holds_since(None, value)
+    }
+
+    /// Whether the condition holds for `value`, `previous` is the value of the previous sample
+    pub fn holds_since(&self, previous: Option<f64>, value: f64) -> bool {
+        match *self {
+            Condition::Above(threshold) => value > threshold,
+            Condition::Below(threshold) => value < threshold,
+            Condition::IncreaseAbove(threshold) => {
+                previous.is_some_and(|previous| value - previous > threshold)
+            }
+        }
+    }
+}
+
+/// Alert rule on a chip metric
+///
+/// ```
+/// use hw_dcmi::monitor::{Metric, Rule};
+/// use std::time::Duration;
+///
+/// let rule = Rule::metric(Metric::Temperature)
+///     .above(90)
+///     .for_duration(Duration::from_secs(60))
+///     .named("chip_overheat");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    pub metric: Metric,
+    pub condition: Condition,
+    /// How long the condition must hold before the alert fires
+    pub duration: Duration,
+}
+
+impl Rule {
+    /// Start building a rule on `metric`, which fires as soon as the metric is above 0
+    pub fn metric(metric: Metric) -> Self {
+        Rule {
+            name: metric.as_str().to_string(),
+            metric,
+            condition: Condition::Above(0.0),
+            duration: Duration::ZERO,
+        }
+    }
+
+    pub fn above(mut self, threshold: impl Into<f64>) -> Self {
+        self.condition = Condition::Above(threshold.into());
+        self
+    }
+
+    pub fn below(mut self, threshold: impl Into<f64>) -> Self {
+        self.condition = Condition::Below(threshold.into());
+        self
+    }
+
+    pub fn increase_above(mut self, threshold: impl Into<f64>) -> Self {
+        self.condition = Condition::IncreaseAbove(threshold.into());
+        self
+    }
+
+    pub fn for_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+/// Whether an alert started or ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum AlertState {
+    Firing,
+    Resolved,
+}
+
+/// Alert emitted by a [`RuleEngine`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    /// Name of the rule
+    pub rule: String,
+    pub metric: Metric,
+    pub card_id: u32,
+    pub chip_id: u32,
+    /// Metric value which caused the transition
+    pub value: f64,
+    pub state: AlertState,
+    pub at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct RuleState {
+    /// Since when the condition holds
+    pending_since: Option<Instant>,
+    firing: bool,
+    /// Value of the previous sample
+    last_value: Option<f64>,
+}
+
+/// Evaluates rules against metric samples and emits alerts on state transitions
+#[derive(Debug)]
+pub struct RuleEngine {
+    rules: Vec<Rule>,
+    states: HashMap<(usize, u32, u32), RuleState>,
+}
+
+impl RuleEngine {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        RuleEngine {
+            rules,
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Metrics referenced by at least one rule
+    pub fn metrics(&self) -> Vec<Metric> {
+        let mut metrics: Vec<Metric> = Vec::new();
+        for rule in &self.rules {
+            if !metrics.contains(&rule.metric) {
+                metrics.push(rule.metric);
+            }
+        }
+        metrics
+    }
+
+    /// Feed a metric sample of a chip
+    ///
+    /// # Returns
+    /// alerts which started firing or were resolved by this sample
+    pub fn evaluate(
+        &mut self,
+        card_id: u32,
+        chip_id: u32,
+        metric: Metric,
+        value: f64,
+        at: Instant,
+    ) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            if rule.metric != metric {
+                continue;
+            }
+            let state = self.states.entry((index, card_id, chip_id)).or_default();
+            let previous = state.last_value.replace(value);
+            let transition = if rule.condition.holds_since(previous, value) {
+                let since = *state.pending_since.get_or_insert(at);
+                let fire = !state.firing && at.saturating_duration_since(since) >= rule.duration;
+                state.firing |= fire;
+                fire.then_some(AlertState::Firing)
+            } else {
+                state.pending_since = None;
+                let resolve = state.firing;
+                state.firing = false;
+                resolve.then_some(AlertState::Resolved)
+            };
+            if let Some(state) = transition {
+                alerts.push(Alert {
+                    rule: rule.name.clone(),
+                    metric,
+                    card_id,
+                    chip_id,
+                    value,
+                    state,
+                    at,
+                });
+            }
+        }
+        alerts
+    }
+}
+
+impl_as_str!(Metric, AlertState);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_fires_after_duration_and_resolves() {
+        let mut engine = RuleEngine::new(vec![Rule::metric(Metric::Temperature)
+            .above(90)
+            .for_duration(Duration::from_secs(60))]);
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert!(engine
+            .evaluate(0, 0, Metric::Temperature, 95.0, at(0))
+            .is_empty());
+        assert!(engine
+            .evaluate(0, 0, Metric::Power, 500.0, at(30))
+            .is_empty());
+        assert!(engine
+            .evaluate(0, 0, Metric::Temperature, 95.0, at(30))
+            .is_empty());
+        let alerts = engine.evaluate(0, 0, Metric::Temperature, 96.0, at(60));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].state, AlertState::Firing);
+        assert_eq!(alerts[0].rule, "temperature");
+        // already firing, and other chips are tracked separately
+        assert!(engine
+            .evaluate(0, 0, Metric::Temperature, 97.0, at(90))
+            .is_empty());
+        assert!(engine
+            .evaluate(0, 1, Metric::Temperature, 97.0, at(90))
+            .is_empty());
+
+        let alerts = engine.evaluate(0, 0, Metric::Temperature, 80.0, at(120));
+        assert_eq!(alerts[0].state, AlertState::Resolved);
+        assert!(engine
+            .evaluate(0, 0, Metric::Temperature, 80.0, at(150))
+            .is_empty());
+
+        let mut engine = RuleEngine::new(vec![
+            Rule::metric(Metric::EccDoubleBitErrors).increase_above(0)
+        ]);
+        let ecc = |engine: &mut RuleEngine, errors, secs| {
+            let alerts = engine.evaluate(0, 0, Metric::EccDoubleBitErrors, errors, at(secs));
+            alerts.first().map(|alert| alert.state)
+        };
+        assert_eq!(ecc(&mut engine, 3.0, 0), None);
+        assert_eq!(ecc(&mut engine, 4.0, 10), Some(AlertState::Firing));
+        assert_eq!(ecc(&mut engine, 4.0, 20), Some(AlertState::Resolved));
+    }
+}
@@ -0,0 +1,337 @@
+#[cfg(all(unix, feature = "thread-tuning"))]
+use super::ThreadTuning;
+use super::{Alert, Metric, RuleEngine};
+use crate::device::OwnedChip;
+use crate::error::DCMIResult;
+use crate::watchdog::Heartbeat;
+use std::collections::VecDeque;
+use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Metric value read from a chip by a [`Sampler`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub card_id: u32,
+    pub chip_id: u32,
+    pub metric: Metric,
+    pub value: DCMIResult<f64>,
+    pub at: Instant,
+    /// Wall clock time of the sample, for export
+    pub timestamp: SystemTime,
+}
+
+/// Settings of a [`Sampler`]
+#[derive(Debug)]
+pub struct SamplerConfig {
+    interval: Duration,
+    metrics: Vec<Metric>,
+    capacity: usize,
+    rules: Option<RuleEngine>,
+    heartbeat: Option<Heartbeat>,
+    #[cfg(all(unix, feature = "thread-tuning"))]
+    thread_tuning: Option<ThreadTuning>,
+}
+
+impl SamplerConfig {
+    /// Sample every `interval`, by default all metrics with room for 4096 samples
+    pub fn new(interval: Duration) -> Self {
+        SamplerConfig {
+            interval,
+            metrics: Vec::new(),
+            capacity: 4096,
+            rules: None,
+            heartbeat: None,
+            #[cfg(all(unix, feature = "thread-tuning"))]
+            thread_tuning: None,
+        }
+    }
+
+    /// Metrics to sample
+    pub fn metrics(mut self, metrics: impl IntoIterator<Item = Metric>) -> Self {
+        self.metrics = metrics.into_iter().collect();
+        self
+    }
+
+    /// Maximum number of samples kept, the oldest are dropped first
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Evaluate the samples against alert rules, the metrics of the rules are sampled as well
+    pub fn rules(mut self, rules: RuleEngine) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// Beat the heartbeat after every sampling round, to be watched by a watchdog
+    pub fn heartbeat(mut self, heartbeat: Heartbeat) -> Self {
+        self.heartbeat = Some(heartbeat);
+        self
+    }
+
+    /// Scheduling settings of the sampling thread
+    #[cfg(all(unix, feature = "thread-tuning"))]
+    pub fn thread_tuning(mut self, thread_tuning: ThreadTuning) -> Self {
+        self.thread_tuning = Some(thread_tuning);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct Buffers {
+    samples: VecDeque<Sample>,
+    alerts: VecDeque<Alert>,
+}
+
+/// Background thread sampling chip metrics into a bounded buffer
+///
+/// Rounds are scheduled on a fixed grid (`start + n × interval`), so the time a round takes does
+/// not make the sampling drift. Rounds which could not start in time are skipped rather than
+/// run back to back. The thread stops when this value is dropped.
+///
+/// ```no_run
+/// # use hw_dcmi::DCMI;
+/// # use hw_dcmi::monitor::{Metric, Sampler, SamplerConfig};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// let dcmi = Arc::new(DCMI::init().unwrap());
+/// let mut chips = Vec::new();
+/// for card in dcmi.owned_cards().unwrap() {
+///     chips.extend(card.get_chips().unwrap());
+/// }
+/// let config = SamplerConfig::new(Duration::from_secs(1))
+///     .metrics([Metric::Temperature, Metric::AiCoreUtilization]);
+/// let sampler = Sampler::spawn(chips, config).unwrap();
+/// std::thread::sleep(Duration::from_secs(10));
+/// for sample in sampler.drain() {
+///     println!("{:?}", sample);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Sampler {
+    buffers: Arc<Mutex<Buffers>>,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Sampler {
+    /// Start sampling the chips
+    pub fn spawn(chips: Vec<OwnedChip>, config: SamplerConfig) -> io::Result<Self> {
+        let SamplerConfig {
+            interval,
+            mut metrics,
+            capacity,
+            mut rules,
+            heartbeat,
+            #[cfg(all(unix, feature = "thread-tuning"))]
+            thread_tuning,
+        } = config;
+        if metrics.is_empty() {
+            metrics = <Metric as strum::IntoEnumIterator>::iter().collect();
+        }
+        for metric in rules.iter().flat_map(RuleEngine::metrics) {
+            if !metrics.contains(&metric) {
+                metrics.push(metric);
+            }
+        }
+
+        let buffers = Arc::new(Mutex::new(Buffers {
+            samples: VecDeque::with_capacity(capacity),
+            alerts: VecDeque::new(),
+        }));
+        let thread_buffers = buffers.clone();
+        let (stop, stop_rx) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("dcmi-sampler".to_string())
+            .spawn(move || {
+                #[cfg(all(unix, feature = "thread-tuning"))]
+                if let Some(thread_tuning) = thread_tuning {
+                    // sampling works with the inherited settings as well
+                    let _ = thread_tuning.apply_to_current_thread();
+                }
+                let start = Instant::now();
+                let mut round = 0u32;
+                loop {
+                    let samples = sample_round(&chips, &metrics);
+                    let alerts: Vec<Alert> = match &mut rules {
+                        Some(rules) => samples
+                            .iter()
+                            .filter_map(|sample| {
+                                let value = *sample.value.as_ref().ok()?;
+                                let (card_id, chip_id) = (sample.card_id, sample.chip_id);
+                                Some(rules.evaluate(
+                                    card_id,
+                                    chip_id,
+                                    sample.metric,
+                                    value,
+                                    sample.at,
+                                ))
+                            })
+                            .flatten()
+                            .collect(),
+                        None => Vec::new(),
+                    };
+                    {
+                        let mut buffers = thread_buffers.lock().unwrap_or_else(|e| e.into_inner());
+                        push_bounded(&mut buffers.samples, samples, capacity);
+                        push_bounded(&mut buffers.alerts, alerts, capacity);
+                    }
+                    if let Some(heartbeat) = &heartbeat {
+                        heartbeat.beat();
+                    }
+
+                    round = next_round(start, interval, round, Instant::now());
+                    if wait_for_round(&stop_rx, start, interval, round) {
+                        break;
+                    }
+                }
+            })?;
+        Ok(Sampler {
+            buffers,
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+
+    /// Copy of the buffered samples, oldest first
+    pub fn samples(&self) -> Vec<Sample> {
+        self.lock().samples.iter().cloned().collect()
+    }
+
+    /// Remove and return the buffered samples, oldest first
+    pub fn drain(&self) -> Vec<Sample> {
+        self.lock().samples.drain(..).collect()
+    }
+
+    /// Remove and return the alerts raised by the rules, oldest first
+    pub fn take_alerts(&self) -> Vec<Alert> {
+        self.lock().alerts.drain(..).collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buffers> {
+        self.buffers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for Sampler {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn sample_round(chips: &[OwnedChip], metrics: &[Metric]) -> Vec<Sample> {
+    let mut samples = Vec::with_capacity(chips.len() * metrics.len());
+    for owned in chips {
+        let chip = owned.chip();
+        for &metric in metrics {
+            samples.push(Sample {
+                card_id: chip.card_id(),
+                chip_id: chip.id(),
+                metric,
+                value: metric.read(&chip),
+                at: Instant::now(),
+                timestamp: SystemTime::now(),
+            });
+        }
+    }
+    samples
+}
+
+pub(super) fn push_bounded<T>(buffer: &mut VecDeque<T>, items: Vec<T>, capacity: usize) {
+    for item in items {
+        if buffer.len() >= capacity {
+            buffer.pop_front();
+        }
+        buffer.push_back(item);
+    }
+}
+
+/// Index of the next round to run, skipping rounds whose start already passed
+pub(super) fn next_round(start: Instant, interval: Duration, round: u32, now: Instant) -> u32 {
+    if interval.is_zero() {
+        return round.saturating_add(1);
+    }
+    let elapsed = now.saturating_duration_since(start).as_nanos() / interval.as_nanos();
+    round
+        .saturating_add(1)
+        .max(u32::try_from(elapsed).unwrap_or(u32::MAX).saturating_add(1))
+}
+
+/// Start of round `round`, `None` if it lies beyond what `Instant` represents
+fn round_start(start: Instant, interval: Duration, round: u32) -> Option<Instant> {
+    interval
+        .checked_mul(round)
+        .and_then(|offset| start.checked_add(offset))
+}
+
+/// Wait until round `round` starts, `true` if the thread was told to stop meanwhile
+///
+/// A round which never starts, e.g. with an interval of `Duration::MAX`, is waited for until
+/// the stop signal.
+pub(super) fn wait_for_round(
+    stop: &Receiver<()>,
+    start: Instant,
+    interval: Duration,
+    round: u32,
+) -> bool {
+    match round_start(start, interval, round) {
+        Some(due) => {
+            let wait = due.saturating_duration_since(Instant::now());
+            stop.recv_timeout(wait) != Err(RecvTimeoutError::Timeout)
+        }
+        None => {
+            let _ = stop.recv();
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_skips_missed_rounds() {
+        let start = Instant::now();
+        let interval = Duration::from_secs(1);
+        let at = |millis| start + Duration::from_millis(millis);
+        assert_eq!(next_round(start, interval, 0, at(200)), 1);
+        // round 1 overran into round 3, rounds 2 and 3 are skipped
+        assert_eq!(next_round(start, interval, 1, at(3500)), 4);
+
+        assert_eq!(next_round(start, Duration::ZERO, u32::MAX, at(0)), u32::MAX);
+
+        let mut buffer = VecDeque::from([1, 2]);
+        push_bounded(&mut buffer, vec![3, 4], 3);
+        assert_eq!(buffer, [2, 3, 4]);
+    }
+
+    #[test]
+    fn unreachable_round_waits_for_stop() {
+        let start = Instant::now();
+        assert_eq!(round_start(start, Duration::MAX, 0), Some(start));
+        assert_eq!(round_start(start, Duration::MAX, 2), None);
+        assert_eq!(
+            round_start(start, Duration::from_secs(u64::MAX / 2), 1),
+            None
+        );
+
+        let (stop, stop_rx) = mpsc::channel();
+        let stopper = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            drop(stop);
+        });
+        assert!(wait_for_round(&stop_rx, start, Duration::MAX, 1));
+        stopper.join().unwrap();
+        // a round which is due does not need the stop signal
+        let (_stop, stop_rx) = mpsc::channel();
+        assert!(!wait_for_round(&stop_rx, start, Duration::ZERO, 1));
+    }
+}
@@ -0,0 +1,126 @@
+use std::io;
+
+/// Scheduling settings applied to background monitoring threads
+///
+/// Keeps telemetry from competing with latency-sensitive inference threads: pin the threads to
+/// housekeeping cores and lower their priority, or give them a realtime priority on hosts where
+/// sampling jitter matters more.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadTuning {
+    /// CPUs the thread may run on, `None` keeps the inherited affinity
+    pub cpu_affinity: Option<Vec<usize>>,
+    /// Nice value (-20 to 19), `None` keeps the inherited value
+    pub nice: Option<i32>,
+    /// `SCHED_FIFO` priority (1 to 99), takes precedence over `nice`
+    pub realtime_priority: Option<i32>,
+}
+
+impl ThreadTuning {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cpu_affinity(mut self, cpus: impl IntoIterator<Item = usize>) -> Self {
+        self.cpu_affinity = Some(cpus.into_iter().collect());
+        self
+    }
+
+    pub fn nice(mut self, nice: i32) -> Self {
+        self.nice = Some(nice);
+        self
+    }
+
+    pub fn realtime_priority(mut self, priority: i32) -> Self {
+        self.realtime_priority = Some(priority);
+        self
+    }
+
+    /// Apply the settings to the calling thread
+    ///
+    /// Realtime priorities and negative nice values need `CAP_SYS_NICE`.
+    pub fn apply_to_current_thread(&self) -> io::Result<()> {
+        if let Some(cpus) = &self.cpu_affinity {
+            set_affinity(cpus)?;
+        }
+        if let Some(priority) = self.realtime_priority {
+            set_realtime_priority(priority)?;
+        } else if let Some(nice) = self.nice {
+            set_nice(nice)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+    // SAFETY: cpu_set_t is a plain bit set, all-zero is the empty set
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &cpu in cpus {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cpu {cpu} out of range"),
+            ));
+        }
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+    // pid 0 is the calling thread
+    if unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "cpu affinity is only supported on Linux",
+    ))
+}
+
+fn set_realtime_priority(priority: i32) -> io::Result<()> {
+    let param = libc::sched_param {
+        sched_priority: priority,
+    };
+    let ret =
+        unsafe { libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param) };
+    if ret != 0 {
+        return Err(io::Error::from_raw_os_error(ret));
+    }
+    Ok(())
+}
+
+fn set_nice(nice: i32) -> io::Result<()> {
+    // on Linux the nice value is per thread, PRIO_PROCESS with a thread id targets one thread
+    #[cfg(target_os = "linux")]
+    let who = unsafe { libc::gettid() } as libc::id_t;
+    #[cfg(not(target_os = "linux"))]
+    let who = 0;
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, who, nice) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_affinity_and_nice_to_a_thread() {
+        std::thread::spawn(|| {
+            ThreadTuning::new()
+                .cpu_affinity([0])
+                .nice(10)
+                .apply_to_current_thread()
+                .unwrap();
+            assert!(ThreadTuning::new()
+                .cpu_affinity([usize::MAX])
+                .apply_to_current_thread()
+                .is_err());
+        })
+        .join()
+        .unwrap();
+    }
+}
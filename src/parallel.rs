@@ -0,0 +1,113 @@
+//! Concurrent queries of all chips
+//!
+//! Every DCMI call takes a few milliseconds, most of it waiting for the device, so a full sweep
+//! of a 16-card server is much faster when the chips are queried from several threads. Some
+//! drivers serialize calls internally and gain nothing beyond a few threads, the number of
+//! threads is therefore limited.
+
+use crate::device::Chip;
+use crate::error::DCMIResult;
+use crate::DCMI;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Threads used by [`DCMI::query_all_parallel`]
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+impl DCMI {
+    /// Run `f` on every NPU from up to [`DEFAULT_CONCURRENCY`] threads
+    ///
+    /// # Returns
+    /// the chips with the result of `f`, in the order of [`DCMI::all_chips`]
+    ///
+    /// ```no_run
+    /// # use hw_dcmi::DCMI;
+    /// let dcmi = DCMI::init().unwrap();
+    /// for (chip, temperature) in dcmi.query_all_parallel(|chip| chip.get_temperature()).unwrap() {
+    ///     println!("{chip}: {temperature:?}");
+    /// }
+    /// ```
+    pub fn query_all_parallel<T, F>(&self, f: F) -> DCMIResult<Vec<(Chip<'_>, DCMIResult<T>)>>
+    where
+        T: Send,
+        F: Fn(&Chip) -> DCMIResult<T> + Sync,
+    {
+        self.query_all_parallel_with(DEFAULT_CONCURRENCY, f)
+    }
+
+    /// [`DCMI::query_all_parallel`] with at most `max_threads` threads, 1 queries the chips one
+    /// after another on the calling thread
+    pub fn query_all_parallel_with<T, F>(
+        &self,
+        max_threads: usize,
+        f: F,
+    ) -> DCMIResult<Vec<(Chip<'_>, DCMIResult<T>)>>
+    where
+        T: Send,
+        F: Fn(&Chip) -> DCMIResult<T> + Sync,
+    {
+        let chips = self.all_chips()?;
+        let results = map_parallel(&chips, max_threads, f);
+        Ok(chips.into_iter().zip(results).collect())
+    }
+}
+
+/// Apply `f` to every item from up to `max_threads` threads, results are in the order of `items`
+fn map_parallel<I, T, F>(items: &[I], max_threads: usize, f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    let threads = max_threads.clamp(1, items.len().max(1));
+    if threads == 1 {
+        return items.iter().map(f).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = f(item);
+                results
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push((index, result));
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    results.sort_unstable_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn keeps_order_and_thread_limit() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let items: Vec<u32> = (0..16).collect();
+        let results = map_parallel(&items, 4, |&item| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            item * 2
+        });
+        assert_eq!(
+            results,
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+        assert!(peak.load(Ordering::SeqCst) <= 4);
+        assert_eq!(map_parallel(&items, 0, |&item| item), items);
+    }
+}
@@ -0,0 +1,219 @@
+//! Query surface shared by the DCMI wrappers and their test doubles
+//!
+//! Code generic over [`DcmiApi`] instead of taking [`DCMI`] and its lifetime-bound [`Card`] and
+//! [`Chip`] handles runs unchanged against the mock backend of the `mock` feature, e.g. in CI
+//! without Ascend hardware. The traits cover the monitoring queries, the inherent methods of
+//! [`Card`] and [`Chip`] remain the full API.
+//!
+//! ```no_run
+//! use hw_dcmi::error::DCMIResult;
+//! use hw_dcmi::query::{ChipQuery, DcmiApi};
+//!
+//! fn hottest_chip(dcmi: &impl DcmiApi) -> DCMIResult<Option<(u32, u32)>> {
+//!     let mut hottest = None;
+//!     for chip in dcmi.all_chips()? {
+//!         let temperature = chip.get_temperature()?;
+//!         if hottest.is_none_or(|(_, max)| temperature > max) {
+//!             hottest = Some(((chip.card_id(), chip.id()), temperature));
+//!         }
+//!     }
+//!     Ok(hottest.map(|(chip, _)| chip))
+//! }
+//!
+//! let dcmi = hw_dcmi::DCMI::init().unwrap();
+//! println!("{:?}", hottest_chip(&dcmi));
+//! ```
+
+use crate::device::{Card, Chip};
+use crate::enums::{DeviceType, FrequencyType, HealthState, UtilizationType};
+use crate::error::DCMIResult;
+use crate::structs::{ChipInfo, ECCInfo, HBMInfo, MemoryInfo, PCIEInfo, ProcessMemoryInfo};
+use crate::units::{Celsius, MegaHertz, Watts};
+use crate::DCMI;
+
+/// Monitoring queries of a chip, see the methods of [`Chip`] of the same name
+pub trait ChipQuery {
+    fn card_id(&self) -> u32;
+    fn id(&self) -> u32;
+    fn get_chip_info(&self) -> DCMIResult<ChipInfo>;
+    fn get_health(&self) -> DCMIResult<HealthState>;
+    fn get_temperature(&self) -> DCMIResult<Celsius>;
+    fn get_power_info(&self) -> DCMIResult<Watts>;
+    fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32>;
+    fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz>;
+    fn get_memory_info(&self) -> DCMIResult<MemoryInfo>;
+    fn get_hbm_info(&self) -> DCMIResult<HBMInfo>;
+    fn get_pcie_info(&self) -> DCMIResult<PCIEInfo>;
+    fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo>;
+    fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>>;
+}
+
+/// Queries of a card, see the methods of [`Card`] of the same name
+pub trait CardQuery {
+    type Chip: ChipQuery;
+
+    fn id(&self) -> u32;
+    fn get_chip_num(&self) -> DCMIResult<u32>;
+    fn get_chips(&self) -> DCMIResult<Vec<Self::Chip>>;
+}
+
+/// DCMI backend, see the methods of [`DCMI`] of the same name
+pub trait DcmiApi {
+    type Card<'a>: CardQuery<Chip = Self::Chip<'a>>
+    where
+        Self: 'a;
+    type Chip<'a>: ChipQuery
+    where
+        Self: 'a;
+
+    fn get_dcmi_version(&self) -> DCMIResult<String>;
+    fn get_driver_version(&self) -> DCMIResult<String>;
+    fn get_card_list(&self) -> DCMIResult<Vec<Self::Card<'_>>>;
+    /// NPUs of all cards
+    fn all_chips(&self) -> DCMIResult<Vec<Self::Chip<'_>>>;
+}
+
+impl<'a> CardQuery for Card<'a> {
+    type Chip = Chip<'a>;
+
+    fn id(&self) -> u32 {
+        Card::id(self)
+    }
+
+    fn get_chip_num(&self) -> DCMIResult<u32> {
+        Card::get_chip_num(self)
+    }
+
+    fn get_chips(&self) -> DCMIResult<Vec<Chip<'a>>> {
+        Card::get_chips(self)
+    }
+}
+
+impl ChipQuery for Chip<'_> {
+    fn card_id(&self) -> u32 {
+        Chip::card_id(self)
+    }
+
+    fn id(&self) -> u32 {
+        Chip::id(self)
+    }
+
+    fn get_chip_info(&self) -> DCMIResult<ChipInfo> {
+        Chip::get_chip_info(self)
+    }
+
+    fn get_health(&self) -> DCMIResult<HealthState> {
+        Chip::get_health(self)
+    }
+
+    fn get_temperature(&self) -> DCMIResult<Celsius> {
+        Chip::get_temperature(self)
+    }
+
+    fn get_power_info(&self) -> DCMIResult<Watts> {
+        Chip::get_power_info(self)
+    }
+
+    fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32> {
+        Chip::get_utilization_rate(self, utilization_type)
+    }
+
+    fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz> {
+        Chip::get_frequency(self, frequency_type)
+    }
+
+    fn get_memory_info(&self) -> DCMIResult<MemoryInfo> {
+        Chip::get_memory_info(self)
+    }
+
+    fn get_hbm_info(&self) -> DCMIResult<HBMInfo> {
+        Chip::get_hbm_info(self)
+    }
+
+    fn get_pcie_info(&self) -> DCMIResult<PCIEInfo> {
+        Chip::get_pcie_info(self)
+    }
+
+    fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo> {
+        Chip::get_ecc_info(self, device_type)
+    }
+
+    fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>> {
+        Chip::get_processes(self)
+    }
+}
+
+impl DcmiApi for DCMI {
+    type Card<'a> = Card<'a>;
+    type Chip<'a> = Chip<'a>;
+
+    fn get_dcmi_version(&self) -> DCMIResult<String> {
+        DCMI::get_dcmi_version(self)
+    }
+
+    fn get_driver_version(&self) -> DCMIResult<String> {
+        DCMI::get_driver_version(self)
+    }
+
+    fn get_card_list(&self) -> DCMIResult<Vec<Card<'_>>> {
+        DCMI::get_card_list(self)
+    }
+
+    fn all_chips(&self) -> DCMIResult<Vec<Chip<'_>>> {
+        DCMI::all_chips(self)
+    }
+}
+
+impl<T: ChipQuery + ?Sized> ChipQuery for &T {
+    fn card_id(&self) -> u32 {
+        (**self).card_id()
+    }
+
+    fn id(&self) -> u32 {
+        (**self).id()
+    }
+
+    fn get_chip_info(&self) -> DCMIResult<ChipInfo> {
+        (**self).get_chip_info()
+    }
+
+    fn get_health(&self) -> DCMIResult<HealthState> {
+        (**self).get_health()
+    }
+
+    fn get_temperature(&self) -> DCMIResult<Celsius> {
+        (**self).get_temperature()
+    }
+
+    fn get_power_info(&self) -> DCMIResult<Watts> {
+        (**self).get_power_info()
+    }
+
+    fn get_utilization_rate(&self, utilization_type: UtilizationType) -> DCMIResult<u32> {
+        (**self).get_utilization_rate(utilization_type)
+    }
+
+    fn get_frequency(&self, frequency_type: FrequencyType) -> DCMIResult<MegaHertz> {
+        (**self).get_frequency(frequency_type)
+    }
+
+    fn get_memory_info(&self) -> DCMIResult<MemoryInfo> {
+        (**self).get_memory_info()
+    }
+
+    fn get_hbm_info(&self) -> DCMIResult<HBMInfo> {
+        (**self).get_hbm_info()
+    }
+
+    fn get_pcie_info(&self) -> DCMIResult<PCIEInfo> {
+        (**self).get_pcie_info()
+    }
+
+    fn get_ecc_info(&self, device_type: DeviceType) -> DCMIResult<ECCInfo> {
+        (**self).get_ecc_info(device_type)
+    }
+
+    fn get_processes(&self) -> DCMIResult<Vec<ProcessMemoryInfo>> {
+        (**self).get_processes()
+    }
+}
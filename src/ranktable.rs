@@ -0,0 +1,247 @@
+//! HCCL rank table generation for distributed training bring-up
+//!
+//! [`generate`] describes the NPUs of one server, tables of several servers are combined with
+//! [`Ranktable::merge`]. Serialize the result with `serde_json` to get `ranktable.json`:
+//!
+//! ```no_run
+//! # use hw_dcmi::DCMI;
+//! use hw_dcmi::ranktable::{self, RanktableConfig};
+//!
+//! let dcmi = DCMI::init().unwrap();
+//! let chips = dcmi.all_chips().unwrap();
+//! let table = ranktable::generate(&chips, &RanktableConfig::new("10.0.0.10")).unwrap();
+//! println!("{}", serde_json::to_string_pretty(&table).unwrap());
+//! ```
+
+use crate::device::Chip;
+use crate::enums::{PortType, UnitType};
+use crate::error::DCMIResult;
+use serde::Serialize;
+
+/// Rank table version without super pods
+pub const RANKTABLE_VERSION: &str = "1.0";
+/// Rank table version listing super pods and super device ids
+pub const RANKTABLE_SUPER_POD_VERSION: &str = "1.2";
+
+/// Server-level settings of a generated rank table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RanktableConfig {
+    /// Identifier of the server, usually its host IP address
+    pub server_id: String,
+    /// Rank of the first NPU of the server
+    pub rank_offset: u32,
+    /// Add super pod membership and super device ids, for super pod systems
+    pub super_pod: bool,
+}
+
+impl RanktableConfig {
+    pub fn new(server_id: impl Into<String>) -> Self {
+        RanktableConfig {
+            server_id: server_id.into(),
+            rank_offset: 0,
+            super_pod: false,
+        }
+    }
+
+    pub fn rank_offset(mut self, rank_offset: u32) -> Self {
+        self.rank_offset = rank_offset;
+        self
+    }
+
+    pub fn super_pod(mut self, super_pod: bool) -> Self {
+        self.super_pod = super_pod;
+        self
+    }
+}
+
+/// HCCL rank table, serializes to the `ranktable.json` format
+///
+/// All numbers are serialized as strings, as expected by HCCL.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Ranktable {
+    pub version: String,
+    pub server_count: String,
+    pub server_list: Vec<RanktableServer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub super_pod_list: Vec<RanktableSuperPod>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RanktableServer {
+    pub server_id: String,
+    pub device: Vec<RanktableDevice>,
+    pub host_nic_ip: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RanktableDevice {
+    /// Physical id of the NPU
+    pub device_id: String,
+    /// IP address of the RoCE port
+    pub device_ip: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub super_device_id: Option<String>,
+    pub rank_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RanktableSuperPod {
+    pub super_pod_id: String,
+    pub server_list: Vec<RanktableServerRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RanktableServerRef {
+    pub server_id: String,
+}
+
+impl Ranktable {
+    fn new(server_list: Vec<RanktableServer>, super_pod_list: Vec<RanktableSuperPod>) -> Self {
+        let version = if super_pod_list.is_empty() {
+            RANKTABLE_VERSION
+        } else {
+            RANKTABLE_SUPER_POD_VERSION
+        };
+        Ranktable {
+            version: version.to_string(),
+            server_count: server_list.len().to_string(),
+            server_list,
+            super_pod_list,
+            status: "completed".to_string(),
+        }
+    }
+
+    /// Combine the tables of several servers into one
+    ///
+    /// Ranks are kept as generated, give each server a distinct [`RanktableConfig::rank_offset`].
+    pub fn merge(tables: impl IntoIterator<Item = Ranktable>) -> Ranktable {
+        let mut server_list = Vec::new();
+        let mut super_pod_list: Vec<RanktableSuperPod> = Vec::new();
+        for table in tables {
+            server_list.extend(table.server_list);
+            for pod in table.super_pod_list {
+                match super_pod_list
+                    .iter_mut()
+                    .find(|known| known.super_pod_id == pod.super_pod_id)
+                {
+                    Some(known) => known.server_list.extend(pod.server_list),
+                    None => super_pod_list.push(pod),
+                }
+            }
+        }
+        Ranktable::new(server_list, super_pod_list)
+    }
+}
+
+/// Describe the NPUs among `chips` as the rank table of one server
+///
+/// MCU and CPU units are skipped. Ranks are assigned in physical id order, starting at
+/// [`RanktableConfig::rank_offset`].
+pub fn generate(chips: &[Chip], config: &RanktableConfig) -> DCMIResult<Ranktable> {
+    let mut npus = Vec::new();
+    for chip in chips
+        .iter()
+        .filter(|chip| chip.unit_type() == UnitType::NPU)
+    {
+        npus.push((chip.get_phy_id()?, chip));
+    }
+    npus.sort_by_key(|&(phy_id, _)| phy_id);
+
+    let mut devices = Vec::with_capacity(npus.len());
+    let mut super_pod_id = None;
+    for (rank, (phy_id, chip)) in (config.rank_offset..).zip(npus) {
+        let (device_ip, _) = chip.get_device_ip(PortType::Roce, 0)?;
+        let super_device_id = if config.super_pod {
+            let pod = chip.get_super_pod_info()?;
+            super_pod_id = Some(pod.super_pod_id);
+            Some(pod.sdid.to_string())
+        } else {
+            None
+        };
+        devices.push(RanktableDevice {
+            device_id: phy_id.to_string(),
+            device_ip: device_ip.to_string(),
+            super_device_id,
+            rank_id: rank.to_string(),
+        });
+    }
+
+    let super_pod_list = super_pod_id
+        .map(|id| RanktableSuperPod {
+            super_pod_id: id.to_string(),
+            server_list: vec![RanktableServerRef {
+                server_id: config.server_id.clone(),
+            }],
+        })
+        .into_iter()
+        .collect();
+    let server = RanktableServer {
+        server_id: config.server_id.clone(),
+        device: devices,
+        host_nic_ip: "reserve".to_string(),
+    };
+    Ok(Ranktable::new(vec![server], super_pod_list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn server(server_id: &str, rank: u32, super_pod_id: Option<&str>) -> Ranktable {
+        let server = RanktableServer {
+            server_id: server_id.to_string(),
+            device: vec![RanktableDevice {
+                device_id: "0".to_string(),
+                device_ip: "192.168.100.101".to_string(),
+                super_device_id: super_pod_id.map(|_| rank.to_string()),
+                rank_id: rank.to_string(),
+            }],
+            host_nic_ip: "reserve".to_string(),
+        };
+        let pods = super_pod_id
+            .map(|id| RanktableSuperPod {
+                super_pod_id: id.to_string(),
+                server_list: vec![RanktableServerRef {
+                    server_id: server_id.to_string(),
+                }],
+            })
+            .into_iter()
+            .collect();
+        Ranktable::new(vec![server], pods)
+    }
+
+    #[test]
+    fn serializes_and_merges_servers() {
+        let table = Ranktable::merge([server("10.0.0.10", 0, None), server("10.0.0.11", 1, None)]);
+        assert_eq!(
+            serde_json::to_value(&table).unwrap(),
+            json!({
+                "version": "1.0",
+                "server_count": "2",
+                "server_list": [
+                    {
+                        "server_id": "10.0.0.10",
+                        "device": [{"device_id": "0", "device_ip": "192.168.100.101", "rank_id": "0"}],
+                        "host_nic_ip": "reserve"
+                    },
+                    {
+                        "server_id": "10.0.0.11",
+                        "device": [{"device_id": "0", "device_ip": "192.168.100.101", "rank_id": "1"}],
+                        "host_nic_ip": "reserve"
+                    }
+                ],
+                "status": "completed"
+            })
+        );
+
+        let table = Ranktable::merge([
+            server("10.0.0.10", 0, Some("0")),
+            server("10.0.0.11", 1, Some("0")),
+        ]);
+        assert_eq!(table.version, RANKTABLE_SUPER_POD_VERSION);
+        assert_eq!(table.super_pod_list.len(), 1);
+        assert_eq!(table.super_pod_list[0].server_list.len(), 2);
+    }
+}
@@ -0,0 +1,284 @@
+//! Batched framing of serialized records for shipping to a remote collector
+//!
+//! Records (usually serialized snapshots) get consecutive sequence numbers and are packed into
+//! frames, optionally compressed with zstd (feature `zstd`). Frames stay in a bounded backlog
+//! until the collector acknowledges them, so they can be sent again after a reconnect.
+//!
+//! Frame layout, all integers little endian:
+//!
+//! | field        | size | description                                  |
+//! |--------------|------|----------------------------------------------|
+//! | magic        | 4    | `b"DCMI"`                                    |
+//! | version      | 1    | [`FRAME_VERSION`]                            |
+//! | flags        | 1    | bit 0 set if the payload is zstd compressed  |
+//! | first_seq    | 8    | sequence number of the first record          |
+//! | count        | 4    | number of records                            |
+//! | payload_len  | 4    | length of the payload                        |
+//! | payload      | n    | records, each prefixed with its `u32` length |
+
+use std::collections::VecDeque;
+use std::io;
+
+pub const FRAME_MAGIC: [u8; 4] = *b"DCMI";
+pub const FRAME_VERSION: u8 = 1;
+const FLAG_ZSTD: u8 = 0x01;
+const HEADER_LEN: usize = 22;
+
+/// Compression applied to frame payloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    /// zstd with the given compression level
+    #[cfg(feature = "zstd")]
+    Zstd(i32),
+}
+
+/// An encoded frame ready to be written to the transport
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedFrame {
+    pub first_seq: u64,
+    pub last_seq: u64,
+    pub bytes: Vec<u8>,
+}
+
+/// Limits of a [`Batcher`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Flush once this many records are pending
+    pub max_records: usize,
+    /// Flush once the pending records reach this size in bytes
+    pub max_bytes: usize,
+    /// Maximum number of unacknowledged frames kept for backfill, the oldest are dropped first
+    pub max_unacked_frames: usize,
+    pub compression: Compression,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        BatchConfig {
+            max_records: 64,
+            max_bytes: 1 << 20,
+            max_unacked_frames: 256,
+            compression: Compression::None,
+        }
+    }
+}
+
+/// Packs records into frames and keeps unacknowledged frames for backfill
+#[derive(Debug)]
+pub struct Batcher {
+    config: BatchConfig,
+    next_seq: u64,
+    pending: Vec<Vec<u8>>,
+    pending_bytes: usize,
+    unacked: VecDeque<EncodedFrame>,
+}
+
+impl Batcher {
+    pub fn new(config: BatchConfig) -> Self {
+        Batcher {
+            config,
+            next_seq: 0,
+            pending: Vec::new(),
+            pending_bytes: 0,
+            unacked: VecDeque::new(),
+        }
+    }
+
+    /// Sequence number the next pushed record will get
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Add a record, returns a frame if the batch limits were reached
+    pub fn push(&mut self, record: Vec<u8>) -> io::Result<Option<EncodedFrame>> {
+        self.next_seq += 1;
+        self.pending_bytes += record.len();
+        self.pending.push(record);
+        if self.pending.len() >= self.config.max_records
+            || self.pending_bytes >= self.config.max_bytes
+        {
+            self.flush()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Encode all pending records into a frame, `None` if nothing is pending
+    pub fn flush(&mut self) -> io::Result<Option<EncodedFrame>> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let records = std::mem::take(&mut self.pending);
+        self.pending_bytes = 0;
+        let first_seq = self.next_seq - records.len() as u64;
+        let frame = EncodedFrame {
+            first_seq,
+            last_seq: self.next_seq - 1,
+            bytes: encode_frame(first_seq, &records, self.config.compression)?,
+        };
+        if self.unacked.len() >= self.config.max_unacked_frames {
+            self.unacked.pop_front();
+        }
+        self.unacked.push_back(frame.clone());
+        Ok(Some(frame))
+    }
+
+    /// Mark every record up to and including `seq` as received by the collector
+    pub fn ack(&mut self, seq: u64) {
+        while self
+            .unacked
+            .front()
+            .is_some_and(|frame| frame.last_seq <= seq)
+        {
+            self.unacked.pop_front();
+        }
+    }
+
+    /// Frames not acknowledged yet, oldest first, to be sent again after a reconnect
+    pub fn backfill(&self) -> impl Iterator<Item = &EncodedFrame> {
+        self.unacked.iter()
+    }
+}
+
+/// Encode records into a single frame
+pub fn encode_frame(
+    first_seq: u64,
+    records: &[Vec<u8>],
+    compression: Compression,
+) -> io::Result<Vec<u8>> {
+    let mut payload = Vec::with_capacity(records.iter().map(|r| r.len() + 4).sum());
+    for record in records {
+        payload.extend_from_slice(&len_u32(record.len())?.to_le_bytes());
+        payload.extend_from_slice(record);
+    }
+    let (flags, payload) = match compression {
+        Compression::None => (0, payload),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd(level) => (FLAG_ZSTD, zstd::encode_all(payload.as_slice(), level)?),
+    };
+
+    let mut frame = Vec::with_capacity(HEADER_LEN + payload.len());
+    frame.extend_from_slice(&FRAME_MAGIC);
+    frame.push(FRAME_VERSION);
+    frame.push(flags);
+    frame.extend_from_slice(&first_seq.to_le_bytes());
+    frame.extend_from_slice(&len_u32(records.len())?.to_le_bytes());
+    frame.extend_from_slice(&len_u32(payload.len())?.to_le_bytes());
+    frame.extend_from_slice(&payload);
+    Ok(frame)
+}
+
+/// Decode a frame produced by [`encode_frame`]
+///
+/// # Returns
+/// the sequence number of the first record and the records
+pub fn decode_frame(frame: &[u8]) -> io::Result<(u64, Vec<Vec<u8>>)> {
+    if frame.len() < HEADER_LEN || frame[..4] != FRAME_MAGIC {
+        return Err(invalid_data("not a frame"));
+    }
+    if frame[4] != FRAME_VERSION {
+        return Err(invalid_data("unsupported frame version"));
+    }
+    let flags = frame[5];
+    let first_seq = u64::from_le_bytes(frame[6..14].try_into().unwrap());
+    let count = u32::from_le_bytes(frame[14..18].try_into().unwrap()) as usize;
+    let payload_len = u32::from_le_bytes(frame[18..22].try_into().unwrap()) as usize;
+    let payload = frame
+        .get(HEADER_LEN..HEADER_LEN + payload_len)
+        .ok_or_else(|| invalid_data("truncated frame"))?;
+
+    let payload = if flags & FLAG_ZSTD != 0 {
+        #[cfg(feature = "zstd")]
+        {
+            std::borrow::Cow::Owned(zstd::decode_all(payload)?)
+        }
+        #[cfg(not(feature = "zstd"))]
+        return Err(invalid_data("zstd frame but the zstd feature is disabled"));
+    } else {
+        std::borrow::Cow::Borrowed(payload)
+    };
+
+    // the count comes from the wire, every record takes at least its 4-byte length
+    let mut records = Vec::with_capacity(count.min(payload.len() / 4));
+    let mut rest = &payload[..];
+    for _ in 0..count {
+        let len = rest
+            .get(..4)
+            .map(|len| u32::from_le_bytes(len.try_into().unwrap()) as usize)
+            .ok_or_else(|| invalid_data("truncated record"))?;
+        let record = rest
+            .get(4..4 + len)
+            .ok_or_else(|| invalid_data("truncated record"))?;
+        records.push(record.to_vec());
+        rest = &rest[4 + len..];
+    }
+    if !rest.is_empty() {
+        return Err(invalid_data("trailing bytes after the records"));
+    }
+    Ok((first_seq, records))
+}
+
+fn len_u32(len: usize) -> io::Result<u32> {
+    u32::try_from(len).map_err(|_| invalid_data("record too large"))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_roundtrip() {
+        let records = vec![b"first".to_vec(), Vec::new(), b"third".to_vec()];
+        let frame = encode_frame(7, &records, Compression::None).unwrap();
+        assert_eq!(decode_frame(&frame).unwrap(), (7, records));
+        assert!(decode_frame(&frame[..frame.len() - 1]).is_err());
+
+        // corrupt counts: far more records than the payload holds, and fewer than it holds
+        let mut corrupt = frame.clone();
+        corrupt[14..18].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(decode_frame(&corrupt).is_err());
+        corrupt[14..18].copy_from_slice(&2u32.to_le_bytes());
+        assert!(decode_frame(&corrupt).is_err());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_frame_roundtrip() {
+        let records = vec![vec![0u8; 4096]; 4];
+        let frame = encode_frame(0, &records, Compression::Zstd(3)).unwrap();
+        assert!(frame.len() < 4096);
+        assert_eq!(decode_frame(&frame).unwrap(), (0, records));
+    }
+
+    #[test]
+    fn batcher_keeps_unacked_frames() {
+        let mut batcher = Batcher::new(BatchConfig {
+            max_records: 2,
+            max_unacked_frames: 2,
+            ..Default::default()
+        });
+        assert!(batcher.push(b"a".to_vec()).unwrap().is_none());
+        let frame = batcher.push(b"b".to_vec()).unwrap().unwrap();
+        assert_eq!((frame.first_seq, frame.last_seq), (0, 1));
+        batcher.push(b"c".to_vec()).unwrap();
+        let frame = batcher.flush().unwrap().unwrap();
+        assert_eq!((frame.first_seq, frame.last_seq), (2, 2));
+        assert!(batcher.flush().unwrap().is_none());
+
+        assert_eq!(batcher.backfill().count(), 2);
+        batcher.ack(1);
+        assert_eq!(batcher.backfill().next().unwrap().first_seq, 2);
+        batcher.push(b"d".to_vec()).unwrap();
+        batcher.push(b"e".to_vec()).unwrap();
+        batcher.push(b"f".to_vec()).unwrap();
+        batcher.push(b"g".to_vec()).unwrap();
+        // the oldest frame was dropped to respect max_unacked_frames
+        assert_eq!(batcher.backfill().next().unwrap().first_seq, 3);
+    }
+}
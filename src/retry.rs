@@ -0,0 +1,178 @@
+//! Retrying DCMI calls which fail transiently
+//!
+//! While a device boots or its firmware is upgraded, queries fail with
+//! [`DCMIError::NotReady`], [`DCMIError::CodeTimeOut`] or [`DCMIError::IsUpgrading`] and succeed
+//! when repeated later. A [`RetryPolicy`] repeats a call on these errors, either for every
+//! read-only query of the process through [`DCMI::set_retry_policy`] or for a single call through
+//! [`RetryPolicy::run`]:
+//!
+//! ```no_run
+//! use hw_dcmi::retry::RetryPolicy;
+//! use hw_dcmi::DCMI;
+//! use std::time::Duration;
+//!
+//! let dcmi = DCMI::init().unwrap();
+//! dcmi.set_retry_policy(RetryPolicy::new(3));
+//!
+//! let chips = dcmi.all_chips().unwrap();
+//! let patient = RetryPolicy::new(10).backoff(Duration::from_secs(1));
+//! let health = patient.run(|| chips[0].get_health()).unwrap();
+//! ```
+//!
+//! Calls which change the device, e.g. creating a vNPU or resetting a chip, are never retried by
+//! the process-wide policy: a timeout does not tell whether the change was made, repeating it
+//! may create a second vNPU or reset the chip twice. Neither is `dcmi_get_fault_event`, whose
+//! timeout means no event arrived. Callers who know a change is safe to repeat wrap it in
+//! [`RetryPolicy::run`].
+//!
+//! [`DCMI::set_retry_policy`]: crate::DCMI::set_retry_policy
+//! [`DCMIError::NotReady`]: crate::error::DCMIError::NotReady
+//! [`DCMIError::CodeTimeOut`]: crate::error::DCMIError::CodeTimeOut
+//! [`DCMIError::IsUpgrading`]: crate::error::DCMIError::IsUpgrading
+
+use crate::error::DCMIResult;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// How often and how patiently to retry transient errors
+///
+/// The delay before a retry starts at the initial backoff and doubles with every retry up to the
+/// maximum backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// No retries
+    fn default() -> Self {
+        RetryPolicy::new(1)
+    }
+}
+
+impl RetryPolicy {
+    /// Make at most `max_attempts` attempts, waiting 100ms before the first retry and at most 5s
+    pub const fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+
+    /// Delay before the first retry
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Upper bound of the delay between two attempts
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Delay before retry number `retry`, counted from 0
+    fn delay(&self, retry: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+
+    /// Call `call` until it succeeds, fails with an error which is not
+    /// [transient](crate::error::DCMIError::is_transient) or the attempts are used up
+    pub fn run<T>(&self, mut call: impl FnMut() -> DCMIResult<T>) -> DCMIResult<T> {
+        let mut retry = 0;
+        loop {
+            match call() {
+                Err(e) if e.is_transient() && retry + 1 < self.max_attempts => {
+                    thread::sleep(self.delay(retry));
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Policy applied to every read-only query of the process
+static POLICY: Mutex<RetryPolicy> = Mutex::new(RetryPolicy::new(1));
+
+pub(crate) fn set(policy: RetryPolicy) {
+    *POLICY.lock().unwrap_or_else(|e| e.into_inner()) = policy;
+}
+
+pub(crate) fn current() -> RetryPolicy {
+    *POLICY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Policy of a call of the DCMI function `function`
+///
+/// The process-wide policy for read-only queries (`dcmi_get_*` and `dcmi_mcu_get_*`) except
+/// `dcmi_get_fault_event`, a single attempt for everything else.
+pub(crate) fn policy_for(function: &str) -> RetryPolicy {
+    if is_query(function) {
+        current()
+    } else {
+        RetryPolicy::default()
+    }
+}
+
+/// Whether calling `function` again cannot change the state of the device
+fn is_query(function: &str) -> bool {
+    (function.starts_with("dcmi_get_") || function.starts_with("dcmi_mcu_get_"))
+        && function != "dcmi_get_fault_event"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DCMIError;
+
+    #[test]
+    fn retries_transient_errors_only() {
+        let policy = RetryPolicy::new(3).backoff(Duration::ZERO);
+        let mut attempts = 0;
+        let result: DCMIResult<()> = policy.run(|| {
+            attempts += 1;
+            Err(DCMIError::NotReady)
+        });
+        assert_eq!((result, attempts), (Err(DCMIError::NotReady), 3));
+
+        attempts = 0;
+        let result = policy.run(|| {
+            attempts += 1;
+            if attempts == 1 {
+                Err(DCMIError::IsUpgrading)
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result, Ok(2));
+
+        attempts = 0;
+        let result: DCMIResult<()> = policy.run(|| {
+            attempts += 1;
+            Err(DCMIError::NotSupport)
+        });
+        assert_eq!((result, attempts), (Err(DCMIError::NotSupport), 1));
+
+        let slow = RetryPolicy::new(10).max_backoff(Duration::from_millis(300));
+        assert_eq!(slow.delay(0), Duration::from_millis(100));
+        assert_eq!(slow.delay(1), Duration::from_millis(200));
+        assert_eq!(slow.delay(5), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn only_queries_follow_the_global_policy() {
+        assert!(is_query("dcmi_get_device_health"));
+        assert!(is_query("dcmi_mcu_get_power_info"));
+        assert!(!is_query("dcmi_get_fault_event"));
+        assert!(!is_query("dcmi_create_vdevice"));
+        assert!(!is_query("dcmi_set_device_reset"));
+        assert_eq!(policy_for("dcmi_set_device_reset"), RetryPolicy::default());
+    }
+}
@@ -0,0 +1,451 @@
+//! One-shot dump of the state of every card and chip
+//!
+//! [`DCMI::snapshot`] collects versions, cards, chips, memory, HBM, power, temperatures,
+//! utilization and health in one call. With the `serde` feature the snapshot is serializable,
+//! e.g. for exporters or to attach to bug reports:
+//!
+//! ```no_run
+//! # use hw_dcmi::DCMI;
+//! let dcmi = DCMI::init().unwrap();
+//! let snapshot = dcmi.snapshot().unwrap();
+//! # #[cfg(feature = "json")]
+//! println!("{}", serde_json::to_string_pretty(&snapshot).unwrap());
+//! ```
+//!
+//! [`SystemSnapshot::diff`] lists what changed between two snapshots, e.g. for logging what
+//! happened since the previous poll. Dashboards showing only the common values of a chip use
+//! the flat [`ChipStatus`] of [`Chip::status`] instead.
+
+use crate::device::{Card, Chip};
+use crate::enums::{HealthState, UnitType, UtilizationType};
+use crate::error::DCMIResult;
+use crate::query::ChipQuery;
+use crate::structs::{HBMInfo, MemoryInfo, Temperatures};
+use crate::units::{Celsius, Mebibytes, Watts};
+use crate::DCMI;
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use strum::IntoEnumIterator;
+
+/// State of the whole system at one point in time
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SystemSnapshot {
+    /// Time the snapshot was started, unit: ms since the Unix epoch
+    pub timestamp_ms: u64,
+    pub dcmi_version: Option<String>,
+    pub driver_version: Option<String>,
+    pub cards: Vec<CardSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CardSnapshot {
+    pub id: u32,
+    pub chips: Vec<ChipSnapshot>,
+}
+
+/// State of a chip, values the chip does not report are `None`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChipSnapshot {
+    pub id: u32,
+    pub unit_type: UnitType,
+    pub health: Option<HealthState>,
+    pub temperatures: Option<Temperatures>,
+    pub power: Option<Watts>,
+    pub memory: Option<MemoryInfo>,
+    pub hbm: Option<HBMInfo>,
+    /// Utilization of every component reporting one, keyed by [`UtilizationType::as_str`],
+    /// unit: %
+    pub utilization: BTreeMap<&'static str, u32>,
+    /// Codes of the active faults, see [`Chip::get_errors`]
+    pub error_codes: Option<Vec<u32>>,
+}
+
+/// Common values of a chip for dashboards, values the chip does not report are `None`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChipStatus {
+    pub health: Option<HealthState>,
+    pub temperature: Option<Celsius>,
+    pub power: Option<Watts>,
+    /// AI core utilization, unit: %
+    pub ai_core_utilization: Option<u32>,
+    pub memory_used: Option<Mebibytes>,
+    pub memory_total: Option<Mebibytes>,
+    pub hbm_used: Option<Mebibytes>,
+    pub hbm_total: Option<Mebibytes>,
+    /// Number of active faults
+    pub error_count: Option<usize>,
+}
+
+impl ChipStatus {
+    /// Read the status of any chip, failed queries leave their value empty
+    ///
+    /// The active faults are not part of [`ChipQuery`], `error_count` is left empty,
+    /// [`Chip::status`] fills it in.
+    pub fn read(chip: &impl ChipQuery) -> ChipStatus {
+        let memory = chip.get_memory_info().ok();
+        let hbm = chip.get_hbm_info().ok();
+        ChipStatus {
+            health: chip.get_health().ok(),
+            temperature: chip.get_temperature().ok(),
+            power: chip.get_power_info().ok(),
+            ai_core_utilization: chip.get_utilization_rate(UtilizationType::AiCore).ok(),
+            memory_used: memory.map(|m| m.memory_size.saturating_sub(m.memory_available)),
+            memory_total: memory.map(|m| m.memory_size),
+            hbm_used: hbm.map(|h| h.memory_usage),
+            hbm_total: hbm.map(|h| h.memory_size),
+            error_count: None,
+        }
+    }
+}
+
+/// Change between two snapshots, see [`SystemSnapshot::diff`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SnapshotChange {
+    /// Card the change is about, `None` for changes of the whole system
+    pub card_id: Option<u32>,
+    /// Chip the change is about, `None` for changes of a whole card or the system
+    pub chip_id: Option<u32>,
+    pub kind: ChangeKind,
+}
+
+/// What changed, old values are in `from` and new values in `to`, `None` if not reported
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChangeKind {
+    DriverVersion {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    CardAdded,
+    CardRemoved,
+    ChipAdded,
+    ChipRemoved,
+    Health {
+        from: Option<HealthState>,
+        to: Option<HealthState>,
+    },
+    /// Faults which were not active before
+    NewErrors(Vec<u32>),
+    /// Faults which are no longer active
+    ErrorsCleared(Vec<u32>),
+    Temperature {
+        from: Option<Celsius>,
+        to: Option<Celsius>,
+    },
+    Power {
+        from: Option<Watts>,
+        to: Option<Watts>,
+    },
+    /// Utilization of a component, keyed as in [`ChipSnapshot::utilization`], unit: %
+    Utilization {
+        component: &'static str,
+        from: Option<u32>,
+        to: Option<u32>,
+    },
+    HbmUsage {
+        from: Option<Mebibytes>,
+        to: Option<Mebibytes>,
+    },
+}
+
+impl SystemSnapshot {
+    /// Changes from this snapshot to the newer snapshot `other`
+    ///
+    /// Cards and chips are matched by id. Every changed value is reported, filter the changes
+    /// to ignore small fluctuations of utilization or temperature.
+    pub fn diff(&self, other: &SystemSnapshot) -> Vec<SnapshotChange> {
+        let mut changes = Vec::new();
+        if self.driver_version != other.driver_version {
+            changes.push(SnapshotChange {
+                card_id: None,
+                chip_id: None,
+                kind: ChangeKind::DriverVersion {
+                    from: self.driver_version.clone(),
+                    to: other.driver_version.clone(),
+                },
+            });
+        }
+        for card in &self.cards {
+            match other.cards.iter().find(|new| new.id == card.id) {
+                Some(new) => card.diff_into(new, &mut changes),
+                None => changes.push(card.change(None, ChangeKind::CardRemoved)),
+            }
+        }
+        for card in &other.cards {
+            if !self.cards.iter().any(|old| old.id == card.id) {
+                changes.push(card.change(None, ChangeKind::CardAdded));
+            }
+        }
+        changes
+    }
+}
+
+impl CardSnapshot {
+    fn change(&self, chip_id: Option<u32>, kind: ChangeKind) -> SnapshotChange {
+        SnapshotChange {
+            card_id: Some(self.id),
+            chip_id,
+            kind,
+        }
+    }
+
+    fn diff_into(&self, other: &CardSnapshot, changes: &mut Vec<SnapshotChange>) {
+        for chip in &self.chips {
+            let Some(new) = other.chips.iter().find(|new| new.id == chip.id) else {
+                changes.push(self.change(Some(chip.id), ChangeKind::ChipRemoved));
+                continue;
+            };
+            changes.extend(
+                chip.diff(new)
+                    .into_iter()
+                    .map(|kind| self.change(Some(chip.id), kind)),
+            );
+        }
+        for chip in &other.chips {
+            if !self.chips.iter().any(|old| old.id == chip.id) {
+                changes.push(self.change(Some(chip.id), ChangeKind::ChipAdded));
+            }
+        }
+    }
+}
+
+impl ChipSnapshot {
+    fn diff(&self, other: &ChipSnapshot) -> Vec<ChangeKind> {
+        let mut changes = Vec::new();
+        if self.health != other.health {
+            changes.push(ChangeKind::Health {
+                from: self.health,
+                to: other.health,
+            });
+        }
+        if let (Some(old), Some(new)) = (&self.error_codes, &other.error_codes) {
+            let added: Vec<u32> = new
+                .iter()
+                .filter(|code| !old.contains(code))
+                .copied()
+                .collect();
+            let cleared: Vec<u32> = old
+                .iter()
+                .filter(|code| !new.contains(code))
+                .copied()
+                .collect();
+            if !added.is_empty() {
+                changes.push(ChangeKind::NewErrors(added));
+            }
+            if !cleared.is_empty() {
+                changes.push(ChangeKind::ErrorsCleared(cleared));
+            }
+        }
+        let temperature = |chip: &ChipSnapshot| chip.temperatures.as_ref().map(|t| t.chip);
+        if temperature(self) != temperature(other) {
+            changes.push(ChangeKind::Temperature {
+                from: temperature(self),
+                to: temperature(other),
+            });
+        }
+        if self.power != other.power {
+            changes.push(ChangeKind::Power {
+                from: self.power,
+                to: other.power,
+            });
+        }
+        let components: std::collections::BTreeSet<_> = self
+            .utilization
+            .keys()
+            .chain(other.utilization.keys())
+            .collect();
+        for &component in components {
+            let from = self.utilization.get(component).copied();
+            let to = other.utilization.get(component).copied();
+            if from != to {
+                changes.push(ChangeKind::Utilization {
+                    component,
+                    from,
+                    to,
+                });
+            }
+        }
+        let hbm_usage = |chip: &ChipSnapshot| chip.hbm.as_ref().map(|hbm| hbm.memory_usage);
+        if hbm_usage(self) != hbm_usage(other) {
+            changes.push(ChangeKind::HbmUsage {
+                from: hbm_usage(self),
+                to: hbm_usage(other),
+            });
+        }
+        changes
+    }
+}
+
+impl Chip<'_> {
+    /// Collect the state of the chip, failed queries leave their value empty
+    pub fn snapshot(&self) -> ChipSnapshot {
+        ChipSnapshot {
+            id: self.id(),
+            unit_type: self.unit_type(),
+            health: self.get_health().ok(),
+            temperatures: self.get_temperatures().ok(),
+            power: self.get_power_info().ok(),
+            memory: self.get_memory_info().ok(),
+            hbm: self.get_hbm_info().ok(),
+            utilization: UtilizationType::iter()
+                .filter_map(|utilization_type| {
+                    let rate = self.get_utilization_rate(utilization_type).ok()?;
+                    Some((utilization_type.as_str(), rate))
+                })
+                .collect(),
+            error_codes: self.error_codes().ok(),
+        }
+    }
+
+    /// Collect the common values of the chip, failed queries leave their value empty
+    pub fn status(&self) -> ChipStatus {
+        ChipStatus {
+            error_count: self.error_codes().ok().map(|codes| codes.len()),
+            ..ChipStatus::read(self)
+        }
+    }
+}
+
+impl Card<'_> {
+    /// Collect the state of every chip of the card
+    pub fn snapshot(&self) -> DCMIResult<CardSnapshot> {
+        Ok(CardSnapshot {
+            id: self.id(),
+            chips: self.get_chips()?.iter().map(Chip::snapshot).collect(),
+        })
+    }
+}
+
+impl DCMI {
+    /// Collect the state of every card and chip
+    ///
+    /// Only failing to enumerate the cards or chips is an error, values that cannot be read are
+    /// left empty in the snapshot.
+    pub fn snapshot(&self) -> DCMIResult<SystemSnapshot> {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        Ok(SystemSnapshot {
+            timestamp_ms,
+            dcmi_version: self.get_dcmi_version().ok(),
+            driver_version: self.get_driver_version().ok(),
+            cards: self
+                .get_card_list()?
+                .iter()
+                .map(Card::snapshot)
+                .collect::<DCMIResult<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn snapshot_serializes_identifiers() {
+        let dcmi = DCMI { _private: () };
+        let chip = Chip::new(&dcmi, 0, 0, UnitType::NPU).snapshot();
+        assert_eq!(chip.health, None);
+        let json = serde_json::to_value(&chip).unwrap();
+        assert_eq!(json["unit_type"], "npu");
+        assert!(json["utilization"].as_object().unwrap().is_empty());
+
+        let json = serde_json::to_value(HealthState::Unknown(9)).unwrap();
+        assert_eq!(json, serde_json::json!({ "unknown": 9 }));
+    }
+
+    #[test]
+    #[cfg(feature = "mock")]
+    fn status_maps_queries() {
+        use crate::error::DCMIError;
+        use crate::mock::{MockChip, MockQuery};
+        use crate::units::MegaHertz;
+
+        let chip = MockChip::new(0, 0)
+            .temperature(Celsius(61))
+            .utilization(UtilizationType::AiCore, 73)
+            .memory(Some(MemoryInfo {
+                memory_size: Mebibytes(32768),
+                memory_available: Mebibytes(24576),
+                freq: MegaHertz(2666),
+                hugepage_size: 2048,
+                hugepages_total: 0,
+                hugepages_free: 0,
+                utilization: 25,
+            }))
+            .fail(MockQuery::Power, DCMIError::CodeTimeOut)
+            .fail(MockQuery::HbmInfo, DCMIError::NotSupport);
+        assert_eq!(
+            ChipStatus::read(&chip),
+            ChipStatus {
+                health: Some(HealthState::Normal),
+                temperature: Some(Celsius(61)),
+                power: None,
+                ai_core_utilization: Some(73),
+                memory_used: Some(Mebibytes(8192)),
+                memory_total: Some(Mebibytes(32768)),
+                hbm_used: None,
+                hbm_total: None,
+                error_count: None,
+            }
+        );
+    }
+
+    #[test]
+    fn diff_lists_changes() {
+        let chip = |id, health, error_codes: &[u32], aicore| ChipSnapshot {
+            id,
+            unit_type: UnitType::NPU,
+            health: Some(health),
+            temperatures: None,
+            power: Some(Watts(90.0)),
+            memory: None,
+            hbm: None,
+            utilization: BTreeMap::from([("ai_core", aicore)]),
+            error_codes: Some(error_codes.to_vec()),
+        };
+        let snapshot = |chips| SystemSnapshot {
+            timestamp_ms: 0,
+            dcmi_version: None,
+            driver_version: Some("24.1.rc2".to_string()),
+            cards: vec![CardSnapshot { id: 0, chips }],
+        };
+        let old = snapshot(vec![
+            chip(0, HealthState::Normal, &[], 10),
+            chip(1, HealthState::Normal, &[0x80e01801], 0),
+        ]);
+        let new = snapshot(vec![
+            chip(0, HealthState::MajorAlarm, &[0x80e18402], 10),
+            chip(2, HealthState::Normal, &[], 0),
+        ]);
+        assert!(old.diff(&old).is_empty());
+        let changes: Vec<_> = old
+            .diff(&new)
+            .into_iter()
+            .map(|change| (change.chip_id, change.kind))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (
+                    Some(0),
+                    ChangeKind::Health {
+                        from: Some(HealthState::Normal),
+                        to: Some(HealthState::MajorAlarm)
+                    }
+                ),
+                (Some(0), ChangeKind::NewErrors(vec![0x80e18402])),
+                (Some(1), ChangeKind::ChipRemoved),
+                (Some(2), ChangeKind::ChipAdded),
+            ]
+        );
+    }
+}
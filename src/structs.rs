@@ -0,0 +1,1175 @@
+use crate::enums::{FrequencyType, VoltageRail};
+use crate::error::VChipResError;
+use crate::hw_dcmi_sys::*;
+use crate::units::{Celsius, Mebibytes, MegaHertz, Millivolts};
+use crate::utils::{string_from_c_chars, string_from_c_uchars};
+use std::fmt;
+
+/// Unique identifier of a die, burnt in during manufacturing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DieInfo {
+    pub soc_die: [u32; DIE_ID_COUNT as usize],
+}
+
+impl From<dcmi_die_id> for DieInfo {
+    fn from(value: dcmi_die_id) -> Self {
+        DieInfo {
+            soc_die: value.soc_die,
+        }
+    }
+}
+
+/// Model information of a chip
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChipInfo {
+    /// Chip family, e.g. `Ascend`
+    pub chip_type: String,
+    /// Chip model, e.g. `910B3`
+    pub chip_name: String,
+    pub chip_version: String,
+    pub aicore_count: u32,
+}
+
+impl ChipInfo {
+    /// Full model name, e.g. `Ascend910B3`
+    pub fn model(&self) -> String {
+        format!("{}{}", self.chip_type, self.chip_name)
+    }
+}
+
+impl fmt::Display for ChipInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}, {} AI cores",
+            self.model(),
+            self.chip_version,
+            self.aicore_count
+        )
+    }
+}
+
+impl From<dcmi_chip_info> for ChipInfo {
+    fn from(value: dcmi_chip_info) -> Self {
+        ChipInfo {
+            chip_type: string_from_c_uchars(&value.chip_type),
+            chip_name: string_from_c_uchars(&value.chip_name),
+            chip_version: string_from_c_uchars(&value.chip_ver),
+            aicore_count: value.aicore_cnt,
+        }
+    }
+}
+
+/// Board the chip is mounted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoardInfo {
+    pub board_id: u32,
+    pub pcb_id: u32,
+    pub bom_id: u32,
+    pub slot_id: u32,
+}
+
+impl From<dcmi_board_info> for BoardInfo {
+    fn from(value: dcmi_board_info) -> Self {
+        BoardInfo {
+            board_id: value.board_id,
+            pcb_id: value.pcb_id,
+            bom_id: value.bom_id,
+            slot_id: value.slot_id,
+        }
+    }
+}
+
+/// Electronic label of a card or chip
+///
+/// The label written by DCMI has no hardware revision or part number, see
+/// [`BoardInfo`] for the PCB and BOM ids.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ELabelInfo {
+    pub product_name: String,
+    pub model: String,
+    /// Vendor name
+    pub manufacturer: String,
+    /// Date of manufacture as written to the label, empty if the label has none
+    pub manufacture_date: String,
+    pub serial_number: String,
+}
+
+impl From<dcmi_elabel_info> for ELabelInfo {
+    fn from(value: dcmi_elabel_info) -> Self {
+        ELabelInfo {
+            product_name: string_from_c_chars(&value.product_name),
+            model: string_from_c_chars(&value.model),
+            manufacturer: string_from_c_chars(&value.manufacturer),
+            manufacture_date: string_from_c_chars(&value.manufacturer_date),
+            serial_number: string_from_c_chars(&value.serial_number),
+        }
+    }
+}
+
+/// Serial numbers of a card as reported by its MCU and by each of its chips
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityCheck {
+    /// Electronic label read by the MCU
+    pub card: ELabelInfo,
+    /// Electronic label reported by each NPU chip, with the chip id
+    pub chips: Vec<(u32, ELabelInfo)>,
+}
+
+impl IdentityCheck {
+    /// Ids of the chips whose serial number differs from the serial number of the card
+    ///
+    /// A chip without a serial number does not count as a mismatch.
+    pub fn mismatched_chips(&self) -> Vec<u32> {
+        self.chips
+            .iter()
+            .filter(|(_, elabel)| {
+                !elabel.serial_number.is_empty() && elabel.serial_number != self.card.serial_number
+            })
+            .map(|&(id, _)| id)
+            .collect()
+    }
+
+    /// Whether the card has a serial number and no chip disagrees with it
+    ///
+    /// A mismatch points to a refurbished or mis-flashed board.
+    pub fn is_consistent(&self) -> bool {
+        !self.card.serial_number.is_empty() && self.mismatched_chips().is_empty()
+    }
+}
+
+/// ECC state and error statistics of a memory type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ECCInfo {
+    pub enable: bool,
+    /// Single-bit errors since the last statistics reset
+    pub single_bit_error_cnt: u32,
+    /// Double-bit errors since the last statistics reset
+    pub double_bit_error_cnt: u32,
+    pub total_single_bit_error_cnt: u32,
+    pub total_double_bit_error_cnt: u32,
+    /// Pages isolated because of single-bit errors
+    pub single_bit_isolated_pages_cnt: u32,
+    /// Pages isolated because of double-bit errors
+    pub double_bit_isolated_pages_cnt: u32,
+}
+
+impl From<dcmi_ecc_info> for ECCInfo {
+    fn from(value: dcmi_ecc_info) -> Self {
+        ECCInfo {
+            enable: value.enable_flag != 0,
+            single_bit_error_cnt: value.single_bit_error_cnt,
+            double_bit_error_cnt: value.double_bit_error_cnt,
+            total_single_bit_error_cnt: value.total_single_bit_error_cnt,
+            total_double_bit_error_cnt: value.total_double_bit_error_cnt,
+            single_bit_isolated_pages_cnt: value.single_bit_isolated_pages_cnt,
+            double_bit_isolated_pages_cnt: value.double_bit_isolated_pages_cnt,
+        }
+    }
+}
+
+impl ECCInfo {
+    /// Whether uncorrectable (double-bit) errors occurred since the last statistics reset
+    pub fn has_uncorrectable(&self) -> bool {
+        self.double_bit_error_cnt > 0
+    }
+}
+
+impl fmt::Display for ECCInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.enable {
+            return write!(f, "disabled");
+        }
+        write!(
+            f,
+            "{} single-bit / {} double-bit errors (total {} / {}), {} pages isolated",
+            self.single_bit_error_cnt,
+            self.double_bit_error_cnt,
+            self.total_single_bit_error_cnt,
+            self.total_double_bit_error_cnt,
+            self.single_bit_isolated_pages_cnt + self.double_bit_isolated_pages_cnt
+        )
+    }
+}
+
+/// ECC statistics of the DDR and HBM of a chip, memories the chip does not have are `None`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ECCSummary {
+    pub ddr: Option<ECCInfo>,
+    pub hbm: Option<ECCInfo>,
+}
+
+impl ECCSummary {
+    /// Whether any memory had uncorrectable errors since the last statistics reset
+    pub fn has_uncorrectable(&self) -> bool {
+        self.memories().any(|info| info.has_uncorrectable())
+    }
+
+    /// Single-bit errors of all memories since the last statistics reset
+    pub fn single_bit_error_cnt(&self) -> u32 {
+        self.memories().map(|info| info.single_bit_error_cnt).sum()
+    }
+
+    /// Double-bit errors of all memories since the last statistics reset
+    pub fn double_bit_error_cnt(&self) -> u32 {
+        self.memories().map(|info| info.double_bit_error_cnt).sum()
+    }
+
+    /// Pages of all memories isolated because of ECC errors
+    pub fn isolated_pages_cnt(&self) -> u32 {
+        self.memories()
+            .map(|info| info.single_bit_isolated_pages_cnt + info.double_bit_isolated_pages_cnt)
+            .sum()
+    }
+
+    fn memories(&self) -> impl Iterator<Item = &ECCInfo> {
+        self.ddr.iter().chain(self.hbm.iter())
+    }
+}
+
+/// Memory (DDR) information of a chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MemoryInfo {
+    /// Total memory
+    pub memory_size: Mebibytes,
+    /// Available memory
+    pub memory_available: Mebibytes,
+    /// Memory frequency
+    pub freq: MegaHertz,
+    /// Huge page size, unit: KB
+    pub hugepage_size: u64,
+    pub hugepages_total: u64,
+    pub hugepages_free: u64,
+    /// Memory utilization, unit: %
+    pub utilization: u32,
+}
+
+impl From<dcmi_get_memory_info_stru> for MemoryInfo {
+    fn from(value: dcmi_get_memory_info_stru) -> Self {
+        MemoryInfo {
+            memory_size: Mebibytes(value.memory_size),
+            memory_available: Mebibytes(value.memory_available),
+            freq: MegaHertz(value.freq),
+            hugepage_size: value.hugepagesize,
+            hugepages_total: value.hugepages_total,
+            hugepages_free: value.hugepages_free,
+            utilization: value.utiliza,
+        }
+    }
+}
+
+impl MemoryInfo {
+    /// Memory reported by the calls before v3, without huge pages
+    fn without_hugepages(memory_size: u64, freq: u32, utilization: u32) -> Self {
+        let used = memory_size * u64::from(utilization.min(100)) / 100;
+        MemoryInfo {
+            memory_size: Mebibytes(memory_size),
+            memory_available: Mebibytes(memory_size).saturating_sub(Mebibytes(used)),
+            freq: MegaHertz(freq),
+            hugepage_size: 0,
+            hugepages_total: 0,
+            hugepages_free: 0,
+            utilization,
+        }
+    }
+}
+
+impl From<dcmi_memory_info> for MemoryInfo {
+    fn from(value: dcmi_memory_info) -> Self {
+        MemoryInfo::without_hugepages(value.memory_size, value.freq, value.utiliza)
+    }
+}
+
+impl From<dcmi_memory_info_stru> for MemoryInfo {
+    fn from(value: dcmi_memory_info_stru) -> Self {
+        MemoryInfo::without_hugepages(value.memory_size, value.freq, value.utiliza)
+    }
+}
+
+impl fmt::Display for MemoryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} used ({}%), {}",
+            self.memory_size.saturating_sub(self.memory_available).0,
+            self.memory_size,
+            self.utilization,
+            self.freq
+        )
+    }
+}
+
+/// HBM information of a chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HBMInfo {
+    /// Total HBM
+    pub memory_size: Mebibytes,
+    /// HBM frequency
+    pub freq: MegaHertz,
+    /// Used HBM
+    pub memory_usage: Mebibytes,
+    /// HBM temperature
+    pub temp: Celsius,
+    /// HBM bandwidth utilization, unit: %
+    pub bandwidth_util_rate: u32,
+}
+
+impl From<dcmi_hbm_info> for HBMInfo {
+    fn from(value: dcmi_hbm_info) -> Self {
+        HBMInfo {
+            memory_size: Mebibytes(value.memory_size),
+            freq: MegaHertz(value.freq),
+            memory_usage: Mebibytes(value.memory_usage),
+            temp: Celsius(value.temp),
+            bandwidth_util_rate: value.bandwith_util_rate,
+        }
+    }
+}
+
+impl fmt::Display for HBMInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} used, bandwidth {}%, {}, {}",
+            self.memory_usage.0, self.memory_size, self.bandwidth_util_rate, self.temp, self.freq
+        )
+    }
+}
+
+/// Faulty memory address recorded by the driver after an ECC error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ECCAddressRecord {
+    pub physical_addr: u64,
+    /// HBM stack and pseudo channel of the address
+    pub stack_pc_id: u32,
+    pub reg_addr_h: u32,
+    pub reg_addr_l: u32,
+    /// Number of errors seen at the address
+    pub ecc_count: u32,
+    /// Time of the last error, as reported by the driver
+    pub timestamp: i32,
+}
+
+impl From<dcmi_ecc_common_data> for ECCAddressRecord {
+    fn from(value: dcmi_ecc_common_data) -> Self {
+        ECCAddressRecord {
+            physical_addr: value.physical_addr,
+            stack_pc_id: value.stack_pc_id,
+            reg_addr_h: value.reg_addr_h,
+            reg_addr_l: value.reg_addr_l,
+            ecc_count: value.ecc_count,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+/// PCIe link error statistics of a chip
+///
+/// `*_status` fields are interrupt or error status registers, the other fields are error
+/// counters accumulated since the last reset of the statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PCIEErrorInfo {
+    pub deskew_fifo_overflow_intr_status: u32,
+    pub symbol_unlock_intr_status: u32,
+    pub deskew_unlock_intr_status: u32,
+    pub phystatus_timeout_intr_status: u32,
+    pub symbol_unlock_counter: u32,
+    pub pcs_rx_err_cnt: u32,
+    pub phy_lane_err_counter: u32,
+    pub pcs_rcv_err_status: u32,
+    pub symbol_unlock_err_status: u32,
+    pub phy_lane_err_status: u32,
+    /// Data link layer LCRC errors
+    pub dl_lcrc_err_num: u32,
+    /// Data link layer DCRC errors
+    pub dl_dcrc_err_num: u32,
+}
+
+impl From<dcmi_chip_pcie_err_rate> for PCIEErrorInfo {
+    fn from(value: dcmi_chip_pcie_err_rate) -> Self {
+        PCIEErrorInfo {
+            deskew_fifo_overflow_intr_status: value.reg_deskew_fifo_overflow_intr_status,
+            symbol_unlock_intr_status: value.reg_symbol_unlock_intr_status,
+            deskew_unlock_intr_status: value.reg_deskew_unlock_intr_status,
+            phystatus_timeout_intr_status: value.reg_phystatus_timeout_intr_status,
+            symbol_unlock_counter: value.symbol_unlock_counter,
+            pcs_rx_err_cnt: value.pcs_rx_err_cnt,
+            phy_lane_err_counter: value.phy_lane_err_counter,
+            pcs_rcv_err_status: value.pcs_rcv_err_status,
+            symbol_unlock_err_status: value.symbol_unlock_err_status,
+            phy_lane_err_status: value.phy_lane_err_status,
+            dl_lcrc_err_num: value.dl_lcrc_err_num,
+            dl_dcrc_err_num: value.dl_dcrc_err_num,
+        }
+    }
+}
+
+/// PCIe identity and address of a chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PCIEInfo {
+    pub vender_id: u32,
+    pub subvender_id: u32,
+    pub device_id: u32,
+    pub subdevice_id: u32,
+    pub domain: i32,
+    pub bdf_bus_id: u32,
+    pub bdf_device_id: u32,
+    pub bdf_func_id: u32,
+}
+
+impl PCIEInfo {
+    /// PCI address in the `domain:bus:device.function` form used by sysfs and lspci
+    pub fn bdf(&self) -> String {
+        format!(
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            self.domain, self.bdf_bus_id, self.bdf_device_id, self.bdf_func_id
+        )
+    }
+}
+
+impl From<dcmi_pcie_info> for PCIEInfo {
+    fn from(value: dcmi_pcie_info) -> Self {
+        PCIEInfo {
+            vender_id: value.venderid,
+            subvender_id: value.subvenderid,
+            device_id: value.deviceid,
+            subdevice_id: value.subdeviceid,
+            domain: 0,
+            bdf_bus_id: value.bdf_busid,
+            bdf_device_id: value.bdf_deviceid,
+            bdf_func_id: value.bdf_funcid,
+        }
+    }
+}
+
+impl From<dcmi_pcie_info_all> for PCIEInfo {
+    fn from(value: dcmi_pcie_info_all) -> Self {
+        PCIEInfo {
+            vender_id: value.venderid,
+            subvender_id: value.subvenderid,
+            device_id: value.deviceid,
+            subdevice_id: value.subdeviceid,
+            domain: value.domain,
+            bdf_bus_id: value.bdf_busid,
+            bdf_device_id: value.bdf_deviceid,
+            bdf_func_id: value.bdf_funcid,
+        }
+    }
+}
+
+impl fmt::Display for PCIEInfo {
+    /// Address and vendor:device ids in the form printed by `lspci -nn`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{:04x}:{:04x}]",
+            self.bdf(),
+            self.vender_id,
+            self.device_id
+        )
+    }
+}
+
+/// Voltage and current of a voltage rail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoltageRailInfo {
+    pub rail: VoltageRail,
+    pub voltage: Millivolts,
+    /// unit: mA
+    pub current: u32,
+}
+
+/// AI CPUs of the chip lent to the host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostAiCpuInfo {
+    pub num: u32,
+    /// Bit `n` is set if AI CPU `n` is used by the host
+    pub bitmap: [u64; 8],
+    pub work_mode: u32,
+}
+
+impl From<dcmi_host_aicpu_info> for HostAiCpuInfo {
+    fn from(value: dcmi_host_aicpu_info) -> Self {
+        HostAiCpuInfo {
+            num: value.num,
+            bitmap: value.bitmap,
+            work_mode: value.work_mode,
+        }
+    }
+}
+
+/// Control CPU of a chip, the cores running the device OS and the runtime
+///
+/// DCMI reports the utilization of the control CPU as a whole, not per core.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CtrlCpuInfo {
+    pub core_count: u32,
+    pub frequency: MegaHertz,
+    /// Utilization of all control CPU cores, unit: %
+    pub utilization: u32,
+}
+
+/// Lanes of the HCCS ports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HccsLaneInfo {
+    /// Bit `n` is set if HCCS port `n` is present
+    pub port_bitmap: u32,
+    /// Active lanes, indexed by HCCS port
+    pub lane_bitmap: [u32; 16],
+}
+
+impl From<dcmi_hccs_lane_info> for HccsLaneInfo {
+    fn from(value: dcmi_hccs_lane_info) -> Self {
+        HccsLaneInfo {
+            port_bitmap: value.hccs_port_pcs_bitmap,
+            lane_bitmap: value.pcs_lane_bitmap,
+        }
+    }
+}
+
+/// Packet and error counters of the HCCS ports, indexed by HCCS port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HccsStatistics {
+    pub tx_count: [u32; 16],
+    pub rx_count: [u32; 16],
+    pub crc_error_count: [u32; 16],
+}
+
+impl From<dcmi_hccs_statistic_info> for HccsStatistics {
+    fn from(value: dcmi_hccs_statistic_info) -> Self {
+        HccsStatistics {
+            tx_count: value.tx_cnt,
+            rx_count: value.rx_cnt,
+            crc_error_count: value.crc_err_cnt,
+        }
+    }
+}
+
+/// CRC errors on the SIO bus between the dies of a chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SioCrcErrors {
+    pub tx_error_count: u16,
+    pub rx_error_count: u16,
+}
+
+impl From<dcmi_sio_crc_err_statistics_info> for SioCrcErrors {
+    fn from(value: dcmi_sio_crc_err_statistics_info) -> Self {
+        SioCrcErrors {
+            tx_error_count: value.tx_error_count,
+            rx_error_count: value.rx_error_count,
+        }
+    }
+}
+
+/// Position of a chip inside a super pod
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuperPodInfo {
+    /// Super device id, unique inside the super pod
+    pub sdid: u32,
+    pub super_pod_size: u32,
+    pub super_pod_id: u32,
+    pub server_index: u32,
+}
+
+impl From<dcmi_spod_info> for SuperPodInfo {
+    fn from(value: dcmi_spod_info) -> Self {
+        SuperPodInfo {
+            sdid: value.sdid,
+            super_pod_size: value.super_pod_size,
+            super_pod_id: value.super_pod_id,
+            server_index: value.server_index,
+        }
+    }
+}
+
+/// Configured computing power tier of the chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkTops {
+    /// unit: TOPS
+    pub work_tops: u32,
+    /// Whether the setting is persisted in flash
+    pub is_in_flash: bool,
+}
+
+impl From<dcmi_lp_work_tops_stru> for WorkTops {
+    fn from(value: dcmi_lp_work_tops_stru) -> Self {
+        WorkTops {
+            work_tops: value.work_tops,
+            is_in_flash: value.is_in_flash != 0,
+        }
+    }
+}
+
+/// Computing power token of a chip
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputeTokenInfo {
+    /// Computing power unlocked by the token
+    pub value: f32,
+    /// Token type as reported by the driver
+    pub token_type: u8,
+}
+
+impl ComputeTokenInfo {
+    /// Whether the token unlocks any computing power
+    pub fn is_activated(&self) -> bool {
+        self.value > 0.0
+    }
+}
+
+impl From<crate::device::dcmi_computing_token_stru> for ComputeTokenInfo {
+    fn from(value: crate::device::dcmi_computing_token_stru) -> Self {
+        ComputeTokenInfo {
+            value: value.value,
+            token_type: value.token_type,
+        }
+    }
+}
+
+/// Resources of a chip not assigned to any vNPU yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VChipFreeResources {
+    /// Number of free virtual function groups
+    pub vfg_num: u32,
+    /// Bit `n` is set if virtual function group `n` is free
+    pub vfg_bitmap: u32,
+    pub aicore: f32,
+    pub vector_core: f32,
+    pub device_aicpu: u16,
+    pub memory_size: Mebibytes,
+}
+
+impl From<dcmi_soc_free_resource> for VChipFreeResources {
+    fn from(value: dcmi_soc_free_resource) -> Self {
+        VChipFreeResources {
+            vfg_num: value.vfg_num,
+            vfg_bitmap: value.vfg_bitmap,
+            aicore: value.computing.aic,
+            vector_core: value.computing.aiv,
+            device_aicpu: value.computing.device_aicpu,
+            memory_size: Mebibytes(value.computing.memory_size),
+        }
+    }
+}
+
+/// Device memory used by a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessMemoryInfo {
+    pub pid: i32,
+    pub memory_usage: Mebibytes,
+}
+
+impl From<dcmi_proc_mem_info> for ProcessMemoryInfo {
+    fn from(value: dcmi_proc_mem_info) -> Self {
+        ProcessMemoryInfo {
+            pid: value.proc_id,
+            memory_usage: Mebibytes(value.proc_mem_usage),
+        }
+    }
+}
+
+/// Result of preparing a chip for PCI passthrough
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassthroughReadiness {
+    /// PCI address of the chip
+    pub bdf: String,
+    /// Host processes using the chip
+    pub processes: Vec<ProcessMemoryInfo>,
+    /// Driver the chip is bound to after preparation
+    pub driver: Option<String>,
+    /// Host driver unbound during preparation
+    pub unbound_driver: Option<String>,
+    /// Reset methods offered by the kernel (e.g. `flr`, `bus`), empty if the chip cannot be reset
+    pub reset_methods: Vec<String>,
+}
+
+impl PassthroughReadiness {
+    /// Whether the chip can be reset by the hypervisor between VM assignments
+    pub fn supports_hot_reset(&self) -> bool {
+        !self.reset_methods.is_empty()
+    }
+
+    /// No host process holds the chip, no host driver is bound and the chip can be reset
+    pub fn is_ready(&self) -> bool {
+        self.processes.is_empty()
+            && self
+                .driver
+                .as_deref()
+                .is_none_or(|driver| driver == crate::device::PASSTHROUGH_DRIVER)
+            && self.supports_hot_reset()
+    }
+}
+
+/// Longest template name DCMI accepts, the template buffer keeps one byte for the NUL terminator
+pub const VCHIP_TEMPLATE_NAME_MAX_LEN: usize = 31;
+/// Number of virtual function groups of a chip, one bit each in
+/// [`VChipFreeResources::vfg_bitmap`]
+pub const VFG_COUNT: u32 = 32;
+/// Virtual function group id which lets the driver choose a free group
+pub const VFG_ID_AUTO: u32 = u32::MAX;
+
+/// Parameters of a vNPU to create
+///
+/// Use [`VChipRes::builder`] to validate the parameters when building them, requests built
+/// directly are validated by [`Chip::create_vchip`](crate::device::Chip::create_vchip).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VChipRes {
+    /// Id of the vNPU
+    pub vchip_id: u32,
+    /// Virtual function group the vNPU is placed in
+    pub vfg_id: u32,
+    /// Name of the resource template, e.g. `vir02`
+    pub template_name: String,
+}
+
+impl VChipRes {
+    pub fn builder() -> VChipResBuilder {
+        VChipResBuilder::default()
+    }
+
+    /// Check the parameters against the limits of DCMI
+    pub fn validate(&self) -> Result<(), VChipResError> {
+        if self.vchip_id >= crate::vnpu::ALL_VCHIPS {
+            return Err(VChipResError::InvalidVChipId(self.vchip_id));
+        }
+        if self.vfg_id >= VFG_COUNT && self.vfg_id != VFG_ID_AUTO {
+            return Err(VChipResError::InvalidVfgId(self.vfg_id));
+        }
+        let name = &self.template_name;
+        if name.is_empty() {
+            return Err(VChipResError::EmptyTemplateName);
+        }
+        if name.len() > VCHIP_TEMPLATE_NAME_MAX_LEN {
+            return Err(VChipResError::TemplateNameTooLong(name.len()));
+        }
+        if name.contains('\0') {
+            return Err(VChipResError::TemplateNameContainsNul);
+        }
+        Ok(())
+    }
+}
+
+/// Builder of a validated [`VChipRes`]
+#[derive(Debug, Clone, Default)]
+pub struct VChipResBuilder {
+    vchip_id: u32,
+    vfg_id: u32,
+    template_name: String,
+}
+
+impl VChipResBuilder {
+    /// Id of the vNPU, 0 by default
+    pub fn vchip_id(mut self, vchip_id: u32) -> Self {
+        self.vchip_id = vchip_id;
+        self
+    }
+
+    /// Virtual function group, 0 by default, [`VFG_ID_AUTO`] lets the driver choose
+    pub fn vfg_id(mut self, vfg_id: u32) -> Self {
+        self.vfg_id = vfg_id;
+        self
+    }
+
+    /// Name of the resource template, required
+    pub fn template_name(mut self, template_name: impl Into<String>) -> Self {
+        self.template_name = template_name.into();
+        self
+    }
+
+    pub fn build(self) -> Result<VChipRes, VChipResError> {
+        let res = VChipRes {
+            vchip_id: self.vchip_id,
+            vfg_id: self.vfg_id,
+            template_name: self.template_name,
+        };
+        res.validate()?;
+        Ok(res)
+    }
+}
+
+impl TryFrom<&VChipRes> for dcmi_create_vdev_res_stru {
+    type Error = VChipResError;
+
+    fn try_from(value: &VChipRes) -> Result<Self, Self::Error> {
+        value.validate()?;
+        let mut res = dcmi_create_vdev_res_stru {
+            vdev_id: value.vchip_id,
+            vfg_id: value.vfg_id,
+            template_name: [0; 32],
+            reserved: [0; 64],
+        };
+        // validated to leave the last byte as NUL terminator
+        for (dst, &src) in res
+            .template_name
+            .iter_mut()
+            .zip(value.template_name.as_bytes())
+        {
+            *dst = src as std::ffi::c_char;
+        }
+        Ok(res)
+    }
+}
+
+/// Ids and PCI address assigned to a created vNPU
+///
+/// The resources of the vNPU are reported by
+/// [`VirtualChip::info`](crate::vnpu::VirtualChip::info).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VChipOutput {
+    pub vchip_id: u32,
+    pub vfg_id: u32,
+    pub pcie_bus: u32,
+    pub pcie_device: u32,
+    pub pcie_function: u32,
+}
+
+impl VChipOutput {
+    /// PCI address of the vNPU in the `domain:bus:device.function` form used by sysfs, DCMI does
+    /// not report the domain, it is the one of the chip, see [`PCIEInfo::domain`]
+    pub fn bdf(&self, domain: i32) -> String {
+        format!(
+            "{:04x}:{:02x}:{:02x}.{:x}",
+            domain, self.pcie_bus, self.pcie_device, self.pcie_function
+        )
+    }
+}
+
+impl From<dcmi_create_vdev_out> for VChipOutput {
+    fn from(value: dcmi_create_vdev_out) -> Self {
+        VChipOutput {
+            vchip_id: value.vdev_id,
+            vfg_id: value.vfg_id,
+            pcie_bus: value.pcie_bus,
+            pcie_device: value.pcie_device,
+            pcie_function: value.pcie_func,
+        }
+    }
+}
+
+/// State and resources of a vNPU
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualChipInfo {
+    /// Name of the template the vNPU was created from
+    pub name: String,
+    pub status: u32,
+    /// Whether a container uses the vNPU
+    pub is_container_used: bool,
+    pub container_id: u64,
+    pub vfid: u32,
+    pub vfg_id: u32,
+    pub aicore: f32,
+    pub vector_core: f32,
+    pub memory_size: Mebibytes,
+    /// unit: %
+    pub aicore_utilization: u32,
+    pub memory_total: u64,
+    pub memory_free: u64,
+}
+
+impl From<dcmi_vdev_query_info> for VirtualChipInfo {
+    fn from(value: dcmi_vdev_query_info) -> Self {
+        VirtualChipInfo {
+            name: string_from_c_chars(&value.name),
+            status: value.status,
+            is_container_used: value.is_container_used != 0,
+            container_id: value.container_id,
+            vfid: value.vfid,
+            vfg_id: value.vfg_id,
+            aicore: value.computing.aic,
+            vector_core: value.computing.aiv,
+            memory_size: Mebibytes(value.computing.memory_size),
+            aicore_utilization: value.computing.vdev_aicore_utilization,
+            memory_total: value.computing.vdev_memory_total,
+            memory_free: value.computing.vdev_memory_free,
+        }
+    }
+}
+
+/// PCIe transaction size settings of a chip, unit: byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PCIEPayloadSettings {
+    /// Max payload size (MPS) in use
+    pub max_payload_size: u32,
+    /// Largest max payload size the device supports
+    pub max_payload_size_supported: u32,
+    /// Max read request size (MRRS) in use
+    pub max_read_request_size: u32,
+}
+
+/// Negotiated and maximum PCIe link of a chip
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PCIELinkStatus {
+    /// Current link speed, unit: GT/s
+    pub speed: f32,
+    /// Highest link speed supported by the chip, unit: GT/s
+    pub max_speed: f32,
+    /// Current number of lanes
+    pub width: u32,
+    /// Number of lanes supported by the chip
+    pub max_width: u32,
+}
+
+impl PCIELinkStatus {
+    /// Whether the link trained below the speed or width supported by the chip
+    ///
+    /// A downtrained link usually points to a bad riser, slot or upstream port, the limit may also
+    /// come from the upstream port supporting less than the chip.
+    pub fn is_downtrained(&self) -> bool {
+        self.speed < self.max_speed || self.width < self.max_width
+    }
+}
+
+/// SR-IOV virtual function of a chip
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualFunction {
+    /// Index of the VF (`virtfn<index>` in sysfs)
+    pub index: u32,
+    /// PCI address of the VF
+    pub bdf: String,
+    /// Kernel driver the VF is bound to (e.g. `vfio-pci` when passed through to a VM)
+    pub driver: Option<String>,
+}
+
+/// Temperatures of the sensors of a chip, read back to back
+///
+/// Sensors the chip does not have are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Temperatures {
+    /// Chip temperature as reported by `get_temperature`
+    pub chip: Celsius,
+    /// Hottest AI core cluster
+    pub ai_core: Option<Celsius>,
+    pub hbm: Option<Celsius>,
+    /// Hottest NTC thermistor on the board
+    pub board: Option<Celsius>,
+    /// Hottest of all readings above
+    pub hotspot: Celsius,
+}
+
+impl Temperatures {
+    pub(crate) fn new(
+        chip: Celsius,
+        ai_core: Option<Celsius>,
+        hbm: Option<Celsius>,
+        board: Option<Celsius>,
+    ) -> Self {
+        let hotspot = [ai_core, hbm, board]
+            .into_iter()
+            .flatten()
+            .fold(chip, Celsius::max);
+        Temperatures {
+            chip,
+            ai_core,
+            hbm,
+            board,
+            hotspot,
+        }
+    }
+}
+
+/// Over-temperature thresholds of a chip, set by its firmware
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TemperatureThresholds {
+    /// Above this temperature the chip raises an alarm and throttles
+    pub alarm: Celsius,
+    /// Above this temperature the chip is reset to protect it
+    pub shutdown: Celsius,
+}
+
+impl From<[std::ffi::c_schar; 2]> for TemperatureThresholds {
+    fn from(value: [std::ffi::c_schar; 2]) -> Self {
+        TemperatureThresholds {
+            alarm: Celsius(value[0].into()),
+            shutdown: Celsius(value[1].into()),
+        }
+    }
+}
+
+/// Frequencies of all clocks of a chip, read back to back
+///
+/// Clocks the chip does not have are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Frequencies {
+    pub ddr: Option<MegaHertz>,
+    pub ctrl_cpu: Option<MegaHertz>,
+    pub hbm: Option<MegaHertz>,
+    /// Current AI core frequency, lowered when the chip throttles
+    pub ai_core_current: Option<MegaHertz>,
+    /// Rated AI core frequency
+    pub ai_core_max: Option<MegaHertz>,
+    pub vector_core_current: Option<MegaHertz>,
+}
+
+impl Frequencies {
+    /// Frequency of `frequency_type`
+    pub fn get(&self, frequency_type: FrequencyType) -> Option<MegaHertz> {
+        match frequency_type {
+            FrequencyType::Ddr => self.ddr,
+            FrequencyType::CtrlCpu => self.ctrl_cpu,
+            FrequencyType::Hbm => self.hbm,
+            FrequencyType::AiCoreCurrent => self.ai_core_current,
+            FrequencyType::AiCoreMax => self.ai_core_max,
+            FrequencyType::VectorCoreCurrent => self.vector_core_current,
+        }
+    }
+}
+
+/// Whether the AI cores of a chip run below their rated frequency
+///
+/// The DCMI header defines no values for the frequency reduction cause, so it is kept as
+/// reported. Only zero, not throttled, has a known meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ThrottleStatus {
+    /// Frequency reduction cause as reported by the driver
+    pub cause: u32,
+}
+
+impl ThrottleStatus {
+    pub fn is_throttled(&self) -> bool {
+        self.cause != 0
+    }
+}
+
+impl From<u32> for ThrottleStatus {
+    fn from(cause: u32) -> Self {
+        ThrottleStatus { cause }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_for_operators() {
+        let info = PCIEInfo {
+            vender_id: 0x19e5,
+            subvender_id: 0x0200,
+            device_id: 0xd802,
+            subdevice_id: 0x0100,
+            domain: 0,
+            bdf_bus_id: 0xc1,
+            bdf_device_id: 0,
+            bdf_func_id: 0,
+        };
+        assert_eq!(info.bdf(), "0000:c1:00.0");
+        assert_eq!(info.to_string(), "0000:c1:00.0 [19e5:d802]");
+
+        let memory = MemoryInfo {
+            memory_size: Mebibytes(32768),
+            memory_available: Mebibytes(24576),
+            freq: MegaHertz(2666),
+            hugepage_size: 2048,
+            hugepages_total: 0,
+            hugepages_free: 0,
+            utilization: 25,
+        };
+        assert_eq!(memory.to_string(), "8192 / 32768 MiB used (25%), 2666 MHz");
+    }
+
+    #[test]
+    fn identity_check_flags_foreign_serials() {
+        let elabel = |serial: &str| ELabelInfo {
+            product_name: "Atlas 300I Duo".to_string(),
+            model: String::new(),
+            manufacturer: "Huawei".to_string(),
+            manufacture_date: "2023/05/12".to_string(),
+            serial_number: serial.to_string(),
+        };
+        let mut check = IdentityCheck {
+            card: elabel("102374551"),
+            chips: vec![(0, elabel("102374551")), (1, elabel(""))],
+        };
+        assert!(check.is_consistent());
+        check.chips.push((2, elabel("102399999")));
+        assert_eq!(check.mismatched_chips(), vec![2]);
+        assert!(!check.is_consistent());
+    }
+
+    #[test]
+    fn hotspot_is_hottest_sensor() {
+        let temperatures =
+            Temperatures::new(Celsius(50), Some(Celsius(62)), None, Some(Celsius(40)));
+        assert_eq!(temperatures.hotspot, Celsius(62));
+        let temperatures = Temperatures::new(Celsius(50), None, None, None);
+        assert_eq!(temperatures.hotspot, Celsius(50));
+    }
+
+    #[test]
+    fn ecc_summary_merges_memories() {
+        let hbm = ECCInfo {
+            enable: true,
+            single_bit_error_cnt: 3,
+            double_bit_error_cnt: 1,
+            total_single_bit_error_cnt: 3,
+            total_double_bit_error_cnt: 1,
+            single_bit_isolated_pages_cnt: 0,
+            double_bit_isolated_pages_cnt: 1,
+        };
+        let summary = ECCSummary {
+            ddr: None,
+            hbm: Some(hbm),
+        };
+        assert!(summary.has_uncorrectable());
+        assert_eq!(summary.single_bit_error_cnt(), 3);
+        assert_eq!(summary.isolated_pages_cnt(), 1);
+        assert!(!ECCSummary::default().has_uncorrectable());
+    }
+
+    #[test]
+    fn vchip_res_keeps_template_nul_terminated() {
+        let res = VChipRes {
+            vchip_id: 100,
+            vfg_id: 0xFFFFFFFF,
+            template_name: "vir02".to_string(),
+        };
+        let raw = dcmi_create_vdev_res_stru::try_from(&res).unwrap();
+        assert_eq!(string_from_c_chars(&raw.template_name), "vir02");
+        assert_eq!(raw.vdev_id, 100);
+
+        let out = dcmi_create_vdev_out {
+            vdev_id: 100,
+            pcie_bus: 0xc1,
+            pcie_device: 0,
+            pcie_func: 3,
+            vfg_id: 1,
+            reserved: [0; 32],
+        };
+        assert_eq!(VChipOutput::from(out).bdf(0), "0000:c1:00.3");
+
+        let long = "vir04_3c_ndvpp_with_a_much_longer_suffix";
+        let builder = VChipRes::builder().vchip_id(100).template_name(long);
+        assert_eq!(
+            builder.clone().build(),
+            Err(VChipResError::TemplateNameTooLong(long.len()))
+        );
+        assert_eq!(
+            builder.template_name("vir02").vfg_id(32).build(),
+            Err(VChipResError::InvalidVfgId(32))
+        );
+        let res = VChipRes {
+            template_name: long.to_string(),
+            ..res
+        };
+        let error =
+            crate::error::DCMIError::from(dcmi_create_vdev_res_stru::try_from(&res).unwrap_err());
+        assert_eq!(
+            error.to_string(),
+            "Invalid vNPU parameters: template name is 40 bytes long, at most 31 bytes are allowed"
+        );
+    }
+
+    #[test]
+    fn any_cause_is_throttled() {
+        assert!(ThrottleStatus::from(0b101).is_throttled());
+        assert!(ThrottleStatus::from(1 << 8).is_throttled());
+        assert!(!ThrottleStatus::from(0).is_throttled());
+    }
+}
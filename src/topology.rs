@@ -0,0 +1,176 @@
+//! Interconnect topology of the NPUs of a node
+//!
+//! DCMI reports the link between every pair of chips, e.g. whether they share a PCIe switch or
+//! are connected by HCCS. [`DCMI::topology_graph`] collects the links into an undirected graph,
+//! which [`TopologyGraph::to_dot`] renders for Graphviz.
+
+use crate::device::Chip;
+use crate::enums::TopologyType;
+use crate::error::DCMIResult;
+use crate::DCMI;
+use std::fmt::Write;
+
+/// Chip of a [`TopologyGraph`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopologyNode {
+    pub card_id: u32,
+    pub chip_id: u32,
+    /// PCI address, `None` if the chip does not report it
+    pub bdf: Option<String>,
+}
+
+/// Link between two chips of a [`TopologyGraph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TopologyEdge {
+    /// Index of one chip in [`TopologyGraph::nodes`]
+    pub from: usize,
+    /// Index of the other chip in [`TopologyGraph::nodes`]
+    pub to: usize,
+    pub link: TopologyType,
+}
+
+/// Undirected graph of the chips and the links between them, as an edge list
+///
+/// There is one edge per pair of chips whose link DCMI reports, `from` is always the smaller
+/// index.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TopologyGraph {
+    pub nodes: Vec<TopologyNode>,
+    pub edges: Vec<TopologyEdge>,
+}
+
+impl TopologyGraph {
+    /// Index of a chip in [`TopologyGraph::nodes`]
+    pub fn node_index(&self, card_id: u32, chip_id: u32) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.card_id == card_id && node.chip_id == chip_id)
+    }
+
+    /// Chips linked to the chip at `index`, with the link
+    pub fn neighbors(&self, index: usize) -> impl Iterator<Item = (usize, TopologyType)> + '_ {
+        self.edges.iter().filter_map(move |edge| {
+            if edge.from == index {
+                Some((edge.to, edge.link))
+            } else if edge.to == index {
+                Some((edge.from, edge.link))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Link between two chips, `None` if it is unknown
+    pub fn link(&self, a: usize, b: usize) -> Option<TopologyType> {
+        self.neighbors(a)
+            .find(|&(index, _)| index == b)
+            .map(|(_, link)| link)
+    }
+
+    /// Render the graph in the Graphviz DOT language, edges are labelled with the link type
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph npu_topology {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let _ = write!(
+                dot,
+                "    n{index} [label=\"{}/{}",
+                node.card_id, node.chip_id
+            );
+            if let Some(bdf) = &node.bdf {
+                let _ = write!(dot, "\\n{bdf}");
+            }
+            dot.push_str("\"];\n");
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                dot,
+                "    n{} -- n{} [label=\"{}\"];",
+                edge.from,
+                edge.to,
+                edge.link.as_str()
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl DCMI {
+    /// Query the links between all NPUs of the node
+    ///
+    /// Pairs whose link the chips do not report are left out of the graph.
+    pub fn topology_graph(&self) -> DCMIResult<TopologyGraph> {
+        let chips = self.all_chips()?;
+        let nodes = chips
+            .iter()
+            .map(|chip| {
+                Ok(TopologyNode {
+                    card_id: chip.card_id(),
+                    chip_id: chip.id(),
+                    bdf: chip.try_get_pcie_info()?.map(|pcie| pcie.bdf()),
+                })
+            })
+            .collect::<DCMIResult<_>>()?;
+        let mut edges = Vec::new();
+        for (from, chip) in chips.iter().enumerate() {
+            for (to, other) in chips.iter().enumerate().skip(from + 1) {
+                if let Some(link) = topology(chip, other)? {
+                    edges.push(TopologyEdge { from, to, link });
+                }
+            }
+        }
+        Ok(TopologyGraph { nodes, edges })
+    }
+}
+
+/// Link between two chips, `None` if it is not reported
+fn topology(chip: &Chip, other: &Chip) -> DCMIResult<Option<TopologyType>> {
+    match chip.get_topology_to(other) {
+        Ok(link) => Ok(Some(link)),
+        Err(e) if e.is_unsupported() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_dot() {
+        let node = |card_id, bdf: Option<&str>| TopologyNode {
+            card_id,
+            chip_id: 0,
+            bdf: bdf.map(str::to_string),
+        };
+        let graph = TopologyGraph {
+            nodes: vec![node(0, Some("0000:c1:00.0")), node(1, None), node(2, None)],
+            edges: vec![
+                TopologyEdge {
+                    from: 0,
+                    to: 1,
+                    link: TopologyType::Hccs,
+                },
+                TopologyEdge {
+                    from: 1,
+                    to: 2,
+                    link: TopologyType::PcieSwitch,
+                },
+            ],
+        };
+        assert_eq!(graph.node_index(1, 0), Some(1));
+        assert_eq!(graph.link(1, 0), Some(TopologyType::Hccs));
+        assert_eq!(graph.link(0, 2), None);
+        assert_eq!(graph.neighbors(1).count(), 2);
+        assert_eq!(
+            graph.to_dot(),
+            "graph npu_topology {\n\
+             \x20   n0 [label=\"0/0\\n0000:c1:00.0\"];\n\
+             \x20   n1 [label=\"1/0\"];\n\
+             \x20   n2 [label=\"2/0\"];\n\
+             \x20   n0 -- n1 [label=\"hccs\"];\n\
+             \x20   n1 -- n2 [label=\"pcie_switch\"];\n\
+             }\n"
+        );
+    }
+}
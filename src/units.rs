@@ -0,0 +1,148 @@
+//! Physical quantities reported by DCMI
+//!
+//! DCMI reports every quantity as a bare integer in its own scale, e.g. power in 0.1W and voltage
+//! in 0.01V. The wrappers convert them into these newtypes, so the unit is part of the type and a
+//! value cannot be read in the wrong scale.
+//!
+//! ```
+//! use hw_dcmi::units::{Celsius, Mebibytes, Millivolts, Watts};
+//!
+//! assert_eq!(Watts::from_deciwatts(925), Watts(92.5));
+//! assert_eq!(Millivolts::from_centivolts(85).volts(), 0.85);
+//! assert_eq!(Mebibytes(2).bytes(), 2 * 1024 * 1024);
+//! assert_eq!(Celsius(45).to_string(), "45 °C");
+//! assert_eq!(format!("{:.1}", Watts(92.54)), "92.5 W");
+//! ```
+
+use std::fmt;
+
+/// Define a newtype over a number with its unit symbol, `Display` and conversions from and to the
+/// number
+macro_rules! unit {
+    ($(#[$attr:meta])* $name:ident($inner:ty), $symbol:literal, [$($derive:ident),*]) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd $(, $derive)*)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+        pub struct $name(pub $inner);
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            /// The value and unit symbol, a precision applies to the value, e.g. `{:.1}`
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)?;
+                f.write_str(concat!(" ", $symbol))
+            }
+        }
+    };
+}
+
+unit!(
+    /// Power, unit: W
+    Watts(f64),
+    "W",
+    []
+);
+unit!(
+    /// Voltage, unit: mV
+    Millivolts(u32),
+    "mV",
+    [Eq, Ord, Hash]
+);
+unit!(
+    /// Temperature, unit: °C
+    Celsius(i32),
+    "°C",
+    [Eq, Ord, Hash]
+);
+unit!(
+    /// Frequency, unit: MHz
+    MegaHertz(u32),
+    "MHz",
+    [Eq, Ord, Hash]
+);
+unit!(
+    /// Memory size, unit: MiB
+    ///
+    /// DCMI documents memory sizes as MB, they are binary megabytes.
+    Mebibytes(u64),
+    "MiB",
+    [Eq, Ord, Hash]
+);
+
+/// Convert integer quantities into `f64`, e.g. for metrics
+macro_rules! impl_into_f64 {
+    ($($name:ident),*) => {
+        $(
+            impl From<$name> for f64 {
+                fn from(value: $name) -> Self {
+                    value.0 as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_into_f64!(Millivolts, Celsius, MegaHertz, Mebibytes);
+
+impl Watts {
+    /// Convert from the 0.1W steps DCMI reports power in
+    pub fn from_deciwatts(deciwatts: u32) -> Self {
+        Watts(f64::from(deciwatts) / 10.0)
+    }
+}
+
+impl Millivolts {
+    /// Convert from the 0.01V steps DCMI reports the chip voltage in
+    pub fn from_centivolts(centivolts: u32) -> Self {
+        Millivolts(centivolts * 10)
+    }
+
+    pub fn volts(self) -> f64 {
+        f64::from(self.0) / 1000.0
+    }
+}
+
+impl MegaHertz {
+    pub fn hertz(self) -> u64 {
+        u64::from(self.0) * 1_000_000
+    }
+
+    pub fn gigahertz(self) -> f64 {
+        f64::from(self.0) / 1000.0
+    }
+}
+
+impl Mebibytes {
+    pub fn bytes(self) -> u64 {
+        self.0 * 1024 * 1024
+    }
+
+    pub fn gibibytes(self) -> f64 {
+        self.0 as f64 / 1024.0
+    }
+
+    /// `self - other`, 0 if `other` is larger
+    pub fn saturating_sub(self, other: Mebibytes) -> Mebibytes {
+        Mebibytes(self.0.saturating_sub(other.0))
+    }
+
+    /// Share of `total` this size makes up, unit: %, 0 if `total` is 0
+    pub fn percent_of(self, total: Mebibytes) -> f64 {
+        if total.0 == 0 {
+            0.0
+        } else {
+            self.0 as f64 * 100.0 / total.0 as f64
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Detection of in-place driver upgrades
+//!
+//! Information that never changes while a driver is loaded (capabilities, topology, static chip
+//! information) may change when the driver is upgraded without restarting the process. Data
+//! derived from it should be tagged with [`DCMI::driver_generation`] and fetched again once the
+//! generation moves on. The generation is bumped by [`DCMI::check_driver_version`], called
+//! periodically by a [`DriverVersionWatcher`].
+
+use crate::error::DCMIResult;
+use crate::DCMI;
+use std::io;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Driver version seen last and number of changes since
+#[derive(Debug, Default)]
+struct VersionTracker {
+    version: Option<String>,
+    generation: u64,
+}
+
+impl VersionTracker {
+    /// Record the current version, returns whether it differs from the previous one
+    fn update(&mut self, version: String) -> bool {
+        match &self.version {
+            Some(last) if *last == version => false,
+            Some(_) => {
+                self.version = Some(version);
+                self.generation += 1;
+                true
+            }
+            None => {
+                self.version = Some(version);
+                false
+            }
+        }
+    }
+}
+
+static TRACKER: Mutex<VersionTracker> = Mutex::new(VersionTracker {
+    version: None,
+    generation: 0,
+});
+
+impl DCMI {
+    /// Number of driver version changes detected by [`DCMI::check_driver_version`] so far
+    pub fn driver_generation(&self) -> u64 {
+        generation()
+    }
+
+    /// Compare the driver version with the version seen at the previous check
+    ///
+    /// The first check only records the version.
+    ///
+    /// # Returns
+    /// whether the driver was upgraded (or downgraded) since the previous check
+    pub fn check_driver_version(&self) -> DCMIResult<bool> {
+        Ok(record_version(self.get_driver_version()?))
+    }
+}
+
+pub(crate) fn generation() -> u64 {
+    TRACKER.lock().unwrap_or_else(|e| e.into_inner()).generation
+}
+
+fn record_version(version: String) -> bool {
+    TRACKER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .update(version)
+}
+
+/// Background thread checking the driver version at a fixed interval
+///
+/// The thread stops when this value is dropped.
+#[derive(Debug)]
+pub struct DriverVersionWatcher {
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl DriverVersionWatcher {
+    /// Start checking the driver version
+    ///
+    /// # Parameters
+    /// - interval: time between two checks, a version query is cheap so a minute is plenty
+    /// - on_change: called with the new version after an upgrade was detected
+    pub fn spawn<F>(dcmi: Arc<DCMI>, interval: Duration, mut on_change: F) -> io::Result<Self>
+    where
+        F: FnMut(&str) + Send + 'static,
+    {
+        let (stop, stop_rx) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("dcmi-driver-version".to_string())
+            .spawn(move || loop {
+                // a failing query is expected while the driver is being replaced
+                if let Ok(version) = dcmi.get_driver_version() {
+                    if record_version(version.clone()) {
+                        on_change(&version);
+                    }
+                }
+                if stop_rx.recv_timeout(interval) != Err(RecvTimeoutError::Timeout) {
+                    break;
+                }
+            })?;
+        Ok(DriverVersionWatcher {
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+}
+
+impl Drop for DriverVersionWatcher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_counts_version_changes() {
+        let mut tracker = VersionTracker::default();
+        assert!(!tracker.update("24.1.rc1".to_string()));
+        assert!(!tracker.update("24.1.rc1".to_string()));
+        assert!(tracker.update("24.1.rc2".to_string()));
+        assert_eq!(tracker.generation, 1);
+        assert_eq!(tracker.version.as_deref(), Some("24.1.rc2"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::cell::Cell;
+use std::ffi::{c_char, c_uchar};
+
+thread_local! {
+    /// Whether a string converted on this thread was not valid UTF-8, see [`take_malformed`]
+    static MALFORMED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Convert a NUL-terminated C string buffer into a `String`
+///
+/// Reading stops at the first NUL byte or at the end of the buffer, invalid UTF-8 sequences are
+/// replaced with `U+FFFD` and noted for [`take_malformed`].
+pub(crate) fn string_from_c_chars(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    string_from_bytes(&bytes)
+}
+
+/// Convert a NUL-terminated buffer of unsigned C chars into a `String`, like
+/// [`string_from_c_chars`]
+pub(crate) fn string_from_c_uchars(chars: &[c_uchar]) -> String {
+    let len = chars.iter().position(|&c| c == 0).unwrap_or(chars.len());
+    string_from_bytes(&chars[..len])
+}
+
+fn string_from_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(string) => string.to_owned(),
+        Err(_) => {
+            MALFORMED.set(true);
+            String::from_utf8_lossy(bytes).into_owned()
+        }
+    }
+}
+
+/// Whether a string converted on this thread since the last call was not valid UTF-8
+pub(crate) fn take_malformed() -> bool {
+    MALFORMED.replace(false)
+}
+
+/// Call `query` with a buffer of `initial` entries and return the entries it reports
+///
+/// `query` returns the number of entries the driver reported. While they fill the buffer the
+/// list may have been cut short, so the buffer grows up to `limit` entries and `query` runs
+/// again.
+pub(crate) fn query_list<T: Copy + Default, E>(
+    initial: usize,
+    limit: usize,
+    mut query: impl FnMut(&mut [T]) -> Result<usize, E>,
+) -> Result<Vec<T>, E> {
+    let mut list = vec![T::default(); initial];
+    loop {
+        let count = query(&mut list)?;
+        if count < list.len() || list.len() >= limit {
+            list.truncate(count);
+            return Ok(list);
+        }
+        let len = (count.max(list.len()) * 2).min(limit);
+        list.resize(len, T::default());
+    }
+}
+
+/// Add an `as_str` method returning the stable identifier of an enum deriving
+/// `strum::IntoStaticStr`
+macro_rules! impl_as_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Stable snake_case identifier, accepted by `FromStr`
+                pub fn as_str(&self) -> &'static str {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_as_str;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_from_c_chars_stops_at_nul() {
+        let chars = [b'2' as c_char, b'4' as c_char, 0, b'x' as c_char];
+        assert_eq!(string_from_c_chars(&chars), "24");
+        let chars = [b'o' as c_char, b'k' as c_char];
+        assert_eq!(string_from_c_chars(&chars), "ok");
+        assert_eq!(string_from_c_uchars(b"910B3\0\0"), "910B3");
+    }
+
+    #[test]
+    fn query_list_grows_until_the_list_fits() {
+        let cards: Vec<i32> = (0..100).collect();
+        let mut calls = 0;
+        let query = |list: &mut [i32]| -> Result<usize, ()> {
+            calls += 1;
+            let count = cards.len().min(list.len());
+            list[..count].copy_from_slice(&cards[..count]);
+            Ok(count)
+        };
+        assert_eq!(query_list(64, 1024, query), Ok(cards.clone()));
+        assert_eq!(calls, 2);
+        assert_eq!(
+            query_list(16, 32, |list: &mut [i32]| -> Result<usize, ()> {
+                list.fill(1);
+                Ok(list.len())
+            })
+            .map(|list| list.len()),
+            Ok(32)
+        );
+    }
+}
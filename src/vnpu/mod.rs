@@ -0,0 +1,272 @@
+//! vNPUs created by splitting the computing resources of a chip
+
+mod templates;
+
+pub use templates::{templates_for, ChipModelTemplates, VChipTemplate, CHIP_MODEL_TEMPLATES};
+
+use crate::device::{sysfs_error, Chip};
+use crate::error::{call_dcmi_function, convert, DCMIError, DCMIResult};
+use crate::hw_dcmi_sys::*;
+use crate::structs::{VChipOutput, VChipRes, VirtualChipInfo};
+use crate::DCMI;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// vNPU id that makes `dcmi_set_destroy_vdevice` destroy every vNPU of a chip
+pub(crate) const ALL_VCHIPS: u32 = 65535;
+
+/// File in which the driver persists the vNPU configuration while the recover mode is enabled
+pub const VCHIP_CONFIG_FILE: &str = "/etc/vnpu.cfg";
+
+/// State of the vNPU configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VChipConfigFile {
+    pub path: PathBuf,
+    /// Size of the file, unit: byte
+    pub size: u64,
+    /// Time of the last write, `None` if the file system does not record it
+    pub modified: Option<SystemTime>,
+}
+
+impl VChipConfigFile {
+    /// Read the state of the configuration file at `path`, `None` if it does not exist
+    pub fn read(path: impl AsRef<Path>) -> DCMIResult<Option<Self>> {
+        let path = path.as_ref();
+        let metadata = match std::fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(sysfs_error(e)),
+        };
+        Ok(Some(VChipConfigFile {
+            path: path.to_path_buf(),
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+        }))
+    }
+}
+
+/// Outcome of [`Chip::destroy_all_virtual_chips`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DestroyAllReport {
+    /// Ids of the destroyed vNPUs
+    pub destroyed: Vec<u32>,
+    /// vNPUs that still exist, with the error of destroying them individually
+    pub failed: Vec<(u32, DCMIError)>,
+}
+
+impl DestroyAllReport {
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// vNPU of a physical chip
+#[derive(Debug, Clone, Copy)]
+pub struct VirtualChip<'a> {
+    chip: Chip<'a>,
+    id: u32,
+}
+
+impl<'a> VirtualChip<'a> {
+    /// Physical chip the vNPU was split from
+    pub fn chip(&self) -> Chip<'a> {
+        self.chip
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Query the state and resources of the vNPU
+    pub fn info(&self) -> DCMIResult<VirtualChipInfo> {
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut query: dcmi_vdev_query_stru = unsafe { std::mem::zeroed() };
+        query.vdev_id = self.id;
+        let query = self.chip.get_device_info_with(
+            dcmi_main_cmd_DCMI_MAIN_CMD_VDEV_MNG,
+            DCMI_VDEV_MNG_SUB_CMD_DCMI_VMNG_SUB_CMD_GET_VDEV_RESOURCE,
+            query,
+        )?;
+        convert(query.query_info)
+    }
+
+    /// Query the AI core utilization of the vNPU
+    ///
+    /// # Returns
+    /// utilization, unit: %
+    pub fn utilization(&self) -> DCMIResult<u32> {
+        Ok(self.info()?.aicore_utilization)
+    }
+
+    /// Destroy the vNPU and return its resources to the physical chip
+    pub fn destroy(self) -> DCMIResult<()> {
+        self.chip.destroy_vchip(self.id)
+    }
+}
+
+/// vNPU destroyed when the guard is dropped
+///
+/// Errors of the destruction on drop are ignored, call [`ScopedVirtualChip::destroy`] to handle
+/// them or [`ScopedVirtualChip::leak`] to keep the vNPU.
+#[derive(Debug)]
+pub struct ScopedVirtualChip<'a> {
+    vchip: Option<VirtualChip<'a>>,
+}
+
+impl<'a> ScopedVirtualChip<'a> {
+    /// Keep the vNPU alive after the guard is gone
+    pub fn leak(mut self) -> VirtualChip<'a> {
+        self.vchip
+            .take()
+            .expect("vNPU is present until the guard is consumed")
+    }
+
+    /// Destroy the vNPU now, reporting errors
+    pub fn destroy(self) -> DCMIResult<()> {
+        self.leak().destroy()
+    }
+}
+
+impl<'a> Deref for ScopedVirtualChip<'a> {
+    type Target = VirtualChip<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.vchip
+            .as_ref()
+            .expect("vNPU is present until the guard is consumed")
+    }
+}
+
+impl Drop for ScopedVirtualChip<'_> {
+    fn drop(&mut self) {
+        if let Some(vchip) = self.vchip.take() {
+            let _ = vchip.destroy();
+        }
+    }
+}
+
+impl DCMI {
+    /// Query whether vNPUs are recreated from [`VCHIP_CONFIG_FILE`] after a reboot
+    ///
+    /// The recover mode is a host-wide setting of the driver, it applies to every card.
+    pub fn get_vchip_recover_mode(&self) -> DCMIResult<bool> {
+        let mut mode = 0;
+        call_dcmi_function!(dcmi_get_vnpu_config_recover_mode, &mut mode)?;
+        Ok(mode != 0)
+    }
+
+    /// Enable or disable recreating vNPUs from [`VCHIP_CONFIG_FILE`] after a reboot
+    pub fn set_vchip_recover_mode(&self, enabled: bool) -> DCMIResult<()> {
+        call_dcmi_function!(dcmi_set_vnpu_config_recover_mode, enabled as u32)
+    }
+
+    /// Query the state of the vNPU configuration file, `None` if the driver has not written it
+    pub fn get_vchip_config_file(&self) -> DCMIResult<Option<VChipConfigFile>> {
+        VChipConfigFile::read(VCHIP_CONFIG_FILE)
+    }
+}
+
+impl<'a> Chip<'a> {
+    /// Create a vNPU from a resource template
+    pub fn create_virtual_chip(&self, res: &VChipRes) -> DCMIResult<VirtualChip<'a>> {
+        let output = self.create_vchip(res)?;
+        Ok(VirtualChip {
+            chip: *self,
+            id: output.vchip_id,
+        })
+    }
+
+    /// Create a vNPU that is destroyed when the returned guard is dropped, including on panic
+    pub fn create_virtual_chip_scoped(&self, res: &VChipRes) -> DCMIResult<ScopedVirtualChip<'a>> {
+        Ok(ScopedVirtualChip {
+            vchip: Some(self.create_virtual_chip(res)?),
+        })
+    }
+
+    /// List the vNPUs of the chip
+    pub fn list_virtual_chips(&self) -> DCMIResult<Vec<VirtualChip<'a>>> {
+        let total: dcmi_soc_total_resource = self.get_device_info(
+            dcmi_main_cmd_DCMI_MAIN_CMD_VDEV_MNG,
+            DCMI_VDEV_MNG_SUB_CMD_DCMI_VMNG_SUB_CMD_GET_TOTAL_RESOURCE,
+        )?;
+        let num = (total.vdev_num as usize).min(total.vdev_id.len());
+        Ok(total.vdev_id[..num]
+            .iter()
+            .map(|&id| VirtualChip { chip: *self, id })
+            .collect())
+    }
+
+    /// Destroy every vNPU of the chip
+    ///
+    /// vNPUs surviving the bulk destruction are retried one by one, those still failing are
+    /// reported in [`DestroyAllReport::failed`].
+    pub fn destroy_all_virtual_chips(&self) -> DCMIResult<DestroyAllReport> {
+        let before = self.list_virtual_chips()?;
+        if before.is_empty() {
+            return Ok(DestroyAllReport::default());
+        }
+        // a failing bulk call is covered by the individual retries below
+        let _ = self.destroy_vchip(ALL_VCHIPS);
+
+        let mut failed = Vec::new();
+        for vchip in self.list_virtual_chips()? {
+            if let Err(e) = self.destroy_vchip(vchip.id) {
+                failed.push((vchip.id, e));
+            }
+        }
+        let destroyed = before
+            .iter()
+            .map(|vchip| vchip.id)
+            .filter(|id| failed.iter().all(|(failed_id, _)| failed_id != id))
+            .collect();
+        Ok(DestroyAllReport { destroyed, failed })
+    }
+
+    /// Create a vNPU, returning only the ids DCMI assigned
+    ///
+    /// See [`Chip::create_virtual_chip`] for a handle to the created vNPU.
+    ///
+    /// # Errors
+    /// [`DCMIError::InvalidVChipRes`] if `res` fails [`VChipRes::validate`]
+    pub fn create_vchip(&self, res: &VChipRes) -> DCMIResult<VChipOutput> {
+        let mut vdev = dcmi_create_vdev_res_stru::try_from(res)?;
+        // SAFETY: plain C struct, all-zero is a valid value
+        let mut out: dcmi_create_vdev_out = unsafe { std::mem::zeroed() };
+        call_dcmi_function!(
+            dcmi_create_vdevice,
+            self.card_id() as i32,
+            self.id() as i32,
+            &mut vdev,
+            &mut out
+        )?;
+        convert(out)
+    }
+
+    /// Destroy a vNPU of the chip by id
+    pub fn destroy_vchip(&self, vchip_id: u32) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_destroy_vdevice,
+            self.card_id() as i32,
+            self.id() as i32,
+            vchip_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_file_state() {
+        let dir = std::env::temp_dir().join(format!("hw_dcmi_vnpu_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("vnpu.cfg");
+        assert_eq!(VChipConfigFile::read(&path), Ok(None));
+        std::fs::write(&path, "vnpu config").unwrap();
+        let file = VChipConfigFile::read(&path).unwrap().unwrap();
+        assert_eq!((file.path.as_path(), file.size), (path.as_path(), 11));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+//! Resource templates of vNPUs known per chip model
+//!
+//! The table is transcribed from the vNPU sections of the Ascend NPU driver documentation. The
+//! driver remains the authority on which templates a chip accepts, the table is meant to reject
+//! obviously wrong requests early and to plan splits without querying the chip.
+
+use crate::structs::{VChipFreeResources, VChipRes};
+
+/// Resources a vNPU created from a template receives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VChipTemplate {
+    pub name: &'static str,
+    pub aicore: u32,
+    pub aicpu: u32,
+    /// unit: MB
+    pub memory_size: u64,
+}
+
+impl VChipTemplate {
+    /// Whether the free resources of a chip suffice for a vNPU of this template
+    pub fn fits(&self, free: &VChipFreeResources) -> bool {
+        free.vfg_num > 0
+            && free.aicore >= self.aicore as f32
+            && u32::from(free.device_aicpu) >= self.aicpu
+            && free.memory_size.0 >= self.memory_size
+    }
+}
+
+/// Templates of a chip model and the resources of the whole chip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChipModelTemplates {
+    /// Chip names as reported in the chip information, e.g. `310P3`
+    pub chip_names: &'static [&'static str],
+    pub aicore: u32,
+    pub aicpu: u32,
+    /// unit: MB
+    pub memory_size: u64,
+    pub templates: &'static [VChipTemplate],
+}
+
+impl ChipModelTemplates {
+    /// Look up a template of the model by name
+    pub fn template(&self, name: &str) -> Option<&'static VChipTemplate> {
+        self.templates.iter().find(|template| template.name == name)
+    }
+}
+
+const GB: u64 = 1024;
+
+const fn template(name: &'static str, aicore: u32, aicpu: u32, memory_gb: u64) -> VChipTemplate {
+    VChipTemplate {
+        name,
+        aicore,
+        aicpu,
+        memory_size: memory_gb * GB,
+    }
+}
+
+/// Known templates of every chip model supporting vNPUs
+pub const CHIP_MODEL_TEMPLATES: &[ChipModelTemplates] = &[
+    ChipModelTemplates {
+        chip_names: &["310P1", "310P3"],
+        aicore: 8,
+        aicpu: 7,
+        memory_size: 24 * GB,
+        templates: &[
+            template("vir01", 1, 1, 3),
+            template("vir02", 2, 2, 6),
+            template("vir02_1c", 2, 1, 6),
+            template("vir04", 4, 4, 12),
+            template("vir04_3c", 4, 3, 12),
+            template("vir04_3c_ndvpp", 4, 3, 12),
+            template("vir04_4c_dvpp", 4, 4, 12),
+        ],
+    },
+    ChipModelTemplates {
+        chip_names: &["910A", "910B", "910ProA", "910ProB", "910PremiumA"],
+        aicore: 32,
+        aicpu: 14,
+        memory_size: 32 * GB,
+        templates: &[
+            template("vir02", 2, 1, 2),
+            template("vir04", 4, 1, 4),
+            template("vir08", 8, 3, 8),
+            template("vir16", 16, 7, 16),
+        ],
+    },
+    ChipModelTemplates {
+        chip_names: &["910B1", "910B2", "910B2C"],
+        aicore: 24,
+        aicpu: 6,
+        memory_size: 64 * GB,
+        templates: &[
+            template("vir06_1c_16g", 6, 1, 16),
+            template("vir12_3c_32g", 12, 3, 32),
+        ],
+    },
+    ChipModelTemplates {
+        chip_names: &["910B3"],
+        aicore: 20,
+        aicpu: 7,
+        memory_size: 64 * GB,
+        templates: &[
+            template("vir05_1c_16g", 5, 1, 16),
+            template("vir10_3c_32g", 10, 3, 32),
+        ],
+    },
+    ChipModelTemplates {
+        chip_names: &["910B4", "910B4-1"],
+        aicore: 20,
+        aicpu: 7,
+        memory_size: 32 * GB,
+        templates: &[
+            template("vir05_1c_8g", 5, 1, 8),
+            template("vir10_3c_16g", 10, 3, 16),
+        ],
+    },
+];
+
+/// Templates of the chip model named `chip_name`, `None` for models without known templates
+pub fn templates_for(chip_name: &str) -> Option<&'static ChipModelTemplates> {
+    let chip_name = chip_name.trim().trim_start_matches("Ascend");
+    CHIP_MODEL_TEMPLATES
+        .iter()
+        .find(|model| model.chip_names.contains(&chip_name))
+}
+
+impl VChipRes {
+    /// Look up the template of the request in the table of the chip model
+    ///
+    /// # Returns
+    /// `None` if the model has no known templates or the template is not one of them
+    pub fn known_template(&self, chip_name: &str) -> Option<&'static VChipTemplate> {
+        templates_for(chip_name)?.template(&self.template_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn table_is_consistent() {
+        let mut chip_names = HashSet::new();
+        for model in CHIP_MODEL_TEMPLATES {
+            for name in model.chip_names {
+                assert!(chip_names.insert(name), "{name} is listed twice");
+            }
+            let mut template_names = HashSet::new();
+            for template in model.templates {
+                assert!(template_names.insert(template.name), "{}", template.name);
+                assert!(
+                    template.name.len() < 32,
+                    "{} does not fit DCMI",
+                    template.name
+                );
+                assert!(template.aicore <= model.aicore && template.aicpu <= model.aicpu);
+                assert!(template.memory_size <= model.memory_size);
+                // the number after `vir` is the number of AI cores
+                let cores: u32 = template.name[3..5].parse().unwrap();
+                assert_eq!(cores, template.aicore, "{}", template.name);
+            }
+        }
+
+        let res = VChipRes {
+            vchip_id: 0,
+            vfg_id: 0,
+            template_name: "vir02".to_string(),
+        };
+        assert_eq!(res.known_template("Ascend310P3").unwrap().aicore, 2);
+        assert_eq!(res.known_template("910B3"), None);
+        assert_eq!(res.known_template("310B1"), None);
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Liveness marker shared between a worker thread and a watchdog
+///
+/// The worker calls [`Heartbeat::beat`] after every successful iteration, the watchdog considers
+/// the worker stuck once the last beat is older than its staleness limit.
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    last_beat: Arc<Mutex<Instant>>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heartbeat {
+    pub fn new() -> Self {
+        Heartbeat {
+            last_beat: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Record that the worker is alive
+    pub fn beat(&self) {
+        *self.last_beat.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    /// Time elapsed since the last beat
+    pub fn elapsed(&self) -> Duration {
+        self.last_beat
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .elapsed()
+    }
+
+    /// Whether the last beat happened within `max_staleness`
+    pub fn is_fresh(&self, max_staleness: Duration) -> bool {
+        self.elapsed() <= max_staleness
+    }
+}
+
+#[cfg(feature = "systemd")]
+pub use systemd::SystemdWatchdog;
+
+#[cfg(feature = "systemd")]
+mod systemd {
+    use super::Heartbeat;
+    use sd_notify::NotifyState;
+    use std::io;
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+
+    /// Pings the systemd service watchdog while a [`Heartbeat`] stays fresh
+    ///
+    /// If the monitored thread hangs (e.g. in a DCMI ioctl that never returns) the pings stop and
+    /// systemd restarts the service according to its `WatchdogSec=` setting, instead of the
+    /// service silently serving stale data. The background thread stops when this value is
+    /// dropped.
+    #[derive(Debug)]
+    pub struct SystemdWatchdog {
+        stop: Option<Sender<()>>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl SystemdWatchdog {
+        /// Start pinging the watchdog
+        ///
+        /// # Parameters
+        /// - heartbeat: heartbeat of the monitored thread
+        /// - max_staleness: maximum age of the last beat for the thread to be considered healthy
+        ///
+        /// # Returns
+        /// `None` if the watchdog is not enabled for the current service (`WATCHDOG_USEC` unset)
+        pub fn spawn(heartbeat: Heartbeat, max_staleness: Duration) -> io::Result<Option<Self>> {
+            let mut usec = 0;
+            if !sd_notify::watchdog_enabled(false, &mut usec) {
+                return Ok(None);
+            }
+            // systemd recommends pinging at half of the configured timeout
+            let interval = Duration::from_micros(usec / 2);
+            let (stop, stop_rx) = mpsc::channel();
+            let handle = thread::Builder::new()
+                .name("dcmi-systemd-watchdog".to_string())
+                .spawn(move || {
+                    while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                        if heartbeat.is_fresh(max_staleness) {
+                            let _ = sd_notify::notify(false, &[NotifyState::Watchdog]);
+                        }
+                    }
+                })?;
+            Ok(Some(SystemdWatchdog {
+                stop: Some(stop),
+                handle: Some(handle),
+            }))
+        }
+    }
+
+    impl Drop for SystemdWatchdog {
+        fn drop(&mut self) {
+            drop(self.stop.take());
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heartbeat_goes_stale() {
+        let heartbeat = Heartbeat::new();
+        assert!(heartbeat.is_fresh(Duration::from_secs(60)));
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!heartbeat.is_fresh(Duration::from_millis(10)));
+        heartbeat.clone().beat();
+        assert!(heartbeat.is_fresh(Duration::from_millis(10)));
+    }
+}
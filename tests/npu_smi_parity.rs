@@ -0,0 +1,116 @@
+//! Cross-checks the values reported by this crate against `npu-smi`
+//!
+//! Catches unit and offset mistakes in the wrappers. Needs Ascend hardware with `npu-smi`
+//! installed and is skipped unless `HW_DCMI_NPU_SMI_PARITY=1` is set:
+//!
+//! ```sh
+//! HW_DCMI_NPU_SMI_PARITY=1 cargo test --test npu_smi_parity -- --nocapture
+//! ```
+
+use hw_dcmi::enums::{HealthState, UnitType};
+use hw_dcmi::units::Mebibytes;
+use hw_dcmi::DCMI;
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Allowed difference between two temperature readings, unit: °C
+const TEMPERATURE_TOLERANCE: i32 = 3;
+
+/// Run `npu-smi info -t <query> -i <card> -c <chip>` and collect its `key : value` lines
+fn npu_smi_info(query: &str, card_id: u32, chip_id: u32) -> Option<HashMap<String, String>> {
+    let output = Command::new("npu-smi")
+        .args(["info", "-t", query, "-i"])
+        .arg(card_id.to_string())
+        .arg("-c")
+        .arg(chip_id.to_string())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect(),
+    )
+}
+
+fn find_value<T: std::str::FromStr>(values: &HashMap<String, String>, key_part: &str) -> Option<T> {
+    values
+        .iter()
+        .find(|(key, _)| key.contains(key_part))
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+fn health_from_npu_smi(status: &str) -> Option<HealthState> {
+    match status {
+        "OK" => Some(HealthState::Normal),
+        "Warning" => Some(HealthState::MinorAlarm),
+        "Alarm" => Some(HealthState::MajorAlarm),
+        "Critical" => Some(HealthState::CriticalAlarm),
+        _ => None,
+    }
+}
+
+#[test]
+fn matches_npu_smi() {
+    if std::env::var("HW_DCMI_NPU_SMI_PARITY").as_deref() != Ok("1") {
+        eprintln!("skipped, set HW_DCMI_NPU_SMI_PARITY=1 to compare against npu-smi");
+        return;
+    }
+    if Command::new("npu-smi").arg("-v").output().is_err() {
+        eprintln!("skipped, npu-smi not found");
+        return;
+    }
+
+    let dcmi = DCMI::init().unwrap();
+    for card in dcmi.get_card_list().unwrap() {
+        for chip in card.get_chips().unwrap() {
+            if chip.unit_type() != UnitType::NPU {
+                continue;
+            }
+            let (card_id, chip_id) = (chip.card_id(), chip.id());
+
+            if let Some(temp) = npu_smi_info("temp", card_id, chip_id) {
+                let expected: i32 = find_value(&temp, "Temperature").unwrap();
+                let actual = chip.get_temperature().unwrap();
+                assert!(
+                    (expected - actual.0).abs() <= TEMPERATURE_TOLERANCE,
+                    "card {card_id} chip {chip_id}: temperature {actual} vs npu-smi {expected}"
+                );
+            }
+
+            if let Some(health) = npu_smi_info("health", card_id, chip_id) {
+                let expected = health
+                    .get("Health Status")
+                    .and_then(|status| health_from_npu_smi(status));
+                if let Some(expected) = expected {
+                    assert_eq!(
+                        chip.get_health().unwrap(),
+                        expected,
+                        "card {card_id} chip {chip_id}: health"
+                    );
+                }
+            }
+
+            if let Some(usages) = npu_smi_info("usages", card_id, chip_id) {
+                if let Some(expected) = find_value::<u64>(&usages, "HBM Capacity(MB)") {
+                    assert_eq!(
+                        chip.get_hbm_info().unwrap().memory_size,
+                        Mebibytes(expected),
+                        "card {card_id} chip {chip_id}: HBM capacity"
+                    );
+                }
+                if let Some(expected) = find_value::<u64>(&usages, "Memory Capacity(MB)") {
+                    assert_eq!(
+                        chip.get_memory_info().unwrap().memory_size,
+                        Mebibytes(expected),
+                        "card {card_id} chip {chip_id}: memory capacity"
+                    );
+                }
+            }
+        }
+    }
+}